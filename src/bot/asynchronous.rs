@@ -0,0 +1,141 @@
+use std::{convert::TryFrom, fmt::Display, path::PathBuf};
+
+use reqwest::{multipart::Form, Client};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    http::response::{async_response_result, failed_async_response_error},
+    operations::{
+        bot::{
+            me::{invalid_token_error, is_invalid_token},
+            BotParams,
+        },
+        CommonExitCodes, OperationError,
+    },
+    API_URL,
+};
+
+use super::{RichText, SendDocument, SendMessage, SendPhoto, User};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The non-blocking counterpart of `Bot` on tokio. The builders are the same
+/// and only `send` is awaited.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), tgcli::operations::OperationError> {
+/// let bot = tgcli::AsyncBot::new("123:token")?;
+/// let message = bot.send_message("@channel", "Deployed\\!").silent().send().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncBot {
+    params: BotParams,
+    client: Client,
+}
+
+impl AsyncBot {
+    pub fn new(token: &str) -> Result<Self, OperationError> {
+        let client = match Client::builder().build() {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ReqwestConnectionError as i32,
+                    "Could not initialize the HTTP client.",
+                    Some(e),
+                ))
+            }
+        };
+
+        Ok(Self {
+            params: BotParams::new(token, API_URL),
+            client,
+        })
+    }
+
+    /// Uses another Bot API server, e.g. a local one.
+    pub fn with_api_url(self, api_url: &str) -> Self {
+        Self {
+            params: self.params.with_api_url(api_url),
+            client: self.client,
+        }
+    }
+
+    /// Uploads files up to 2000 MB to a local Bot API server set with `with_api_url`.
+    pub fn with_local_server(self) -> Self {
+        Self {
+            params: self.params.with_local_server(true),
+            client: self.client,
+        }
+    }
+
+    /// Gets the bot itself, which also verifies the token.
+    pub async fn get_me(&self) -> Result<User, OperationError> {
+        let url = self.params.method_url("getMe");
+        trace!("url: {}", url);
+
+        let response = match self.client.post(url).send().await {
+            Ok(r) if is_invalid_token(r.status()) => {
+                return Err(invalid_token_error(failed_async_response_error(r).await))
+            }
+            r => r,
+        };
+        async_response_result(response).await
+    }
+
+    pub fn send_message(&self, chat: impl Display, text: impl Display) -> SendMessage<'_, Self> {
+        SendMessage::new(self, self.params.clone(), chat, text)
+    }
+
+    /// Sends a text styled with entities, which needs no escaping.
+    pub fn send_rich_text(&self, chat: impl Display, text: RichText) -> SendMessage<'_, Self> {
+        SendMessage::new(self, self.params.clone(), chat, "").rich_text(text)
+    }
+
+    pub fn send_document(
+        &self,
+        chat: impl Display,
+        file: impl Into<PathBuf>,
+    ) -> SendDocument<'_, Self> {
+        SendDocument::new(self, self.params.clone(), chat, file.into())
+    }
+
+    pub fn send_photo(&self, chat: impl Display, file: impl Into<PathBuf>) -> SendPhoto<'_, Self> {
+        SendPhoto::new(self, self.params.clone(), chat, file.into())
+    }
+
+    /// Posts a request model to `method` and yields its result.
+    pub(super) async fn post<M, T>(
+        &self,
+        method: &str,
+        req_instance: M,
+    ) -> Result<T, OperationError>
+    where
+        Form: TryFrom<M, Error = OperationError>,
+        T: DeserializeOwned,
+    {
+        let url = self.params.method_url(method);
+        trace!("url: {}", url);
+
+        let req_body = match Form::try_from(req_instance) {
+            Ok(f) => f,
+            Err(e) => return Err(e),
+        };
+
+        let response = self.client.post(url).multipart(req_body).send().await;
+        async_response_result(response).await
+    }
+}
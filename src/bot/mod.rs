@@ -0,0 +1,142 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt::Display,
+};
+
+use reqwest::blocking::{multipart::Form, Client};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    handle_response,
+    http::transfer_client,
+    operations::{
+        bot::{me::get_me, BotParams},
+        OperationError,
+    },
+    API_URL,
+};
+
+#[cfg(feature = "async")]
+pub use self::asynchronous::AsyncBot;
+pub use self::send::{SendDocument, SendMessage, SendPhoto};
+pub use crate::{
+    http::response::models::{
+        message::{ChatModel as Chat, MessageModel as Message},
+        user::UserModel as User,
+    },
+    operations::bot::send::{
+        entities::{MessageEntity, RichText},
+        MessageFormat,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "async")]
+mod asynchronous;
+mod send;
+
+/// A client of Bot API to use Telegram without spawning `tgcli`.
+///
+/// ```no_run
+/// # fn main() -> Result<(), tgcli::operations::OperationError> {
+/// let bot = tgcli::Bot::new("123:token")?;
+/// let message = bot.send_message("@channel", "Deployed\\!").silent().send()?;
+/// bot.send_message("@channel", "🎉").reply_to(message.message_id).send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bot {
+    params: BotParams,
+    client: Client,
+}
+
+impl Bot {
+    pub fn new(token: &str) -> Result<Self, OperationError> {
+        let client = transfer_client()?;
+
+        Ok(Self {
+            params: BotParams::new(token, API_URL),
+            client,
+        })
+    }
+
+    /// Uses another Bot API server, e.g. a local one.
+    pub fn with_api_url(self, api_url: &str) -> Self {
+        Self {
+            params: self.params.with_api_url(api_url),
+            client: self.client,
+        }
+    }
+
+    /// Uploads files up to 2000 MB to a local Bot API server set with `with_api_url`.
+    pub fn with_local_server(self) -> Self {
+        Self {
+            params: self.params.with_local_server(true),
+            client: self.client,
+        }
+    }
+
+    /// Gets the bot itself, which also verifies the token.
+    pub fn get_me(&self) -> Result<User, OperationError> {
+        get_me(&self.client, &self.params)
+    }
+
+    pub fn send_message(&self, chat: impl Display, text: impl Display) -> SendMessage<'_> {
+        SendMessage::new(self, self.params.clone(), chat, text)
+    }
+
+    /// Sends a text styled with entities, which needs no escaping.
+    pub fn send_rich_text(&self, chat: impl Display, text: RichText) -> SendMessage<'_> {
+        SendMessage::new(self, self.params.clone(), chat, "").rich_text(text)
+    }
+
+    pub fn send_document(
+        &self,
+        chat: impl Display,
+        file: impl Into<std::path::PathBuf>,
+    ) -> SendDocument<'_> {
+        SendDocument::new(self, self.params.clone(), chat, file.into())
+    }
+
+    pub fn send_photo(
+        &self,
+        chat: impl Display,
+        file: impl Into<std::path::PathBuf>,
+    ) -> SendPhoto<'_> {
+        SendPhoto::new(self, self.params.clone(), chat, file.into())
+    }
+
+    /// Posts a request model to `method` and yields its result.
+    pub(super) fn post<M, T>(&self, method: &str, req_instance: M) -> Result<T, OperationError>
+    where
+        Form: TryFrom<M, Error = OperationError>,
+        T: DeserializeOwned,
+    {
+        let url = self.params.method_url(method);
+        trace!("url: {}", url);
+
+        let req_body: Form = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let response = self.client.post(url).multipart(req_body).send();
+        handle_response!(response => T, on_success => {
+            debug!("Received the result of {}.", method);
+        }, on_failure => {
+            debug!("Could not get the result of {}.", method);
+        })
+    }
+}
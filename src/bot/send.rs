@@ -0,0 +1,283 @@
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{
+    http::request::models::{
+        senddocument::SendDocumentRequestModel, sendmessage::SendMessageRequestModel,
+        sendphoto::SendPhotoRequestModel,
+    },
+    operations::{
+        bot::{
+            send::{
+                document::DocumentParams, entities::RichText, message::MessageParams,
+                photo::PhotoParams, MessageFormat, SendParams, ThumbnailParams,
+            },
+            BotParams,
+        },
+        OperationError, ProgressMode, RootParams,
+    },
+};
+
+#[cfg(feature = "async")]
+use super::AsyncBot;
+use super::{Bot, Message};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Library calls never draw progress.
+fn root_params() -> RootParams {
+    RootParams::new(ProgressMode::Hidden, None)
+}
+
+/// The builder of /sendMessage.
+#[derive(Debug)]
+pub struct SendMessage<'a, B = Bot> {
+    bot: &'a B,
+    bot_params: BotParams,
+    send_params: SendParams,
+    message_params: MessageParams,
+}
+
+impl<'a, B> SendMessage<'a, B> {
+    pub(super) fn new(
+        bot: &'a B,
+        bot_params: BotParams,
+        chat: impl Display,
+        text: impl Display,
+    ) -> Self {
+        Self {
+            bot,
+            bot_params,
+            send_params: SendParams::new(&chat.to_string(), MessageFormat::Markdown, false),
+            message_params: MessageParams::new(text.to_string()),
+        }
+    }
+
+    /// Sends `text` with its entities instead of a format.
+    pub(super) fn rich_text(mut self, text: RichText) -> Self {
+        let (text, entities) = text.into_parts();
+        self.message_params.message = text;
+        self.message_params.entities = Some(entities);
+        self
+    }
+
+    /// Defaults to MarkdownV2. Has no effect on a rich text.
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.send_params.format = format;
+        self
+    }
+
+    /// Sends the message without a notification sound.
+    pub fn silent(mut self) -> Self {
+        self.send_params.silent = true;
+        self
+    }
+
+    pub fn reply_to(mut self, message_id: usize) -> Self {
+        self.send_params.reply_to = Some(message_id);
+        self
+    }
+
+    fn into_request(self) -> Result<(&'a B, SendMessageRequestModel), OperationError> {
+        let req_instance = (
+            root_params(),
+            self.bot_params,
+            self.send_params,
+            self.message_params,
+        )
+            .into();
+        Ok((self.bot, req_instance))
+    }
+}
+
+impl SendMessage<'_, Bot> {
+    pub fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendMessage", req_instance),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl SendMessage<'_, AsyncBot> {
+    pub async fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendMessage", req_instance).await,
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The builder of /sendDocument.
+#[derive(Debug)]
+pub struct SendDocument<'a, B = Bot> {
+    bot: &'a B,
+    bot_params: BotParams,
+    send_params: SendParams,
+    document_params: DocumentParams,
+}
+
+impl<'a, B> SendDocument<'a, B> {
+    pub(super) fn new(
+        bot: &'a B,
+        bot_params: BotParams,
+        chat: impl Display,
+        file: PathBuf,
+    ) -> Self {
+        Self {
+            bot,
+            bot_params,
+            send_params: SendParams::new(&chat.to_string(), MessageFormat::Markdown, false),
+            document_params: DocumentParams::new(file, None, None),
+        }
+    }
+
+    pub fn caption(mut self, caption: impl Display) -> Self {
+        self.document_params.message = Some(caption.to_string());
+        self
+    }
+
+    /// Uploads a JPEG thumbnail, fitted into the limits of Telegram if `fit` is true.
+    pub fn thumbnail(mut self, file: impl Into<PathBuf>, fit: bool) -> Self {
+        self.document_params.thumbnail = Some(ThumbnailParams::new(file.into(), fit));
+        self
+    }
+
+    /// The format of the caption. Defaults to MarkdownV2.
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.send_params.format = format;
+        self
+    }
+
+    pub fn silent(mut self) -> Self {
+        self.send_params.silent = true;
+        self
+    }
+
+    pub fn reply_to(mut self, message_id: usize) -> Self {
+        self.send_params.reply_to = Some(message_id);
+        self
+    }
+
+    fn into_request(self) -> Result<(&'a B, SendDocumentRequestModel), OperationError> {
+        self.bot_params.check_upload(&self.document_params.file)?;
+
+        let req_instance = (
+            root_params(),
+            self.bot_params,
+            self.send_params,
+            self.document_params,
+        )
+            .into();
+        Ok((self.bot, req_instance))
+    }
+}
+
+impl SendDocument<'_, Bot> {
+    pub fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendDocument", req_instance),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl SendDocument<'_, AsyncBot> {
+    pub async fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendDocument", req_instance).await,
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The builder of /sendPhoto.
+#[derive(Debug)]
+pub struct SendPhoto<'a, B = Bot> {
+    bot: &'a B,
+    bot_params: BotParams,
+    send_params: SendParams,
+    photo_params: PhotoParams,
+}
+
+impl<'a, B> SendPhoto<'a, B> {
+    pub(super) fn new(
+        bot: &'a B,
+        bot_params: BotParams,
+        chat: impl Display,
+        file: PathBuf,
+    ) -> Self {
+        Self {
+            bot,
+            bot_params,
+            send_params: SendParams::new(&chat.to_string(), MessageFormat::Markdown, false),
+            photo_params: PhotoParams::new(file, None),
+        }
+    }
+
+    pub fn caption(mut self, caption: impl Display) -> Self {
+        self.photo_params.message = Some(caption.to_string());
+        self
+    }
+
+    /// The format of the caption. Defaults to MarkdownV2.
+    pub fn format(mut self, format: MessageFormat) -> Self {
+        self.send_params.format = format;
+        self
+    }
+
+    pub fn silent(mut self) -> Self {
+        self.send_params.silent = true;
+        self
+    }
+
+    pub fn reply_to(mut self, message_id: usize) -> Self {
+        self.send_params.reply_to = Some(message_id);
+        self
+    }
+
+    fn into_request(self) -> Result<(&'a B, SendPhotoRequestModel), OperationError> {
+        self.bot_params.check_upload(&self.photo_params.file)?;
+
+        let req_instance = (
+            root_params(),
+            self.bot_params,
+            self.send_params,
+            self.photo_params,
+        )
+            .into();
+        Ok((self.bot, req_instance))
+    }
+}
+
+impl SendPhoto<'_, Bot> {
+    pub fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendPhoto", req_instance),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl SendPhoto<'_, AsyncBot> {
+    pub async fn send(self) -> Result<Message, OperationError> {
+        match self.into_request() {
+            Ok((bot, req_instance)) => bot.post("sendPhoto", req_instance).await,
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,25 +1,70 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, env};
 
 use clap::{
     app_from_crate, crate_authors, crate_description, crate_name, crate_version, App, AppSettings,
-    Arg, SubCommand,
+    Arg, ArgGroup, ArgMatches, SubCommand,
 };
+use serde_json::json;
 
 use crate::{
     cli::{
         logging::set_logger,
         validators::{
-            audio_validator, caption_validator, file_validator, float_validator, image_validator,
-            poll_option_validator, poll_question_validator, video_validator,
+            animation_validator, audio_validator, bot_description_validator, bot_name_validator,
+            bot_short_description_validator, caption_validator, chat_description_validator,
+            chat_title_validator, cron_validator, datetime_validator, duration_validator,
+            file_validator, float_validator, heading_validator, horizontal_accuracy_validator,
+            image_validator, language_code_validator, live_period_validator,
+            member_limit_validator, poll_option_validator, poll_question_validator,
+            positive_integer_validator, proximity_alert_radius_validator, retries_validator,
+            template_var_validator, until_validator, url_validator, video_validator,
         },
     },
+    convert::operations::schedule::{schedule_params, spooled_args},
     operations::{
-        bot::send::{
-            audio::SendAudioOperation, document::SendDocumentOperation,
-            location::SendLocationOperation, message::SendMessageOperation,
-            photo::SendPhotoOperation, poll::SendPollOperation, video::SendVideoOperation,
-            SendOperation,
+        batch::BatchOperation,
+        bot::{
+            chat::{
+                administrators::GetAdministratorsOperation,
+                description::SetChatDescriptionOperation,
+                invite::{CreateInviteLinkOperation, ExportInviteLinkOperation},
+                leave::LeaveChatOperation,
+                membercount::GetMemberCountOperation,
+                photo::{DeleteChatPhotoOperation, SetChatPhotoOperation},
+                pin::{PinMessageOperation, UnpinAllMessagesOperation, UnpinMessageOperation},
+                title::SetChatTitleOperation,
+            },
+            commands::{
+                delete::DeleteCommandsOperation, get::GetCommandsOperation,
+                set::SetCommandsOperation,
+            },
+            file::get::GetFileOperation,
+            location::{
+                follow::FollowLocationOperation, stop::StopLocationOperation,
+                update::UpdateLocationOperation,
+            },
+            me::GetMeOperation,
+            member::{
+                ban::BanMemberOperation, info::GetMemberOperation, promote::PromoteMemberOperation,
+                restrict::RestrictMemberOperation, unban::UnbanMemberOperation,
+            },
+            profile::{
+                description::{SetDescriptionOperation, SetShortDescriptionOperation},
+                name::SetNameOperation,
+            },
+            send::{
+                animation::SendAnimationOperation, audio::SendAudioOperation,
+                code::SendCodeOperation, document::SendDocumentOperation,
+                location::SendLocationOperation, message::SendMessageOperation,
+                photo::SendPhotoOperation, poll::SendPollOperation, video::SendVideoOperation,
+                SendOperation,
+            },
+        },
+        daemon::DaemonOperation,
+        outbox::{
+            drop::DropOutboxOperation, flush::FlushOutboxOperation, list::ListOutboxOperation,
         },
+        schedule::SpoolEntry,
         CommonExitCodes, OperationError,
     },
 };
@@ -62,10 +107,134 @@ pub fn get_app() -> App<'static, 'static> {
         .help("A message to be sent.")
         .validator(caption_validator);
     #[allow(non_snake_case)]
+    let TEMPLATE_ARG = Arg::with_name("template")
+        .long("template")
+        .help("A minijinja template rendered into the message instead, escaping values for the format.")
+        .takes_value(true)
+        .conflicts_with("message")
+        .validator(file_validator);
+    #[allow(non_snake_case)]
+    let VAR_ARG = Arg::with_name("var")
+        .long("var")
+        .help("A variable of the template as key=value.")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .requires("template")
+        .validator(template_var_validator);
+    #[allow(non_snake_case)]
+    let VARS_JSON_ARG = Arg::with_name("vars_json")
+        .long("vars-json")
+        .help("A JSON file with an object of variables of the template.")
+        .takes_value(true)
+        .requires("template")
+        .validator(file_validator);
+    #[allow(non_snake_case)]
     let FILE_ARG = Arg::with_name("file")
         .required(true)
         .help("A file to be uploaded.")
         .validator(file_validator);
+    #[allow(non_snake_case)]
+    let DURATION_ARG = Arg::with_name("duration")
+        .long("duration")
+        .help("Duration in seconds. Read from the file if absent.")
+        .takes_value(true)
+        .validator(positive_integer_validator);
+    #[allow(non_snake_case)]
+    let THUMBNAIL_ARG = Arg::with_name("thumbnail")
+        .long("thumbnail")
+        .help("A thumbnail for the file. Should be a JPEG up to 320x320 and 200KB.")
+        .takes_value(true)
+        .validator(image_validator);
+    #[allow(non_snake_case)]
+    let AUTO_THUMBNAIL_ARG = Arg::with_name("auto_thumbnail")
+        .long("auto-thumbnail")
+        .help("Resizes and re-encodes the thumbnail to fit Telegram's limits.")
+        .takes_value(false)
+        .requires("thumbnail");
+    #[allow(non_snake_case)]
+    let LATITUDE_ARG = Arg::with_name("latitude")
+        .help("The latitude of the location.")
+        .short("x")
+        .takes_value(true)
+        .required(true)
+        .validator(float_validator);
+    #[allow(non_snake_case)]
+    let LONGITUDE_ARG = Arg::with_name("longitude")
+        .help("The longitude of the location.")
+        .short("y")
+        .takes_value(true)
+        .required(true)
+        .validator(float_validator);
+    #[allow(non_snake_case)]
+    let LIVE_PERIOD_ARG = Arg::with_name("live_period")
+        .long("live-period")
+        .help("Period in seconds for which the location will be updated, between 60 and 86400.")
+        .takes_value(true)
+        .validator(live_period_validator);
+    #[allow(non_snake_case)]
+    let HEADING_ARG = Arg::with_name("heading")
+        .long("heading")
+        .help("The direction in which the user is moving in degrees, between 1 and 360.")
+        .takes_value(true)
+        .validator(heading_validator);
+    #[allow(non_snake_case)]
+    let HORIZONTAL_ACCURACY_ARG = Arg::with_name("horizontal_accuracy")
+        .long("horizontal-accuracy")
+        .help("The radius of uncertainty for the location in meters, between 0 and 1500.")
+        .takes_value(true)
+        .validator(horizontal_accuracy_validator);
+    #[allow(non_snake_case)]
+    let PROXIMITY_ALERT_RADIUS_ARG = Arg::with_name("proximity_alert_radius")
+        .long("proximity-alert-radius")
+        .help("The maximum distance in meters for proximity alerts, between 1 and 100000.")
+        .takes_value(true)
+        .validator(proximity_alert_radius_validator);
+    #[allow(non_snake_case)]
+    let MESSAGE_ID_ARG = Arg::with_name("message_id")
+        .long("message-id")
+        .help("The id of the message with live location.")
+        .takes_value(true)
+        .required(true)
+        .validator(positive_integer_validator);
+    #[allow(non_snake_case)]
+    let UNTIL_ARG = Arg::with_name("until")
+        .long("until")
+        .help("The duration after which the restriction is lifted, between 1m and 366d, e.g. 2h or 7d. Forever if absent.")
+        .takes_value(true)
+        .validator(until_validator);
+
+    #[allow(non_snake_case)]
+    let LANGUAGE_CODE_ARG = Arg::with_name("language_code")
+        .long("language-code")
+        .help("A two-letter ISO 639-1 language code. Applies to users with the language only if present.")
+        .takes_value(true)
+        .validator(language_code_validator)
+        .global(true);
+
+    #[allow(non_snake_case)]
+    let SPOOL_DIR_ARG = Arg::with_name("spool_dir")
+        .long("spool-dir")
+        .help("The directory of operations queued for tgcli daemon. Defaults to tgcli/spool in the local data directory.")
+        .takes_value(true)
+        .env("TGCLI_SPOOL_DIR");
+    #[allow(non_snake_case)]
+    let OUTBOX_DIR_ARG = Arg::with_name("outbox_dir")
+        .long("outbox-dir")
+        .help("The directory of failed sends kept with --outbox. Defaults to tgcli/outbox in the local data directory.")
+        .takes_value(true)
+        .env("TGCLI_OUTBOX_DIR");
+    #[allow(non_snake_case)]
+    let STATE_DIR_ARG = Arg::with_name("state_dir")
+        .long("state-dir")
+        .help("The directory of messages sent with --idempotency-key. Defaults to tgcli/state in the local data directory.")
+        .takes_value(true)
+        .env("TGCLI_STATE_DIR");
+    #[allow(non_snake_case)]
+    let LOCAL_SERVER_ARG = Arg::with_name("local_server")
+        .long("local-server")
+        .help("The Bot API server given with --api-url is a local one, which accepts uploads up to 2000 MB.")
+        .takes_value(false);
 
     app_from_crate!()
         .global_settings(&[
@@ -75,31 +244,414 @@ pub fn get_app() -> App<'static, 'static> {
             AppSettings::DeriveDisplayOrder,
         ])
         .settings(&[AppSettings::SubcommandRequiredElseHelp])
-        .args(&[Arg::with_name("verbose")
-            .short("v")
-            .multiple(true)
-            .takes_value(false)
-            .global(true)
-            .help("Sets the verbosity level.")])
+        .args(&[
+            Arg::with_name("verbose")
+                .short("v")
+                .multiple(true)
+                .takes_value(false)
+                .global(true)
+                .help("Sets the verbosity level."),
+            Arg::with_name("progress")
+                .long("progress")
+                .help("How the progress of file transfers is reported on stderr. Defaults to bar.")
+                .takes_value(true)
+                .possible_values(&["bar", "json", "none"])
+                .global(true),
+            Arg::with_name("dry_run")
+                .long("dry-run")
+                .help("Prints the request instead of sending it. Only bot send subcommands support it.")
+                .takes_value(false)
+                .global(true),
+            Arg::with_name("emit")
+                .long("emit")
+                .help("How the request is printed, which implies --dry-run. Defaults to json.")
+                .takes_value(true)
+                .possible_values(&["json", "curl", "http"])
+                .global(true),
+            Arg::with_name("show_token")
+                .long("show-token")
+                .help("Prints the bot token as is instead of redacting it with --dry-run.")
+                .takes_value(false)
+                .global(true),
+        ])
         .subcommands(vec![SubCommand::with_name("bot")
             .settings(&[AppSettings::SubcommandRequiredElseHelp])
             .about("Operations for bots.")
-            .args(&[Arg::with_name("token")
-                .long("token")
-                .short("t")
-                .help("Telegram bot token.")
-                .takes_value(true)
-                // an arg cannot be global and required at the same time for some reason
-                // REF https://github.com/clap-rs/clap/issues/1546
-                // .required(true)
-                .env("TELEGRAM_BOT_TOKEN")
-                .hide_env_values(true)
-                .global(true)])
-            .subcommands(vec![SubCommand::with_name("send")
-                .settings(&[AppSettings::SubcommandRequiredElseHelp])
-                .about("Sending operations for bots.")
-                .args(&[
-                    Arg::with_name("receiver")
+            .args(&[
+                Arg::with_name("token")
+                    .long("token")
+                    .short("t")
+                    .help("Telegram bot token.")
+                    .takes_value(true)
+                    // an arg cannot be global and required at the same time for some reason
+                    // REF https://github.com/clap-rs/clap/issues/1546
+                    // .required(true)
+                    .env("TELEGRAM_BOT_TOKEN")
+                    .hide_env_values(true)
+                    .global(true),
+                Arg::with_name("api_url")
+                    .long("api-url")
+                    .help("The URL of Bot API server. Defaults to the official one.")
+                    .takes_value(true)
+                    .env("TELEGRAM_API_URL")
+                    .validator(url_validator)
+                    .global(true),
+                LOCAL_SERVER_ARG.clone().global(true),
+            ])
+            .subcommands(vec![
+                SubCommand::with_name("send")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Sending operations for bots.")
+                    .args(&[
+                        Arg::with_name("receiver")
+                            .short("r")
+                            .long("receiver")
+                            .help("The chat ID of receiver.")
+                            .takes_value(true)
+                            // an arg cannot be global and required at the same time for some reason
+                            // REF https://github.com/clap-rs/clap/issues/1546
+                            // .required(true)
+                            .global(true),
+                        Arg::with_name("format")
+                            .long("format")
+                            .help("Format of the message.")
+                            .takes_value(true)
+                            .possible_values(&["markdown", "html", "commonmark"])
+                            .default_value("markdown")
+                            .global(true),
+                        Arg::with_name("sanitize")
+                            .long("sanitize")
+                            .takes_value(false)
+                            .global(true)
+                            .help("Rewrites or strips the tags Telegram does not support instead of failing. Requires --format html."),
+                        Arg::with_name("silent")
+                            .long("silent")
+                            .takes_value(false)
+                            .global(true)
+                            .help("Will send a silent notification to the user if present."),
+                        Arg::with_name("at")
+                            .long("at")
+                            .help("Sends at a time such as 2026-11-01T09:00, in local time unless an offset is given.")
+                            .takes_value(true)
+                            .conflicts_with_all(&["in", "cron"])
+                            .validator(datetime_validator)
+                            .global(true),
+                        Arg::with_name("in")
+                            .long("in")
+                            .help("Sends after a duration such as 30m or 1h30m.")
+                            .takes_value(true)
+                            .conflicts_with("cron")
+                            .validator(duration_validator)
+                            .global(true),
+                        Arg::with_name("cron")
+                            .long("cron")
+                            .help("Sends whenever a cron pattern such as \"0 9 * * 1-5\" matches. Requires --queue.")
+                            .takes_value(true)
+                            .requires("queue")
+                            .validator(cron_validator)
+                            .global(true),
+                        Arg::with_name("queue")
+                            .long("queue")
+                            .help("Writes the operation to the spool directory for tgcli daemon instead of waiting.")
+                            .takes_value(false)
+                            .global(true),
+                        SPOOL_DIR_ARG.clone().global(true),
+                        Arg::with_name("outbox")
+                            .long("outbox")
+                            .help("Keeps the request in the outbox to be flushed later if it fails because of the network or Telegram.")
+                            .takes_value(false)
+                            .global(true),
+                        OUTBOX_DIR_ARG.clone().global(true),
+                        Arg::with_name("idempotency_key")
+                            .long("idempotency-key")
+                            .help("Sends the message once for the key. A repeat prints the message sent before instead.")
+                            .takes_value(true)
+                            .global(true),
+                        Arg::with_name("idempotency_ttl")
+                            .long("idempotency-ttl")
+                            .help("How long a key is remembered, e.g. 30m or 7d. Defaults to 24h.")
+                            .takes_value(true)
+                            .requires("idempotency_key")
+                            .validator(duration_validator)
+                            .global(true),
+                        Arg::with_name("upsert")
+                            .long("upsert")
+                            .help("Edits the message sent before with the key instead of printing it.")
+                            .takes_value(false)
+                            .requires("idempotency_key")
+                            .global(true),
+                        STATE_DIR_ARG.global(true),
+                    ])
+                    .subcommands(vec![
+                        SubCommand::with_name("message")
+                            .about("Send a message with a bot.")
+                            .args(&[
+                                Arg::with_name("message")
+                                    .help("A message to be sent.")
+                                    .required_unless("template"),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                                Arg::with_name("entities_json")
+                                    .long("entities-json")
+                                    .help("A JSON file with an array of entities to style the message with instead of --format, with offsets in UTF-16 code units.")
+                                    .takes_value(true)
+                                    .conflicts_with("template")
+                                    .validator(file_validator),
+                                Arg::with_name("sticky")
+                                    .long("sticky")
+                                    .help("Sends the message named so once and edits it afterwards, e.g. for a status dashboard.")
+                                    .takes_value(true)
+                                    .conflicts_with("idempotency_key"),
+                            ]),
+                        SubCommand::with_name("code")
+                            .about("Send code or a log in a code block with a bot.")
+                            .args(&[
+                                Arg::with_name("file")
+                                    .help("A file to send the content of.")
+                                    .required_unless("stdin")
+                                    .validator(file_validator),
+                                Arg::with_name("stdin")
+                                    .long("stdin")
+                                    .help("Reads the code from the standard input instead.")
+                                    .takes_value(false)
+                                    .conflicts_with_all(&["file", "queue"]),
+                                Arg::with_name("lang")
+                                    .long("lang")
+                                    .help("The language of the code. Guessed from the extension of the file if absent.")
+                                    .takes_value(true),
+                                Arg::with_name("tail")
+                                    .long("tail")
+                                    .help("Sends only the last lines of the code, e.g. of a log.")
+                                    .takes_value(true)
+                                    .validator(positive_integer_validator),
+                                Arg::with_name("overflow")
+                                    .long("overflow")
+                                    .help("Splits code over the limit of a message across messages or sends it as a document.")
+                                    .takes_value(true)
+                                    .possible_values(&["split", "document"])
+                                    .default_value("split"),
+                            ]),
+                        SubCommand::with_name("document")
+                            .about("Send a document with a bot.")
+                            .args(&[
+                                FILE_ARG.clone(),
+                                CAPTION_ARG.clone(),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                                THUMBNAIL_ARG.clone(),
+                                AUTO_THUMBNAIL_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("photo")
+                            .about("Send a photo with a bot.")
+                            .args(&[
+                                FILE_ARG.clone().validator(image_validator),
+                                CAPTION_ARG.clone(),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("video")
+                            .about("Send a video with a bot.")
+                            .args(&[
+                                FILE_ARG.clone().validator(video_validator),
+                                CAPTION_ARG.clone(),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                                Arg::with_name("width")
+                                    .long("width")
+                                    .help("The width of the video. Read from the file if absent.")
+                                    .takes_value(true)
+                                    .validator(positive_integer_validator),
+                                Arg::with_name("height")
+                                    .long("height")
+                                    .help("The height of the video. Read from the file if absent.")
+                                    .takes_value(true)
+                                    .validator(positive_integer_validator),
+                                DURATION_ARG.clone(),
+                                Arg::with_name("supports_streaming")
+                                    .long("supports-streaming")
+                                    .takes_value(false)
+                                    .help(
+                                        "Will mark the video as suitable for streaming if present.",
+                                    ),
+                                THUMBNAIL_ARG.clone(),
+                                AUTO_THUMBNAIL_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("animation")
+                            .about("Send an animation (GIF or soundless video) with a bot.")
+                            .args(&[
+                                FILE_ARG.clone().validator(animation_validator),
+                                CAPTION_ARG.clone(),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                                Arg::with_name("width")
+                                    .long("width")
+                                    .help("The width of the animation. Read from the file if absent.")
+                                    .takes_value(true)
+                                    .validator(positive_integer_validator),
+                                Arg::with_name("height")
+                                    .long("height")
+                                    .help("The height of the animation. Read from the file if absent.")
+                                    .takes_value(true)
+                                    .validator(positive_integer_validator),
+                                DURATION_ARG.clone(),
+                                THUMBNAIL_ARG.clone(),
+                                AUTO_THUMBNAIL_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("audio")
+                            .about("Send an audio with a bot.")
+                            .args(&[
+                                FILE_ARG.clone().validator(audio_validator),
+                                CAPTION_ARG.clone(),
+                                TEMPLATE_ARG.clone(),
+                                VAR_ARG.clone(),
+                                VARS_JSON_ARG.clone(),
+                                Arg::with_name("performer")
+                                    .long("performer")
+                                    .help("The performer of the audio.")
+                                    .takes_value(true),
+                                Arg::with_name("title")
+                                    .long("title")
+                                    .help("The title of the audio.")
+                                    .takes_value(true),
+                                DURATION_ARG.clone(),
+                                THUMBNAIL_ARG.clone(),
+                                AUTO_THUMBNAIL_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("poll")
+                            .about("Send a poll with a bot.")
+                            .args(&[
+                                Arg::with_name("question")
+                                    .help("The question to ask.")
+                                    .takes_value(true)
+                                    .required(true)
+                                    .validator(poll_question_validator),
+                                Arg::with_name("option")
+                                    .help("An option for the question.")
+                                    .short("o")
+                                    .required(true)
+                                    .multiple(true)
+                                    .min_values(2)
+                                    .max_values(10)
+                                    .validator(poll_option_validator),
+                            ]),
+                        SubCommand::with_name("location")
+                            .about("Send a location with a bot.")
+                            .args(&[
+                                LATITUDE_ARG.clone(),
+                                LONGITUDE_ARG.clone(),
+                                LIVE_PERIOD_ARG.clone(),
+                                HEADING_ARG.clone().requires("live_period"),
+                                HORIZONTAL_ACCURACY_ARG.clone(),
+                                PROXIMITY_ALERT_RADIUS_ARG.clone().requires("live_period"),
+                            ]),
+                    ]),
+                SubCommand::with_name("chat")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Chat administration operations for bots.")
+                    .args(&[Arg::with_name("chat")
+                        .short("c")
+                        .long("chat")
+                        .help("The chat ID or the username of a channel, e.g. @channel.")
+                        .takes_value(true)
+                        // an arg cannot be global and required at the same time for some reason
+                        // REF https://github.com/clap-rs/clap/issues/1546
+                        // .required(true)
+                        .global(true)])
+                    .subcommands(vec![
+                        SubCommand::with_name("pin")
+                            .about("Pin a message in a chat.")
+                            .args(&[
+                                MESSAGE_ID_ARG.clone().help("The id of the message to pin."),
+                                Arg::with_name("silent")
+                                    .long("silent")
+                                    .takes_value(false)
+                                    .help("Will not notify the members of the chat if present."),
+                            ]),
+                        SubCommand::with_name("unpin")
+                            .about("Unpin a message in a chat.")
+                            .args(&[MESSAGE_ID_ARG
+                                .clone()
+                                .required(false)
+                                .help("The id of the message to unpin. The most recent pinned message if absent.")]),
+                        SubCommand::with_name("unpin-all")
+                            .about("Unpin all messages in a chat."),
+                        SubCommand::with_name("set-title")
+                            .about("Change the title of a chat.")
+                            .args(&[Arg::with_name("title")
+                                .help("The new title, between 1 and 128 characters.")
+                                .required(true)
+                                .validator(chat_title_validator)]),
+                        SubCommand::with_name("set-description")
+                            .about("Change the description of a chat.")
+                            .args(&[Arg::with_name("description")
+                                .help("The new description, up to 255 characters. Removes the description if absent.")
+                                .validator(chat_description_validator)]),
+                        SubCommand::with_name("set-photo")
+                            .about("Change the photo of a chat.")
+                            .args(&[FILE_ARG.clone().validator(image_validator)]),
+                        SubCommand::with_name("delete-photo")
+                            .about("Delete the photo of a chat."),
+                        SubCommand::with_name("export-invite-link")
+                            .about("Generate a new primary invite link for a chat. The previous one is revoked."),
+                        SubCommand::with_name("create-invite-link")
+                            .about("Create an additional invite link for a chat.")
+                            .args(&[
+                                Arg::with_name("name")
+                                    .long("name")
+                                    .help("The name of the link, visible to administrators only.")
+                                    .takes_value(true),
+                                Arg::with_name("expire_in")
+                                    .long("expire-in")
+                                    .help("The duration after which the link expires, e.g. 2h or 7d.")
+                                    .takes_value(true)
+                                    .validator(duration_validator),
+                                Arg::with_name("member_limit")
+                                    .long("member-limit")
+                                    .help("How many users can join with the link, between 1 and 99999.")
+                                    .takes_value(true)
+                                    .validator(member_limit_validator),
+                                Arg::with_name("join_request")
+                                    .long("join-request")
+                                    .help("Users joining with the link need to be approved by administrators.")
+                                    .takes_value(false)
+                                    .conflicts_with("member_limit"),
+                            ]),
+                        SubCommand::with_name("leave")
+                            .about("Make the bot leave a chat."),
+                        SubCommand::with_name("get-administrators")
+                            .about("List the administrators of a chat, a JSON object per line."),
+                        SubCommand::with_name("get-member-count")
+                            .about("Print the number of members in a chat."),
+                    ]),
+                SubCommand::with_name("file")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("File operations for bots.")
+                    .subcommands(vec![SubCommand::with_name("get")
+                        .about("Download a file sent to a bot.")
+                        .args(&[
+                            Arg::with_name("file_id")
+                                .help("The id of the file to be downloaded.")
+                                .required_unless("stdin"),
+                            Arg::with_name("stdin")
+                                .long("stdin")
+                                .help("Downloads every attachment of an update or message JSON read from stdin.")
+                                .takes_value(false)
+                                .conflicts_with("file_id"),
+                            Arg::with_name("output")
+                                .short("o")
+                                .long("output")
+                                .help("The path to save the file. A directory if --stdin is present.")
+                                .takes_value(true),
+                        ])]),
+                SubCommand::with_name("location")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Live location operations for bots.")
+                    .args(&[Arg::with_name("receiver")
                         .short("r")
                         .long("receiver")
                         .help("The chat ID of receiver.")
@@ -107,113 +659,396 @@ pub fn get_app() -> App<'static, 'static> {
                         // an arg cannot be global and required at the same time for some reason
                         // REF https://github.com/clap-rs/clap/issues/1546
                         // .required(true)
-                        .global(true),
-                    Arg::with_name("format")
-                        .long("format")
-                        .help("Format of the message.")
+                        .global(true)])
+                    .subcommands(vec![
+                        SubCommand::with_name("update")
+                            .about("Update a live location sent by a bot.")
+                            .args(&[
+                                MESSAGE_ID_ARG.clone(),
+                                LATITUDE_ARG.clone(),
+                                LONGITUDE_ARG.clone(),
+                                HEADING_ARG.clone(),
+                                HORIZONTAL_ACCURACY_ARG.clone(),
+                                PROXIMITY_ALERT_RADIUS_ARG.clone(),
+                            ]),
+                        SubCommand::with_name("stop")
+                            .about("Stop updating a live location sent by a bot.")
+                            .args(std::slice::from_ref(&MESSAGE_ID_ARG)),
+                        SubCommand::with_name("follow")
+                            .about("Replay a recorded track as a live location.")
+                            .args(&[
+                                Arg::with_name("from_gpx")
+                                    .long("from-gpx")
+                                    .help("A GPX file to replay.")
+                                    .takes_value(true)
+                                    .validator(file_validator),
+                                Arg::with_name("from_nmea")
+                                    .long("from-nmea")
+                                    .help("An NMEA file to replay.")
+                                    .takes_value(true)
+                                    .validator(file_validator),
+                                LIVE_PERIOD_ARG.clone(),
+                                Arg::with_name("silent")
+                                    .long("silent")
+                                    .takes_value(false)
+                                    .help(
+                                        "Will send a silent notification to the user if present.",
+                                    ),
+                            ])
+                            .group(
+                                ArgGroup::with_name("track")
+                                    .args(&["from_gpx", "from_nmea"])
+                                    .required(true),
+                            ),
+                    ]),
+                SubCommand::with_name("member")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Chat member moderation operations for bots.")
+                    .args(&[
+                        Arg::with_name("chat")
+                            .short("c")
+                            .long("chat")
+                            .help("The chat ID or the username of a channel, e.g. @channel.")
+                            .takes_value(true)
+                            // an arg cannot be global and required at the same time for some reason
+                            // REF https://github.com/clap-rs/clap/issues/1546
+                            // .required(true)
+                            .global(true),
+                        Arg::with_name("user")
+                            .short("u")
+                            .long("user")
+                            .help("The id of a user. Can be given multiple times.")
+                            .takes_value(true)
+                            .multiple(true)
+                            .number_of_values(1)
+                            .validator(positive_integer_validator)
+                            .global(true),
+                        Arg::with_name("users_from")
+                            .long("users-from")
+                            .help("A file with a user id per line, or - for stdin. Anything after # is a comment.")
+                            .takes_value(true)
+                            .global(true),
+                    ])
+                    .subcommands(vec![
+                        SubCommand::with_name("ban")
+                            .about("Ban users from a chat.")
+                            .args(&[
+                                UNTIL_ARG.clone(),
+                                Arg::with_name("revoke_messages")
+                                    .long("revoke-messages")
+                                    .help("Deletes all messages of the users in the chat.")
+                                    .takes_value(false),
+                            ]),
+                        SubCommand::with_name("unban")
+                            .about("Unban users from a chat. They are not added back, but can join again.")
+                            .args(&[Arg::with_name("only_if_banned")
+                                .long("only-if-banned")
+                                .help("Does nothing for users who are not banned. Otherwise, members are removed from the chat.")
+                                .takes_value(false)]),
+                        SubCommand::with_name("restrict")
+                            .about("Restrict users in a supergroup. Every permission not allowed is revoked.")
+                            .args(&[
+                                UNTIL_ARG.clone(),
+                                Arg::with_name("allow")
+                                    .long("allow")
+                                    .help("The permissions users keep, comma-separated.")
+                                    .takes_value(true)
+                                    .multiple(true)
+                                    .use_delimiter(true)
+                                    .possible_values(&[
+                                        "messages",
+                                        "audios",
+                                        "documents",
+                                        "photos",
+                                        "videos",
+                                        "video-notes",
+                                        "voice-notes",
+                                        "polls",
+                                        "other-messages",
+                                        "web-page-previews",
+                                        "change-info",
+                                        "invite-users",
+                                        "pin-messages",
+                                        "manage-topics",
+                                    ]),
+                            ]),
+                        SubCommand::with_name("promote")
+                            .about("Promote users to administrators. Every right not given is revoked, so no rights demote them.")
+                            .args(&[
+                                Arg::with_name("right")
+                                    .long("right")
+                                    .help("The rights users get, comma-separated.")
+                                    .takes_value(true)
+                                    .multiple(true)
+                                    .use_delimiter(true)
+                                    .possible_values(&[
+                                        "manage-chat",
+                                        "delete-messages",
+                                        "manage-video-chats",
+                                        "restrict-members",
+                                        "promote-members",
+                                        "change-info",
+                                        "invite-users",
+                                        "post-messages",
+                                        "edit-messages",
+                                        "pin-messages",
+                                        "manage-topics",
+                                    ]),
+                                Arg::with_name("anonymous")
+                                    .long("anonymous")
+                                    .help("Hides the administrators in the chat.")
+                                    .takes_value(false),
+                            ]),
+                        SubCommand::with_name("info")
+                            .about("Print the membership of users in a chat, a JSON object per line."),
+                    ]),
+                SubCommand::with_name("me")
+                    .about("Verify the token and print the bot as a JSON object, including its capabilities."),
+                SubCommand::with_name("commands")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Command menu operations for bots.")
+                    .args(&[
+                        Arg::with_name("scope")
+                            .long("scope")
+                            .help("The users who see the commands. Defaults to default.")
+                            .takes_value(true)
+                            .possible_values(&[
+                                "default",
+                                "all-private-chats",
+                                "all-group-chats",
+                                "all-chat-administrators",
+                                "chat",
+                                "chat-administrators",
+                                "chat-member",
+                            ])
+                            .global(true),
+                        Arg::with_name("chat")
+                            .short("c")
+                            .long("chat")
+                            .help("The chat ID or the username of a channel, e.g. @channel. Required for chat scopes.")
+                            .takes_value(true)
+                            .global(true),
+                        Arg::with_name("user")
+                            .short("u")
+                            .long("user")
+                            .help("The id of a user. Required for chat-member scope.")
+                            .takes_value(true)
+                            .validator(positive_integer_validator)
+                            .global(true),
+                        LANGUAGE_CODE_ARG.clone(),
+                    ])
+                    .subcommands(vec![
+                        SubCommand::with_name("set")
+                            .about("Set the commands from a TOML or JSON file.")
+                            .args(&[FILE_ARG
+                                .clone()
+                                .help("A TOML or JSON file with the commands, decided by the extension.")]),
+                        SubCommand::with_name("get")
+                            .about("Print the commands as JSON, which can be given to set."),
+                        SubCommand::with_name("delete")
+                            .about("Delete the commands. Users see the commands of a broader scope instead."),
+                    ]),
+                SubCommand::with_name("profile")
+                    .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                    .about("Profile operations for bots.")
+                    .args(std::slice::from_ref(&LANGUAGE_CODE_ARG))
+                    .subcommands(vec![
+                        SubCommand::with_name("set-name")
+                            .about("Change the name of the bot.")
+                            .args(&[Arg::with_name("value")
+                                .help("The new name, up to 64 characters. Removes the name for the language if absent.")
+                                .validator(bot_name_validator)]),
+                        SubCommand::with_name("set-description")
+                            .about("Change the description shown in an empty chat with the bot.")
+                            .args(&[Arg::with_name("value")
+                                .help("The new description, up to 512 characters. Removes the description if absent.")
+                                .validator(bot_description_validator)]),
+                        SubCommand::with_name("set-short-description")
+                            .about("Change the short description shown on the profile page of the bot.")
+                            .args(&[Arg::with_name("value")
+                                .help("The new short description, up to 120 characters. Removes the short description if absent.")
+                                .validator(bot_short_description_validator)]),
+                    ]),
+            ])])
+        .subcommand(SubCommand::with_name("batch")
+                .about("Runs many operations from a JSON Lines or YAML manifest.")
+                .args(&[
+                    Arg::with_name("manifest")
+                        .required(true)
+                        .help("A JSON Lines file with an operation per line or a YAML file with a list of them. - reads JSON Lines from stdin."),
+                    Arg::with_name("jobs")
+                        .long("jobs")
+                        .short("j")
+                        .help("How many operations run at the same time. Defaults to 1.")
+                        .takes_value(true)
+                        .validator(positive_integer_validator),
+                    Arg::with_name("continue_on_error")
+                        .long("continue-on-error")
+                        .help("Runs the rest of operations after a failure.")
+                        .takes_value(false),
+                    Arg::with_name("token")
+                        .long("token")
+                        .short("t")
+                        .help("Telegram bot token.")
                         .takes_value(true)
-                        .possible_values(&["markdown", "html"])
-                        .default_value("markdown")
-                        .global(true),
-                    Arg::with_name("silent")
-                        .long("silent")
-                        .takes_value(false)
-                        .global(true)
-                        .help("Will send a silent notification to the user if present."),
+                        .env("TELEGRAM_BOT_TOKEN")
+                        .hide_env_values(true),
+                    Arg::with_name("api_url")
+                        .long("api-url")
+                        .help("The URL of Bot API server. Defaults to the official one.")
+                        .takes_value(true)
+                        .env("TELEGRAM_API_URL")
+                        .validator(url_validator),
+                    LOCAL_SERVER_ARG.clone(),
                 ])
+        )
+        .subcommand(SubCommand::with_name("daemon")
+                .about("Runs operations queued with --queue when they are due.")
+                .args(&[
+                    SPOOL_DIR_ARG,
+                    Arg::with_name("once")
+                        .long("once")
+                        .help("Runs the operations that are due and exits.")
+                        .takes_value(false),
+                    Arg::with_name("token")
+                        .long("token")
+                        .short("t")
+                        .help("Telegram bot token.")
+                        .takes_value(true)
+                        .env("TELEGRAM_BOT_TOKEN")
+                        .hide_env_values(true),
+                    Arg::with_name("api_url")
+                        .long("api-url")
+                        .help("The URL of Bot API server. Defaults to the official one.")
+                        .takes_value(true)
+                        .env("TELEGRAM_API_URL")
+                        .validator(url_validator),
+                    LOCAL_SERVER_ARG.clone(),
+                ])
+        )
+        .subcommand(SubCommand::with_name("outbox")
+                .settings(&[AppSettings::SubcommandRequiredElseHelp])
+                .about("Operations for failed sends kept with --outbox.")
                 .subcommands(vec![
-                    SubCommand::with_name("message")
-                        .about("Send a message with a bot.")
-                        .args(&[Arg::with_name("message")
-                            .help("A message to be sent.")
-                            .required(true)]),
-                    SubCommand::with_name("document")
-                        .about("Send a document with a bot.")
+                    SubCommand::with_name("flush")
+                        .about("Sends the requests in the outbox in the order they were kept.")
                         .args(&[
-                            FILE_ARG.clone(),
-                            CAPTION_ARG.clone(),
-                            Arg::with_name("thumbnail")
-                                .long("thumbnail")
-                                .help("A thumbnail for the document.")
+                            OUTBOX_DIR_ARG.clone(),
+                            Arg::with_name("retries")
+                                .long("retries")
+                                .help("How many times a request failing because of the network or Telegram is retried before the flush stops. Defaults to 3.")
                                 .takes_value(true)
-                                .validator(image_validator),
-                        ]),
-                    SubCommand::with_name("photo")
-                        .about("Send a photo with a bot.")
-                        .args(&[
-                            FILE_ARG.clone().validator(image_validator),
-                            CAPTION_ARG.clone(),
-                        ]),
-                    SubCommand::with_name("video")
-                        .about("Send a video with a bot.")
-                        .args(&[
-                            FILE_ARG.clone().validator(video_validator),
-                            CAPTION_ARG.clone(),
-                            // aspect ratio does not work
-                            // currently, reqwest can only send multipart/form-data with
-                            // files and texts, not numeric values
-                            // which blocks this thing
-                            // Arg::with_name("horizontal")
-                            //     .help("Horizontal aspect ratio of the video.")
-                            //     .takes_value(true)
-                            //     .short("x")
-                            //     .long("horizontal")
-                            //     .validator(positive_integer_validator),
-                            // Arg::with_name("vertical")
-                            //     .help("Vertical aspect ratio of the video.")
-                            //     .takes_value(true)
-                            //     .short("y")
-                            //     .long("vertical")
-                            //     .validator(positive_integer_validator),
-                        ]),
-                    SubCommand::with_name("audio")
-                        .about("Send an audio with a bot.")
-                        .args(&[
-                            FILE_ARG.clone().validator(audio_validator),
-                            CAPTION_ARG.clone(),
-                            Arg::with_name("performer")
-                                .long("performer")
-                                .help("The performer of the audio.")
-                                .takes_value(true),
-                            Arg::with_name("title")
-                                .long("title")
-                                .help("The title of the audio.")
-                                .takes_value(true),
-                        ]),
-                    SubCommand::with_name("poll")
-                        .about("Send a poll with a bot.")
-                        .args(&[
-                            Arg::with_name("question")
-                                .help("The question to ask.")
+                                .validator(retries_validator),
+                            Arg::with_name("token")
+                                .long("token")
+                                .short("t")
+                                .help("Telegram bot token.")
                                 .takes_value(true)
-                                .required(true)
-                                .validator(poll_question_validator),
-                            Arg::with_name("option")
-                                .help("An option for the question.")
-                                .short("o")
-                                .required(true)
-                                .multiple(true)
-                                .min_values(2)
-                                .max_values(10)
-                                .validator(poll_option_validator),
+                                .env("TELEGRAM_BOT_TOKEN")
+                                .hide_env_values(true),
+                            Arg::with_name("api_url")
+                                .long("api-url")
+                                .help("The URL of Bot API server. Defaults to the official one.")
+                                .takes_value(true)
+                                .env("TELEGRAM_API_URL")
+                                .validator(url_validator),
+                            LOCAL_SERVER_ARG.clone(),
                         ]),
-                    SubCommand::with_name("location")
-                        .about("Send a location with a bot.")
+                    SubCommand::with_name("list")
+                        .about("Lists the requests in the outbox.")
+                        .args(std::slice::from_ref(&OUTBOX_DIR_ARG)),
+                    SubCommand::with_name("drop")
+                        .about("Removes requests from the outbox without sending them.")
                         .args(&[
-                            Arg::with_name("latitude")
-                                .help("The latitude of the location.")
-                                .short("x")
-                                .takes_value(true)
-                                .required(true)
-                                .validator(float_validator),
-                            Arg::with_name("longitude")
-                                .help("The longitude of the location.")
-                                .short("y")
-                                .takes_value(true)
-                                .required(true)
-                                .validator(float_validator),
+                            OUTBOX_DIR_ARG,
+                            Arg::with_name("id")
+                                .help("The ids of the requests as listed by outbox list.")
+                                .multiple(true)
+                                .required_unless("all"),
+                            Arg::with_name("all")
+                                .long("all")
+                                .help("Removes all the requests.")
+                                .takes_value(false)
+                                .conflicts_with("id"),
                         ]),
-                ])])])
+                ])
+        )
+}
+
+/// The names of subcommands down to the last one and its matches.
+fn leaf_matches<'a>(matches: &'a ArgMatches<'static>) -> (Vec<&'a str>, &'a ArgMatches<'static>) {
+    let mut path = Vec::new();
+    let mut leaf = matches;
+    while let (name, Some(subc)) = leaf.subcommand() {
+        path.push(name);
+        leaf = subc;
+    }
+    (path, leaf)
+}
+
+/// Waits until a scheduled operation is due or queues it for `daemon`, in
+/// which case it returns true. A dry run is printed right away.
+fn schedule(matches: &ArgMatches<'static>) -> Result<bool, OperationError> {
+    let (_, leaf) = leaf_matches(matches);
+    if leaf.is_present("dry_run") || leaf.is_present("emit") {
+        return Ok(false);
+    }
+
+    let params = match schedule_params(leaf) {
+        Ok(Some(p)) => p,
+        Ok(None) => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    let spool = match &params.queue {
+        Some(s) => s,
+        None => {
+            params.wait();
+            return Ok(false);
+        }
+    };
+
+    let dir = match env::current_dir() {
+        Ok(d) => d,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                "Could not get current working directory.",
+                Some(e),
+            ))
+        }
+    };
+    let entry = SpoolEntry::new(
+        spooled_args(env::args().collect(), leaf.value_of("token")),
+        dir,
+        params.at.into(),
+        params.cron.clone(),
+    );
+    let id = entry.queue(spool)?;
+
+    info!("📥 Queued {} for {}.", id, params.at.to_rfc3339());
+    println!("{}", json!({ "id": id, "at": params.at.to_rfc3339() }));
+    Ok(true)
+}
+
+/// Fails if `--dry-run` is given to a subcommand that cannot print its request,
+/// so that it is never sent by mistake.
+fn check_dry_run(matches: &ArgMatches<'static>) -> Result<(), OperationError> {
+    let (path, leaf) = leaf_matches(matches);
+    let dry_run = leaf.is_present("dry_run") || leaf.is_present("emit");
+    // a batch passes it on to its operations
+    if dry_run && !path.starts_with(&["bot", "send"]) && path != ["batch"] {
+        return Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!(
+                "`{}` does not support `--dry-run` or `--emit`.",
+                path.join(" ")
+            ),
+            None::<&str>,
+        ));
+    }
+
+    Ok(())
 }
 
 pub fn match_app(app: App<'static, 'static>) -> Result<(), OperationError> {
@@ -231,10 +1066,31 @@ pub fn match_app(app: App<'static, 'static>) -> Result<(), OperationError> {
         }
     };
 
+    match schedule(&matches) {
+        Ok(true) => return Ok(()),
+        Ok(false) => (),
+        Err(e) => return Err(e),
+    };
+
+    dispatch(&matches)
+}
+
+/// Runs the operation of parsed arguments.
+pub fn dispatch(matches: &ArgMatches<'static>) -> Result<(), OperationError> {
+    match check_dry_run(matches) {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
     match matches.subcommand() {
+        ("batch", Some(batch_subc)) => handle_operation!(batch_subc, BatchOperation),
         ("bot", Some(bot_subc)) => match bot_subc.subcommand() {
             ("send", Some(send_subc)) => match send_subc.subcommand() {
+                ("animation", Some(animation_subc)) => {
+                    handle_operation!(animation_subc, SendAnimationOperation)
+                }
                 ("audio", Some(audio_subc)) => handle_operation!(audio_subc, SendAudioOperation),
+                ("code", Some(code_subc)) => handle_operation!(code_subc, SendCodeOperation),
                 ("document", Some(document_subc)) => {
                     handle_operation!(document_subc, SendDocumentOperation)
                 }
@@ -249,6 +1105,91 @@ pub fn match_app(app: App<'static, 'static>) -> Result<(), OperationError> {
                 ("video", Some(video_subc)) => handle_operation!(video_subc, SendVideoOperation),
                 (&_, _) => unimplemented!(),
             },
+            ("commands", Some(commands_subc)) => match commands_subc.subcommand() {
+                ("delete", Some(delete_subc)) => {
+                    handle_operation!(delete_subc, DeleteCommandsOperation)
+                }
+                ("get", Some(get_subc)) => handle_operation!(get_subc, GetCommandsOperation),
+                ("set", Some(set_subc)) => handle_operation!(set_subc, SetCommandsOperation),
+                (&_, _) => unimplemented!(),
+            },
+            ("chat", Some(chat_subc)) => match chat_subc.subcommand() {
+                ("create-invite-link", Some(create_subc)) => {
+                    handle_operation!(create_subc, CreateInviteLinkOperation)
+                }
+                ("delete-photo", Some(delete_subc)) => {
+                    handle_operation!(delete_subc, DeleteChatPhotoOperation)
+                }
+                ("export-invite-link", Some(export_subc)) => {
+                    handle_operation!(export_subc, ExportInviteLinkOperation)
+                }
+                ("get-administrators", Some(administrators_subc)) => {
+                    handle_operation!(administrators_subc, GetAdministratorsOperation)
+                }
+                ("get-member-count", Some(count_subc)) => {
+                    handle_operation!(count_subc, GetMemberCountOperation)
+                }
+                ("leave", Some(leave_subc)) => handle_operation!(leave_subc, LeaveChatOperation),
+                ("pin", Some(pin_subc)) => handle_operation!(pin_subc, PinMessageOperation),
+                ("set-description", Some(description_subc)) => {
+                    handle_operation!(description_subc, SetChatDescriptionOperation)
+                }
+                ("set-photo", Some(photo_subc)) => {
+                    handle_operation!(photo_subc, SetChatPhotoOperation)
+                }
+                ("set-title", Some(title_subc)) => {
+                    handle_operation!(title_subc, SetChatTitleOperation)
+                }
+                ("unpin", Some(unpin_subc)) => handle_operation!(unpin_subc, UnpinMessageOperation),
+                ("unpin-all", Some(unpin_all_subc)) => {
+                    handle_operation!(unpin_all_subc, UnpinAllMessagesOperation)
+                }
+                (&_, _) => unimplemented!(),
+            },
+            ("file", Some(file_subc)) => match file_subc.subcommand() {
+                ("get", Some(get_subc)) => handle_operation!(get_subc, GetFileOperation),
+                (&_, _) => unimplemented!(),
+            },
+            ("location", Some(location_subc)) => match location_subc.subcommand() {
+                ("follow", Some(follow_subc)) => {
+                    handle_operation!(follow_subc, FollowLocationOperation)
+                }
+                ("stop", Some(stop_subc)) => handle_operation!(stop_subc, StopLocationOperation),
+                ("update", Some(update_subc)) => {
+                    handle_operation!(update_subc, UpdateLocationOperation)
+                }
+                (&_, _) => unimplemented!(),
+            },
+            ("me", Some(me_subc)) => handle_operation!(me_subc, GetMeOperation),
+            ("member", Some(member_subc)) => match member_subc.subcommand() {
+                ("ban", Some(ban_subc)) => handle_operation!(ban_subc, BanMemberOperation),
+                ("info", Some(info_subc)) => handle_operation!(info_subc, GetMemberOperation),
+                ("promote", Some(promote_subc)) => {
+                    handle_operation!(promote_subc, PromoteMemberOperation)
+                }
+                ("restrict", Some(restrict_subc)) => {
+                    handle_operation!(restrict_subc, RestrictMemberOperation)
+                }
+                ("unban", Some(unban_subc)) => handle_operation!(unban_subc, UnbanMemberOperation),
+                (&_, _) => unimplemented!(),
+            },
+            ("profile", Some(profile_subc)) => match profile_subc.subcommand() {
+                ("set-description", Some(description_subc)) => {
+                    handle_operation!(description_subc, SetDescriptionOperation)
+                }
+                ("set-name", Some(name_subc)) => handle_operation!(name_subc, SetNameOperation),
+                ("set-short-description", Some(short_description_subc)) => {
+                    handle_operation!(short_description_subc, SetShortDescriptionOperation)
+                }
+                (&_, _) => unimplemented!(),
+            },
+            (&_, _) => unimplemented!(),
+        },
+        ("daemon", Some(daemon_subc)) => handle_operation!(daemon_subc, DaemonOperation),
+        ("outbox", Some(outbox_subc)) => match outbox_subc.subcommand() {
+            ("drop", Some(drop_subc)) => handle_operation!(drop_subc, DropOutboxOperation),
+            ("flush", Some(flush_subc)) => handle_operation!(flush_subc, FlushOutboxOperation),
+            ("list", Some(list_subc)) => handle_operation!(list_subc, ListOutboxOperation),
             (&_, _) => unimplemented!(),
         },
         (&_, _) => unimplemented!(),
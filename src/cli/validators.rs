@@ -3,6 +3,8 @@ use std::{env::current_dir, path::PathBuf};
 use mime::Name;
 use mime_guess::MimeGuess;
 
+use crate::convert::{parse_datetime, parse_duration};
+
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -29,6 +31,16 @@ pub fn caption_validator(value: String) -> ValidatorResult {
     Ok(())
 }
 
+pub fn template_var_validator(value: String) -> ValidatorResult {
+    match value.split_once('=') {
+        Some((key, _)) if !key.is_empty() => Ok(()),
+        _ => Err(format!(
+            "Value must be in key=value form.\nValue: {}",
+            value
+        )),
+    }
+}
+
 pub fn file_validator(value: String) -> ValidatorResult {
     let cwd = match current_dir() {
         Ok(d) => d,
@@ -153,3 +165,154 @@ pub fn poll_option_validator(value: String) -> ValidatorResult {
         _ => Ok(()),
     }
 }
+
+fn ranged_integer_validator(value: String, min: usize, max: usize) -> ValidatorResult {
+    match value.parse::<usize>() {
+        Ok(v) if (min..=max).contains(&v) => Ok(()),
+        _ => Err(format!(
+            "Value must be an integer between {min} and {max}.\nValue: {}",
+            value,
+            min = min,
+            max = max,
+        )),
+    }
+}
+
+pub fn retries_validator(value: String) -> ValidatorResult {
+    ranged_integer_validator(value, 0, 100)
+}
+
+pub fn live_period_validator(value: String) -> ValidatorResult {
+    ranged_integer_validator(value, 60, 86400)
+}
+
+pub fn heading_validator(value: String) -> ValidatorResult {
+    ranged_integer_validator(value, 1, 360)
+}
+
+pub fn proximity_alert_radius_validator(value: String) -> ValidatorResult {
+    ranged_integer_validator(value, 1, 100000)
+}
+
+pub fn horizontal_accuracy_validator(value: String) -> ValidatorResult {
+    match value.parse::<f32>() {
+        Ok(v) if (0.0..=1500.0).contains(&v) => Ok(()),
+        _ => Err(format!(
+            "Value must be a float between 0 and 1500.\nValue: {}",
+            value
+        )),
+    }
+}
+
+pub fn animation_validator(value: String) -> ValidatorResult {
+    file_validator(value.clone())?;
+
+    // animations are either gif or silent videos
+    let path = current_dir().unwrap().join(value);
+    match MimeGuess::from_path(&path).first() {
+        Some(m) if m == mime::IMAGE_GIF => Ok(()),
+        _ => validate_file_type(&path, mime::VIDEO),
+    }
+}
+
+pub fn url_validator(value: String) -> ValidatorResult {
+    match url::Url::parse(&value) {
+        Ok(u) if u.scheme() == "http" || u.scheme() == "https" => Ok(()),
+        _ => Err(format!(
+            "Value must be a valid HTTP or HTTPS URL.\nValue: {}",
+            value
+        )),
+    }
+}
+
+pub fn duration_validator(value: String) -> ValidatorResult {
+    match parse_duration(&value) {
+        Some(d) if d > chrono::Duration::zero() => Ok(()),
+        _ => Err(format!(
+            "Value must be a positive duration such as 90s, 30m, 2h, 7d or 1h30m.\nValue: {}",
+            value
+        )),
+    }
+}
+
+/// Telegram lifts a restriction under 30 seconds or over 366 days never, so
+/// such values are rejected rather than turned into a permanent one. The lower
+/// bound leaves room for the time a bulk operation takes.
+pub fn until_validator(value: String) -> ValidatorResult {
+    match parse_duration(&value) {
+        Some(d) if d >= chrono::Duration::minutes(1) && d <= chrono::Duration::days(366) => Ok(()),
+        _ => Err(format!(
+            "Value must be a duration between 1m and 366d such as 2h or 7d. Leave it out for forever.\nValue: {}",
+            value
+        )),
+    }
+}
+
+pub fn datetime_validator(value: String) -> ValidatorResult {
+    match parse_datetime(&value) {
+        Some(_) => Ok(()),
+        None => Err(format!(
+            "Value must be a time such as 2026-11-01T09:00 or 2026-11-01T09:00:00+03:00.\nValue: {}",
+            value
+        )),
+    }
+}
+
+pub fn cron_validator(value: String) -> ValidatorResult {
+    match croner::Cron::new(&value).parse() {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!(
+            "Value must be a cron pattern such as \"0 9 * * 1-5\".\nValue: {}\nReason: {}",
+            value, e
+        )),
+    }
+}
+
+pub fn chat_title_validator(value: String) -> ValidatorResult {
+    match value.chars().count() {
+        l if !(1..=128).contains(&l) => {
+            Err("The title length must be between 1 and 128.".to_owned())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn chat_description_validator(value: String) -> ValidatorResult {
+    match value.chars().count() {
+        l if l > 255 => Err("The description cannot be longer than 255 characters.".to_owned()),
+        _ => Ok(()),
+    }
+}
+
+pub fn member_limit_validator(value: String) -> ValidatorResult {
+    ranged_integer_validator(value, 1, 99999)
+}
+
+pub fn language_code_validator(value: String) -> ValidatorResult {
+    match value.len() == 2 && value.chars().all(|c| c.is_ascii_lowercase()) {
+        true => Ok(()),
+        false => Err(format!(
+            "Value must be a two-letter ISO 639-1 language code such as en.\nValue: {}",
+            value
+        )),
+    }
+}
+
+fn max_length_validator(value: String, max: usize) -> ValidatorResult {
+    match value.chars().count() {
+        l if l > max => Err(format!("Value cannot be longer than {} characters.", max)),
+        _ => Ok(()),
+    }
+}
+
+pub fn bot_name_validator(value: String) -> ValidatorResult {
+    max_length_validator(value, 64)
+}
+
+pub fn bot_description_validator(value: String) -> ValidatorResult {
+    max_length_validator(value, 512)
+}
+
+pub fn bot_short_description_validator(value: String) -> ValidatorResult {
+    max_length_validator(value, 120)
+}
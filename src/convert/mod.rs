@@ -1,3 +1,5 @@
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone};
+
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -13,3 +15,53 @@
 // limitations under the License.
 
 pub mod operations;
+
+/// Parses a duration such as `90s`, `30m`, `2h`, `7d`, `1w` or `1h30m`.
+/// A number without a unit is in seconds.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let mut total = Duration::zero();
+    let mut number = String::new();
+
+    for c in value.trim().chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+
+        let amount: i64 = number.parse().ok()?;
+        number.clear();
+        total += match c {
+            's' => Duration::seconds(amount),
+            'm' => Duration::minutes(amount),
+            'h' => Duration::hours(amount),
+            'd' => Duration::days(amount),
+            'w' => Duration::weeks(amount),
+            _ => return None,
+        };
+    }
+
+    match number.is_empty() {
+        true if value.trim().is_empty() => None,
+        true => Some(total),
+        false => Some(total + Duration::seconds(number.parse().ok()?)),
+    }
+}
+
+/// Parses a point in time such as `2026-11-01T09:00` in local time or
+/// `2026-11-01T09:00:00+03:00` with an offset.
+pub fn parse_datetime(value: &str) -> Option<DateTime<Local>> {
+    let value = value.trim();
+    if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        return Some(t.with_timezone(&Local));
+    }
+
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+    .and_then(|t| Local.from_local_datetime(&t).earliest())
+}
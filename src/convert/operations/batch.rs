@@ -0,0 +1,333 @@
+use std::{
+    convert::TryFrom,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use clap::{AppSettings, ArgMatches};
+use serde_json::{Map, Value};
+
+use crate::{
+    cli::{dispatch, get_app},
+    operations::{
+        batch::{BatchLine, BatchOperation, BatchParams},
+        bot::BotParams,
+        CommonExitCodes, OperationError, RootParams,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The arguments of operations that are given by position rather than by name.
+const POSITIONALS: &[(&str, &str)] = &[
+    ("chat.set-description", "description"),
+    ("chat.set-photo", "file"),
+    ("chat.set-title", "title"),
+    ("commands.set", "file"),
+    ("file.get", "file_id"),
+    ("profile.set-description", "value"),
+    ("profile.set-name", "value"),
+    ("profile.set-short-description", "value"),
+    ("send.animation", "file"),
+    ("send.audio", "file"),
+    ("send.document", "file"),
+    ("send.message", "message"),
+    ("send.photo", "file"),
+    ("send.poll", "question"),
+    ("send.video", "file"),
+];
+
+/// The keys of arguments that schedule an operation, which only the command
+/// line supports.
+const SCHEDULING_KEYS: &[&str] = &["at", "in", "cron", "queue", "spool_dir"];
+
+/// The keys of arguments that only have a short name.
+const SHORTS: &[(&str, &str)] = &[("latitude", "x"), ("longitude", "y"), ("option", "o")];
+
+/// An operation of a manifest with its number.
+type ManifestOperation = (usize, Map<String, Value>);
+
+fn invalid_manifest_error(number: usize, message: impl std::fmt::Display) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::StdInvalidValue as i32,
+        format!("Operation {} in the manifest is not valid.", number),
+        Some(message.to_string()),
+    )
+}
+
+/// Parses the operations of a manifest with their numbers. A JSON Lines
+/// manifest has an object per line, where blank lines are ignored, and
+/// a YAML manifest is a list of them.
+pub(crate) fn parse_manifest(
+    content: &str,
+    yaml: bool,
+) -> Result<Vec<ManifestOperation>, OperationError> {
+    let values = match yaml {
+        true => match serde_yaml::from_str::<Vec<Value>>(content) {
+            Ok(v) => v.into_iter().enumerate().map(|(i, v)| (i + 1, v)).collect(),
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdInvalidValue as i32,
+                    "The manifest is not a valid YAML list.",
+                    Some(e),
+                ))
+            }
+        },
+        false => {
+            let mut values = Vec::new();
+            for (i, line) in content.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<Value>(line) {
+                    Ok(v) => values.push((i + 1, v)),
+                    Err(e) => return Err(invalid_manifest_error(i + 1, e)),
+                }
+            }
+            values
+        }
+    };
+
+    let mut operations = Vec::new();
+    for (number, value) in values {
+        let fields = match value {
+            Value::Object(o) => o,
+            _ => return Err(invalid_manifest_error(number, "It is not an object.")),
+        };
+        match fields.get("op") {
+            Some(Value::String(_)) => operations.push((number, fields)),
+            _ => {
+                return Err(invalid_manifest_error(
+                    number,
+                    "It does not have an `op` such as `send.message`.",
+                ))
+            }
+        }
+    }
+
+    Ok(operations)
+}
+
+/// The command line arguments of an operation after `bot`, e.g.
+/// `send photo --receiver=1234 -- photo.png` for `send.photo`.
+pub(crate) fn operation_args(op: &str, fields: &Map<String, Value>) -> Result<Vec<String>, String> {
+    let positional = POSITIONALS.iter().find(|(o, _)| *o == op).map(|(_, p)| *p);
+
+    let mut args: Vec<String> = op.split('.').map(String::from).collect();
+    let mut positionals = Vec::new();
+
+    for (key, value) in fields {
+        if key == "op" {
+            continue;
+        }
+        if SCHEDULING_KEYS.contains(&key.as_str()) {
+            return Err(format!("`{}` cannot be used in a batch.", key));
+        }
+
+        let values = match value {
+            Value::Array(a) => a.iter().collect(),
+            v => vec![v],
+        };
+
+        for v in values {
+            let value = match v {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                Value::Bool(true) => None,
+                Value::Bool(false) | Value::Null => continue,
+                _ => {
+                    return Err(format!(
+                        "`{}` must be a string, a number or a boolean.",
+                        key
+                    ))
+                }
+            };
+
+            if Some(key.as_str()) == positional {
+                match value {
+                    Some(v) => positionals.push(v),
+                    None => return Err(format!("`{}` must be a string.", key)),
+                }
+                continue;
+            }
+
+            // `caption` reads better than `message` for media
+            let name = match key.as_str() {
+                "caption" => "message",
+                k => k,
+            };
+            let flag = match SHORTS.iter().find(|(k, _)| *k == name) {
+                Some((_, short)) => format!("-{}", short),
+                None => format!("--{}", name.replace('_', "-")),
+            };
+            // the value is attached so that it may start with a hyphen
+            args.push(match value {
+                Some(v) => format!("{}={}", flag, v),
+                None => flag,
+            });
+        }
+    }
+
+    if !positionals.is_empty() {
+        args.push(String::from("--"));
+        args.extend(positionals);
+    }
+
+    Ok(args)
+}
+
+/// Parses an operation as if it were given on the command line with the same
+/// root and bot arguments as the batch.
+fn operation_matches(
+    m: &ArgMatches<'static>,
+    op: &str,
+    fields: &Map<String, Value>,
+) -> Result<ArgMatches<'static>, OperationError> {
+    let args = match operation_args(op, fields) {
+        Ok(a) => a,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                format!("The arguments of `{}` are not valid.", op),
+                Some(e),
+            ))
+        }
+    };
+
+    let mut argv = vec![String::from("tgcli")];
+    if let Some(p) = m.value_of("progress") {
+        argv.push(format!("--progress={}", p));
+    }
+    if m.is_present("dry_run") {
+        argv.push(String::from("--dry-run"));
+    }
+    if let Some(e) = m.value_of("emit") {
+        argv.push(format!("--emit={}", e));
+    }
+    if m.is_present("show_token") {
+        argv.push(String::from("--show-token"));
+    }
+    argv.push(String::from("bot"));
+    if let Some(t) = m.value_of("token") {
+        argv.push(format!("--token={}", t));
+    }
+    if let Some(u) = m.value_of("api_url") {
+        argv.push(format!("--api-url={}", u));
+    }
+    if m.is_present("local_server") {
+        argv.push(String::from("--local-server"));
+    }
+    argv.extend(args);
+
+    match get_app()
+        .global_setting(AppSettings::ColorNever)
+        .get_matches_from_safe(argv)
+    {
+        Ok(m) => Ok(m),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!(
+                "The arguments of `{}` are not valid: {}",
+                op,
+                clap_error_reason(&e)
+            ),
+            Some(e),
+        )),
+    }
+}
+
+/// The reason of a clap error without the usage that follows it.
+pub(crate) fn clap_error_reason(e: &clap::Error) -> String {
+    e.message
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("error: ")
+        .to_string()
+}
+
+fn read_manifest(path: &str) -> Result<String, OperationError> {
+    if path == "-" {
+        let mut input = String::new();
+        return match io::stdin().read_to_string(&mut input) {
+            Ok(_) => Ok(input),
+            Err(e) => Err(OperationError::new(
+                CommonExitCodes::StdStdinReadError as i32,
+                "Could not read from standard input.",
+                Some(e),
+            )),
+        };
+    }
+
+    match fs::read_to_string(path) {
+        Ok(c) => Ok(c),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdFsReadError as i32,
+            format!("Could not read {}.", path),
+            Some(e),
+        )),
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for BatchParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to BatchParams...");
+        trace!("arg matches: {:?}", m);
+
+        let path = m.value_of("manifest").unwrap_or("-");
+        let content = read_manifest(path)?;
+
+        let yaml = matches!(
+            Path::new(path).extension().and_then(|e| e.to_str()),
+            Some("yaml") | Some("yml")
+        );
+        let operations = parse_manifest(&content, yaml)?;
+
+        let lines = operations
+            .iter()
+            .map(|(number, fields)| {
+                let op = fields["op"].as_str().unwrap_or_default();
+                BatchLine::new(*number, op, operation_matches(&m, op, fields))
+            })
+            .collect();
+
+        let jobs = match m.value_of("jobs") {
+            Some(j) => j.parse::<usize>().unwrap_or(1),
+            None => 1,
+        };
+        let continue_on_error = m.is_present("continue_on_error");
+
+        let params = BatchParams::new(lines, jobs, continue_on_error, dispatch);
+        trace!("batch params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for BatchOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to BatchOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let batch_params = BatchParams::try_from(m)?;
+
+        Ok(BatchOperation::new((root_params, bot_params, batch_params)))
+    }
+}
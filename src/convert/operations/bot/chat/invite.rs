@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use chrono::Utc;
+use clap::ArgMatches;
+
+use super::chat_operation_params;
+use crate::{
+    convert::parse_duration,
+    operations::{
+        bot::chat::invite::{
+            CreateInviteLinkOperation, ExportInviteLinkOperation, InviteLinkParams,
+        },
+        CommonExitCodes, OperationError,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for ExportInviteLinkOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to ExportInviteLinkOperation...");
+
+        match chat_operation_params(m) {
+            Ok(p) => Ok(ExportInviteLinkOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for InviteLinkParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to InviteLinkParams...");
+        trace!("arg matches: {:?}", m);
+
+        let expire_date = match m.value_of("expire_in") {
+            Some(v) => match parse_duration(v) {
+                Some(d) => Some(Utc::now() + d),
+                None => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`expire-in` argument must be a valid duration such as 2h.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let params = InviteLinkParams::new(
+            m.value_of("name").map(String::from),
+            expire_date,
+            m.value_of("member_limit").map(|v| v.parse().unwrap()),
+            m.is_present("join_request"),
+        );
+        trace!("invite link params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for CreateInviteLinkOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to CreateInviteLinkOperation...");
+
+        let (root_params, bot_params, chat_params) = chat_operation_params(m.clone())?;
+
+        let invite_link_params = InviteLinkParams::try_from(m.clone())?;
+
+        Ok(CreateInviteLinkOperation::new((
+            root_params,
+            bot_params,
+            chat_params,
+            invite_link_params,
+        )))
+    }
+}
@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        chat::{ChatOperationParams, ChatParams},
+        BotParams,
+    },
+    CommonExitCodes, OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod administrators;
+pub mod description;
+pub mod invite;
+pub mod leave;
+pub mod membercount;
+pub mod photo;
+pub mod pin;
+pub mod title;
+
+impl TryFrom<ArgMatches<'static>> for ChatParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to ChatParams...");
+        trace!("arg matches: {:?}", m);
+
+        let chat = match m.value_of("chat") {
+            Some(c) => c,
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`chat` is a required argument on `chat` subcommand but is missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let params = ChatParams::new(chat);
+        trace!("chat params: {:?}", params);
+        Ok(params)
+    }
+}
+
+/// Converts the parameters shared by the operations which only need the chat.
+fn chat_operation_params(m: ArgMatches<'static>) -> Result<ChatOperationParams, OperationError> {
+    let root_params = RootParams::try_from(m.clone())?;
+    let bot_params = BotParams::try_from(m.clone())?;
+    let chat_params = ChatParams::try_from(m)?;
+
+    Ok((root_params, bot_params, chat_params))
+}
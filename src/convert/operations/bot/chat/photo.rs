@@ -0,0 +1,68 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use clap::ArgMatches;
+
+use super::chat_operation_params;
+use crate::operations::{
+    bot::chat::photo::{DeleteChatPhotoOperation, PhotoParams, SetChatPhotoOperation},
+    OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for PhotoParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to PhotoParams...");
+        trace!("arg matches: {:?}", m);
+
+        let params = PhotoParams::new(PathBuf::from(m.value_of("file").unwrap()));
+        trace!("photo params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SetChatPhotoOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetChatPhotoOperation...");
+
+        let (root_params, bot_params, chat_params) = chat_operation_params(m.clone())?;
+
+        let photo_params = PhotoParams::try_from(m.clone())?;
+
+        Ok(SetChatPhotoOperation::new((
+            root_params,
+            bot_params,
+            chat_params,
+            photo_params,
+        )))
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for DeleteChatPhotoOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to DeleteChatPhotoOperation...");
+
+        match chat_operation_params(m) {
+            Ok(p) => Ok(DeleteChatPhotoOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
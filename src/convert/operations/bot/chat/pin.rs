@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::chat_operation_params;
+use crate::operations::{
+    bot::chat::pin::{
+        PinMessageOperation, PinParams, UnpinAllMessagesOperation, UnpinMessageOperation,
+        UnpinParams,
+    },
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for PinParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to PinParams...");
+        trace!("arg matches: {:?}", m);
+
+        let message_id: usize = match m.value_of("message_id") {
+            Some(i) => match i.parse() {
+                Ok(v) => v,
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`message-id` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`message-id` is a required argument on `pin` subcommand but is missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let params = PinParams::new(message_id, m.is_present("silent"));
+        trace!("pin params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for PinMessageOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to PinMessageOperation...");
+
+        let (root_params, bot_params, chat_params) = chat_operation_params(m.clone())?;
+
+        let pin_params = PinParams::try_from(m.clone())?;
+
+        Ok(PinMessageOperation::new((
+            root_params,
+            bot_params,
+            chat_params,
+            pin_params,
+        )))
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for UnpinParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to UnpinParams...");
+        trace!("arg matches: {:?}", m);
+
+        let params = UnpinParams::new(m.value_of("message_id").map(|v| v.parse().unwrap()));
+        trace!("unpin params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for UnpinMessageOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to UnpinMessageOperation...");
+
+        let (root_params, bot_params, chat_params) = chat_operation_params(m.clone())?;
+
+        let unpin_params = UnpinParams::try_from(m.clone())?;
+
+        Ok(UnpinMessageOperation::new((
+            root_params,
+            bot_params,
+            chat_params,
+            unpin_params,
+        )))
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for UnpinAllMessagesOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to UnpinAllMessagesOperation...");
+
+        match chat_operation_params(m) {
+            Ok(p) => Ok(UnpinAllMessagesOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::chat_operation_params;
+use crate::operations::{
+    bot::chat::title::{SetChatTitleOperation, TitleParams},
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for TitleParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to TitleParams...");
+        trace!("arg matches: {:?}", m);
+
+        let title = match m.value_of("title") {
+            Some(t) => t,
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`title` is a required argument on `set-title` subcommand but is missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let params = TitleParams::new(title);
+        trace!("title params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SetChatTitleOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetChatTitleOperation...");
+
+        let (root_params, bot_params, chat_params) = chat_operation_params(m.clone())?;
+
+        let title_params = TitleParams::try_from(m.clone())?;
+
+        Ok(SetChatTitleOperation::new((
+            root_params,
+            bot_params,
+            chat_params,
+            title_params,
+        )))
+    }
+}
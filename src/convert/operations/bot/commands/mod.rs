@@ -0,0 +1,81 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        commands::{CommandScope, CommandsOperationParams, CommandsParams},
+        BotParams,
+    },
+    CommonExitCodes, OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod delete;
+pub mod get;
+pub mod set;
+
+fn missing_scope_value(arg: &str, scope: &str) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::ClapMissingValue as i32,
+        format!(
+            "`{}` is required for `{}` scope but is missing.",
+            arg, scope
+        ),
+        None::<&str>,
+    )
+}
+
+impl TryFrom<ArgMatches<'static>> for CommandsParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to CommandsParams...");
+        trace!("arg matches: {:?}", m);
+
+        let scope_name = m.value_of("scope").unwrap_or("default");
+        let chat = m.value_of("chat").map(String::from);
+        let user = m.value_of("user").map(|v| v.parse::<u64>().unwrap());
+
+        let scope = match (scope_name, chat, user) {
+            ("all-private-chats", _, _) => CommandScope::AllPrivateChats,
+            ("all-group-chats", _, _) => CommandScope::AllGroupChats,
+            ("all-chat-administrators", _, _) => CommandScope::AllChatAdministrators,
+            ("chat", Some(c), _) => CommandScope::Chat(c),
+            ("chat-administrators", Some(c), _) => CommandScope::ChatAdministrators(c),
+            ("chat-member", Some(c), Some(u)) => CommandScope::ChatMember(c, u),
+            ("chat-member", Some(_), None) => return Err(missing_scope_value("user", scope_name)),
+            ("chat" | "chat-administrators" | "chat-member", None, _) => {
+                return Err(missing_scope_value("chat", scope_name))
+            }
+            _ => CommandScope::Default,
+        };
+
+        let params = CommandsParams::new(scope, m.value_of("language_code").map(String::from));
+        trace!("commands params: {:?}", params);
+        Ok(params)
+    }
+}
+
+fn commands_operation_params(
+    m: ArgMatches<'static>,
+) -> Result<CommandsOperationParams, OperationError> {
+    let root_params = RootParams::try_from(m.clone())?;
+    let bot_params = BotParams::try_from(m.clone())?;
+    let commands_params = CommandsParams::try_from(m)?;
+
+    Ok((root_params, bot_params, commands_params))
+}
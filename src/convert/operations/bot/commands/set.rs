@@ -0,0 +1,66 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use clap::ArgMatches;
+
+use super::commands_operation_params;
+use crate::operations::{
+    bot::commands::set::{SetCommandsOperation, SetCommandsParams},
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for SetCommandsParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetCommandsParams...");
+        trace!("arg matches: {:?}", m);
+
+        let file = match m.value_of("file") {
+            Some(f) => PathBuf::from(f),
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`file` is a required argument on `set` subcommand but is missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let params = SetCommandsParams::new(file);
+        trace!("set commands params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SetCommandsOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetCommandsOperation...");
+
+        let (root_params, bot_params, commands_params) = commands_operation_params(m.clone())?;
+
+        let set_params = SetCommandsParams::try_from(m)?;
+
+        Ok(SetCommandsOperation::new((
+            root_params,
+            bot_params,
+            commands_params,
+            set_params,
+        )))
+    }
+}
@@ -0,0 +1,66 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        file::get::{FileSource, GetFileOperation, GetFileParams},
+        BotParams,
+    },
+    CommonExitCodes, OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for GetFileParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to GetFileParams...");
+        trace!("arg matches: {:?}", m);
+
+        let source = match (m.is_present("stdin"), m.value_of("file_id")) {
+            (true, _) => FileSource::Stdin,
+            (false, Some(i)) => FileSource::Id(String::from(i)),
+            (false, None) => return Err(OperationError::new(
+                CommonExitCodes::ClapMissingValue as i32,
+                "`file_id` is a required argument on `get` subcommand unless `--stdin` is present.",
+                None::<&str>,
+            )),
+        };
+
+        let params = GetFileParams::new(source, m.value_of("output").map(PathBuf::from));
+        trace!("get file params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for GetFileOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to GetFileOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let get_file_params = GetFileParams::try_from(m.clone())?;
+
+        Ok(GetFileOperation::new((
+            root_params,
+            bot_params,
+            get_file_params,
+        )))
+    }
+}
@@ -0,0 +1,97 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        location::{
+            follow::{FollowLocationOperation, FollowParams},
+            track::TrackFormat,
+        },
+        BotParams,
+    },
+    CommonExitCodes, OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for FollowParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to FollowParams...");
+        trace!("arg matches: {:?}", m);
+
+        let receiver =
+            match m.value_of("receiver") {
+                Some(r) => r,
+                None => return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`receiver` is a required argument on `location` subcommand but is missing.",
+                    None::<&str>,
+                )),
+            };
+
+        let (file, format) = match (m.value_of("from_gpx"), m.value_of("from_nmea")) {
+            (Some(f), _) => (PathBuf::from(f), TrackFormat::GPX),
+            (None, Some(f)) => (PathBuf::from(f), TrackFormat::NMEA),
+            (None, None) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "Either `from-gpx` or `from-nmea` is required on `follow` subcommand but both are missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let live_period: Option<usize> = match m.value_of("live_period") {
+            Some(l) => match l.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`live-period` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let silent = m.is_present("silent");
+
+        let params = FollowParams::new(receiver, file, format, live_period, silent);
+        trace!("follow params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for FollowLocationOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to FollowLocationOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let follow_params = FollowParams::try_from(m.clone())?;
+
+        Ok(FollowLocationOperation::new((
+            root_params,
+            bot_params,
+            follow_params,
+        )))
+    }
+}
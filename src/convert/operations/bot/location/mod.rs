@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::operations::{bot::location::LiveLocationParams, CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod follow;
+pub mod stop;
+pub mod update;
+
+impl TryFrom<ArgMatches<'static>> for LiveLocationParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to LiveLocationParams...");
+        trace!("arg matches: {:?}", m);
+
+        let receiver =
+            match m.value_of("receiver") {
+                Some(r) => r,
+                None => return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`receiver` is a required argument on `location` subcommand but is missing.",
+                    None::<&str>,
+                )),
+            };
+
+        let message_id: usize =
+            match m.value_of("message_id") {
+                Some(i) => match i.parse() {
+                    Ok(v) => v,
+                    Err(_) => {
+                        return Err(OperationError::new(
+                            CommonExitCodes::StdInvalidValue as i32,
+                            "`message-id` argument must be a valid positive integer.",
+                            None::<&str>,
+                        ))
+                    }
+                },
+                None => return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`message-id` is a required argument on `location` subcommand but is missing.",
+                    None::<&str>,
+                )),
+            };
+
+        let params = LiveLocationParams::new(receiver, message_id);
+        trace!("live location params: {:?}", params);
+        Ok(params)
+    }
+}
@@ -0,0 +1,57 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::{members_operation_params, until_date};
+use crate::operations::{
+    bot::member::ban::{BanMemberOperation, BanParams},
+    OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for BanParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to BanParams...");
+        trace!("arg matches: {:?}", m);
+
+        let until = until_date(&m)?;
+
+        let params = BanParams::new(until, m.is_present("revoke_messages"));
+        trace!("ban params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for BanMemberOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to BanMemberOperation...");
+
+        let (root_params, bot_params, members_params) = members_operation_params(m.clone())?;
+
+        let ban_params = BanParams::try_from(m)?;
+
+        Ok(BanMemberOperation::new((
+            root_params,
+            bot_params,
+            members_params,
+            ban_params,
+        )))
+    }
+}
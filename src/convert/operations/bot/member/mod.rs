@@ -0,0 +1,167 @@
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    fs,
+    io::{self, Read},
+};
+
+use chrono::{DateTime, Utc};
+use clap::ArgMatches;
+
+use crate::{
+    convert::parse_duration,
+    operations::{
+        bot::{
+            member::{MembersOperationParams, MembersParams},
+            BotParams,
+        },
+        CommonExitCodes, OperationError, RootParams,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod ban;
+pub mod info;
+pub mod promote;
+pub mod restrict;
+pub mod unban;
+
+/// Parses user ids, one per line. Empty lines and anything after `#` are
+/// ignored so that a list can document why each user is there.
+fn parse_user_ids(content: &str) -> Result<Vec<u64>, OperationError> {
+    let mut users = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let id = line.split('#').next().unwrap_or_default().trim();
+        if id.is_empty() {
+            continue;
+        }
+
+        match id.parse::<u64>() {
+            Ok(v) if v > 0 => users.push(v),
+            _ => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdInvalidValue as i32,
+                    format!("Line {} is not a valid user id.", index + 1),
+                    Some(id),
+                ))
+            }
+        }
+    }
+
+    Ok(users)
+}
+
+/// Reads user ids from a file or stdin if the path is `-`.
+fn read_user_ids(path: &str) -> Result<Vec<u64>, OperationError> {
+    let content = if path == "-" {
+        let mut input = String::new();
+        match io::stdin().read_to_string(&mut input) {
+            Ok(_) => input,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdStdinReadError as i32,
+                    "Could not read from standard input.",
+                    Some(e),
+                ))
+            }
+        }
+    } else {
+        match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!("Could not read {}.", path),
+                    Some(e),
+                ))
+            }
+        }
+    };
+
+    parse_user_ids(&content)
+}
+
+impl TryFrom<ArgMatches<'static>> for MembersParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to MembersParams...");
+        trace!("arg matches: {:?}", m);
+
+        let chat = match m.value_of("chat") {
+            Some(c) => c,
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ClapMissingValue as i32,
+                    "`chat` is a required argument on `member` subcommand but is missing.",
+                    None::<&str>,
+                ))
+            }
+        };
+
+        let mut users: Vec<u64> = match m.values_of("user") {
+            Some(vs) => vs.map(|v| v.parse().unwrap()).collect(),
+            None => vec![],
+        };
+        if let Some(path) = m.value_of("users_from") {
+            match read_user_ids(path) {
+                Ok(ids) => users.extend(ids),
+                Err(e) => return Err(e),
+            }
+        }
+        // a user is moderated once even if listed more than once
+        let mut seen = HashSet::new();
+        users.retain(|u| seen.insert(*u));
+
+        if users.is_empty() {
+            return Err(OperationError::new(
+                CommonExitCodes::ClapMissingValue as i32,
+                "At least one user is required via `user` or `users-from` arguments.",
+                None::<&str>,
+            ));
+        }
+
+        let params = MembersParams::new(chat, users);
+        trace!("members params: {:?}", params);
+        Ok(params)
+    }
+}
+
+/// The end of a restriction given as a duration from now.
+fn until_date(m: &ArgMatches<'static>) -> Result<Option<DateTime<Utc>>, OperationError> {
+    match m.value_of("until") {
+        Some(v) => match parse_duration(v) {
+            Some(d) => Ok(Some(Utc::now() + d)),
+            None => Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                "`until` argument must be a valid duration such as 2h.",
+                None::<&str>,
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+fn members_operation_params(
+    m: ArgMatches<'static>,
+) -> Result<MembersOperationParams, OperationError> {
+    let root_params = RootParams::try_from(m.clone())?;
+    let bot_params = BotParams::try_from(m.clone())?;
+    let members_params = MembersParams::try_from(m)?;
+
+    Ok((root_params, bot_params, members_params))
+}
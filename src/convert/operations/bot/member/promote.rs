@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::members_operation_params;
+use crate::operations::{
+    bot::member::promote::{AdministratorRight, PromoteMemberOperation, PromoteParams},
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for PromoteParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to PromoteParams...");
+        trace!("arg matches: {:?}", m);
+
+        let mut rights = vec![];
+        for name in m.values_of("right").unwrap_or_default() {
+            match AdministratorRight::from_name(name) {
+                Some(r) => rights.push(r),
+                None => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        format!("`{}` is not a valid administrator right.", name),
+                        None::<&str>,
+                    ))
+                }
+            }
+        }
+
+        let params = PromoteParams::new(rights, m.is_present("anonymous"));
+        trace!("promote params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for PromoteMemberOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to PromoteMemberOperation...");
+
+        let (root_params, bot_params, members_params) = members_operation_params(m.clone())?;
+
+        let promote_params = PromoteParams::try_from(m)?;
+
+        Ok(PromoteMemberOperation::new((
+            root_params,
+            bot_params,
+            members_params,
+            promote_params,
+        )))
+    }
+}
@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::{members_operation_params, until_date};
+use crate::operations::{
+    bot::member::restrict::{ChatPermission, RestrictMemberOperation, RestrictParams},
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for RestrictParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to RestrictParams...");
+        trace!("arg matches: {:?}", m);
+
+        let until = until_date(&m)?;
+
+        let mut permissions = vec![];
+        for name in m.values_of("allow").unwrap_or_default() {
+            match ChatPermission::from_name(name) {
+                Some(p) => permissions.push(p),
+                None => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        format!("`{}` is not a valid permission.", name),
+                        None::<&str>,
+                    ))
+                }
+            }
+        }
+
+        let params = RestrictParams::new(permissions, until);
+        trace!("restrict params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for RestrictMemberOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to RestrictMemberOperation...");
+
+        let (root_params, bot_params, members_params) = members_operation_params(m.clone())?;
+
+        let restrict_params = RestrictParams::try_from(m)?;
+
+        Ok(RestrictMemberOperation::new((
+            root_params,
+            bot_params,
+            members_params,
+            restrict_params,
+        )))
+    }
+}
@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::members_operation_params;
+use crate::operations::{
+    bot::member::unban::{UnbanMemberOperation, UnbanParams},
+    OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for UnbanParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to UnbanParams...");
+        trace!("arg matches: {:?}", m);
+
+        let params = UnbanParams::new(m.is_present("only_if_banned"));
+        trace!("unban params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for UnbanMemberOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to UnbanMemberOperation...");
+
+        let (root_params, bot_params, members_params) = members_operation_params(m.clone())?;
+
+        let unban_params = UnbanParams::try_from(m)?;
+
+        Ok(UnbanMemberOperation::new((
+            root_params,
+            bot_params,
+            members_params,
+            unban_params,
+        )))
+    }
+}
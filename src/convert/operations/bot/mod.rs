@@ -2,7 +2,10 @@ use std::convert::TryFrom;
 
 use clap::ArgMatches;
 
-use crate::operations::{bot::BotParams, CommonExitCodes, OperationError};
+use crate::{
+    operations::{bot::BotParams, CommonExitCodes, OperationError},
+    API_URL,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -18,6 +21,13 @@ use crate::operations::{bot::BotParams, CommonExitCodes, OperationError};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod chat;
+pub mod commands;
+pub mod file;
+pub mod location;
+pub mod me;
+pub mod member;
+pub mod profile;
 pub mod send;
 
 impl TryFrom<ArgMatches<'static>> for BotParams {
@@ -38,7 +48,9 @@ impl TryFrom<ArgMatches<'static>> for BotParams {
             }
         };
 
-        let params = BotParams::new(token);
+        let api_url = m.value_of("api_url").unwrap_or(API_URL);
+
+        let params = BotParams::new(token, api_url).with_local_server(m.is_present("local_server"));
         trace!("bot params: {:?}", params);
         Ok(params)
     }
@@ -0,0 +1,52 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use super::profile_operation_params;
+use crate::operations::{
+    bot::profile::{
+        description::{SetDescriptionOperation, SetShortDescriptionOperation},
+        ProfileField,
+    },
+    OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for SetDescriptionOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetDescriptionOperation...");
+
+        match profile_operation_params(m, ProfileField::Description) {
+            Ok(p) => Ok(SetDescriptionOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SetShortDescriptionOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SetShortDescriptionOperation...");
+
+        match profile_operation_params(m, ProfileField::ShortDescription) {
+            Ok(p) => Ok(SetShortDescriptionOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
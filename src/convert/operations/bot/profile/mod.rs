@@ -0,0 +1,45 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        profile::{ProfileField, ProfileOperationParams, ProfileParams},
+        BotParams,
+    },
+    OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod description;
+pub mod name;
+
+fn profile_operation_params(
+    m: ArgMatches<'static>,
+    field: ProfileField,
+) -> Result<ProfileOperationParams, OperationError> {
+    let root_params = RootParams::try_from(m.clone())?;
+    let bot_params = BotParams::try_from(m.clone())?;
+
+    let profile_params = ProfileParams::new(
+        field,
+        m.value_of("value").map(String::from),
+        m.value_of("language_code").map(String::from),
+    );
+    trace!("profile params: {:?}", profile_params);
+
+    Ok((root_params, bot_params, profile_params))
+}
@@ -0,0 +1,71 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use clap::ArgMatches;
+
+use super::{message_text, thumbnail_params};
+use crate::operations::{
+    bot::{
+        send::{
+            animation::{AnimationParams, SendAnimationOperation},
+            SendParams,
+        },
+        BotParams,
+    },
+    OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for AnimationParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to AnimationParams...");
+        trace!("arg matches: {:?}", m);
+
+        let message = message_text(&m)?;
+
+        let params = AnimationParams::new(
+            PathBuf::from(m.value_of("file").unwrap()),
+            message,
+            m.value_of("width").map(|v| v.parse().unwrap()),
+            m.value_of("height").map(|v| v.parse().unwrap()),
+            m.value_of("duration").map(|v| v.parse().unwrap()),
+            thumbnail_params(&m),
+        );
+        trace!("animation params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SendAnimationOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SendAnimationOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let send_params = SendParams::try_from(m.clone())?;
+        let animation_params = AnimationParams::try_from(m.clone())?;
+
+        Ok(SendAnimationOperation::new((
+            root_params,
+            bot_params,
+            send_params,
+            animation_params,
+        )))
+    }
+}
@@ -2,6 +2,7 @@ use std::{convert::TryFrom, path::PathBuf};
 
 use clap::ArgMatches;
 
+use super::{message_text, thumbnail_params};
 use crate::operations::{
     bot::{
         send::{
@@ -45,12 +46,29 @@ impl TryFrom<ArgMatches<'static>> for AudioParams {
             }
         };
 
+        let duration: Option<usize> = match m.value_of("duration") {
+            Some(d) => match d.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`duration` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let message = message_text(&m)?;
+
         let params = AudioParams::new(
             file,
-            m.value_of("message").map_or(None, |v| Some(v.to_string())),
-            m.value_of("title").map_or(None, |v| Some(v.to_string())),
-            m.value_of("performer")
-                .map_or(None, |v| Some(v.to_string())),
+            message,
+            m.value_of("title").map(str::to_string),
+            m.value_of("performer").map(str::to_string),
+            duration,
+            thumbnail_params(&m),
         );
         trace!("audio params: {:?}", params);
         Ok(params)
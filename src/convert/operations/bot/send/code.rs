@@ -0,0 +1,119 @@
+use std::{
+    convert::TryFrom,
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use clap::ArgMatches;
+
+use crate::operations::{
+    bot::{
+        send::{
+            code::{language_of, tail, CodeParams, Overflow, SendCodeOperation},
+            SendParams,
+        },
+        BotParams,
+    },
+    CommonExitCodes, OperationError, RootParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl From<&str> for Overflow {
+    fn from(v: &str) -> Self {
+        debug!("Converting {} to Overflow...", v);
+
+        match v {
+            "document" => Overflow::Document,
+            _ => Overflow::Split,
+        }
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for CodeParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to CodeParams...");
+        trace!("arg matches: {:?}", m);
+
+        let file = m.value_of("file").map(Path::new);
+        let content = match file {
+            Some(f) => fs::read(f),
+            None => {
+                let mut content = vec![];
+                io::stdin().read_to_end(&mut content).map(|_| content)
+            }
+        };
+        let content = match content {
+            Ok(c) => c,
+            Err(e) => {
+                let path = file
+                    .map(|f| f.display().to_string())
+                    .unwrap_or_else(|| String::from("<stdin>"));
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!("Could not read the code.\nPath: {}", path),
+                    Some(e),
+                ));
+            }
+        };
+        // logs are not always valid UTF-8
+        let code = String::from_utf8_lossy(&content);
+
+        // validated by clap
+        let code = match m.value_of("tail").map(|v| v.parse::<usize>().unwrap()) {
+            Some(lines) => tail(&code, lines).to_string(),
+            None => code.to_string(),
+        };
+
+        let language = m
+            .value_of("lang")
+            .or_else(|| file.and_then(language_of))
+            .map(String::from);
+
+        let name = file
+            .and_then(|f| f.file_name())
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| String::from("snippet.txt"));
+
+        let overflow = Overflow::from(m.value_of("overflow").unwrap_or("split"));
+
+        let params = CodeParams::new(code, language, name, overflow);
+        trace!("code params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for SendCodeOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to SendCodeOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let send_params = SendParams::try_from(m.clone())?;
+        let code_params = CodeParams::try_from(m.clone())?;
+
+        Ok(SendCodeOperation::new((
+            root_params,
+            bot_params,
+            send_params,
+            code_params,
+        )))
+    }
+}
@@ -2,6 +2,7 @@ use std::{convert::TryFrom, path::PathBuf};
 
 use clap::ArgMatches;
 
+use super::{message_text, thumbnail_params};
 use crate::operations::{
     bot::{
         send::{
@@ -45,12 +46,9 @@ impl TryFrom<ArgMatches<'static>> for DocumentParams {
             }
         };
 
-        let params = DocumentParams::new(
-            file,
-            m.value_of("thumbnail")
-                .map_or(None, |v| Some(PathBuf::from(v))),
-            m.value_of("message").map_or(None, |v| Some(v.to_string())),
-        );
+        let message = message_text(&m)?;
+
+        let params = DocumentParams::new(file, thumbnail_params(&m), message);
         trace!("document params: {:?}", params);
         Ok(params)
     }
@@ -72,7 +72,68 @@ impl TryFrom<ArgMatches<'static>> for LocationParams {
                 )),
             };
 
-        let params = LocationParams::new(latitude, longitude);
+        let live_period: Option<usize> = match m.value_of("live_period") {
+            Some(l) => match l.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`live-period` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let heading: Option<usize> = match m.value_of("heading") {
+            Some(h) => match h.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`heading` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let horizontal_accuracy: Option<f32> = match m.value_of("horizontal_accuracy") {
+            Some(h) => match h.parse() {
+                Ok(v) => Some(v),
+                Err(_) => return Err(OperationError::new(
+                    CommonExitCodes::StdInvalidValue as i32,
+                    "`horizontal-accuracy` argument must be a valid 32-bit floating point number.",
+                    None::<&str>,
+                )),
+            },
+            None => None,
+        };
+
+        let proximity_alert_radius: Option<usize> = match m.value_of("proximity_alert_radius") {
+            Some(p) => match p.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        "`proximity-alert-radius` argument must be a valid positive integer.",
+                        None::<&str>,
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let params = LocationParams::new(
+            latitude,
+            longitude,
+            live_period,
+            heading,
+            horizontal_accuracy,
+            proximity_alert_radius,
+        );
         trace!("location params: {:?}", params);
         Ok(params)
     }
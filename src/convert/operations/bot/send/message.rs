@@ -1,16 +1,18 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fs};
 
 use clap::ArgMatches;
 
+use super::message_text;
 use crate::operations::{
     bot::{
         send::{
+            entities::{check_entities, MessageEntity},
             message::{MessageParams, SendMessageOperation},
             SendParams,
         },
         BotParams,
     },
-    OperationError, RootParams,
+    CommonExitCodes, OperationError, RootParams,
 };
 
 // Copyright 2021 Eray Erdin
@@ -27,6 +29,32 @@ use crate::operations::{
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+/// Reads the entities of a message from a JSON file.
+fn read_entities(path: &str) -> Result<Vec<MessageEntity>, OperationError> {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!("Could not read the entities.\nPath: {}", path),
+                Some(e),
+            ))
+        }
+    };
+
+    match serde_json::from_str(&content) {
+        Ok(e) => Ok(e),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!(
+                "The entities must be a JSON array of MessageEntity objects.\nPath: {}",
+                path
+            ),
+            Some(e),
+        )),
+    }
+}
+
 impl TryFrom<ArgMatches<'static>> for MessageParams {
     type Error = OperationError;
 
@@ -34,7 +62,22 @@ impl TryFrom<ArgMatches<'static>> for MessageParams {
         debug!("Converting ArgMatches to MessageParams...");
         trace!("arg params: {:?}", m);
 
-        let params = MessageParams::new(m.value_of("message").unwrap().to_owned());
+        let message = match message_text(&m) {
+            Ok(t) => t.unwrap(),
+            Err(e) => return Err(e),
+        };
+
+        let entities = match m.value_of("entities_json").map(read_entities) {
+            Some(Ok(e)) => match check_entities(&message, &e) {
+                Ok(_) => Some(e),
+                Err(e) => return Err(e),
+            },
+            Some(Err(e)) => return Err(e),
+            None => None,
+        };
+
+        let mut params = MessageParams::new(message);
+        params.entities = entities;
         trace!("message params: {:?}", params);
         Ok(params)
     }
@@ -1,10 +1,18 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fs, path::PathBuf};
 
+use chrono::Duration;
 use clap::ArgMatches;
+use serde_json::{Map, Value};
 
-use crate::operations::{
-    bot::send::{MessageFormat, SendParams},
-    CommonExitCodes, OperationError,
+use crate::{
+    convert::{operations::data_dir, parse_duration},
+    operations::{
+        bot::send::{
+            html, idempotency::IdempotencyParams, template::TemplateParams, MessageFormat,
+            SendParams, ThumbnailParams,
+        },
+        CommonExitCodes, OperationError,
+    },
 };
 
 // Copyright 2021 Eray Erdin
@@ -21,7 +29,9 @@ use crate::operations::{
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod animation;
 pub mod audio;
+pub mod code;
 pub mod document;
 pub mod location;
 pub mod message;
@@ -36,6 +46,7 @@ impl From<&str> for MessageFormat {
         match v {
             "markdown" => MessageFormat::Markdown,
             "html" => MessageFormat::HTML,
+            "commonmark" => MessageFormat::CommonMark,
             _ => {
                 warn!("Unknown message format was provided. Falling back to markdown.");
                 MessageFormat::Markdown
@@ -73,10 +84,130 @@ impl TryFrom<ArgMatches<'static>> for SendParams {
             }
         };
 
+        // only HTML is sanitized, so the flag would silently do nothing otherwise
+        if m.is_present("sanitize") && format != "html" {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                "`--sanitize` can only be used with `--format html`.",
+                None::<&str>,
+            ));
+        }
+
         let silent = m.is_present("silent");
 
-        let params = SendParams::new(receiver, MessageFormat::from(format), silent);
+        let mut params = SendParams::new(receiver, MessageFormat::from(format), silent);
+        if m.is_present("outbox") {
+            params.outbox = match data_dir(&m, "outbox") {
+                Ok(d) => Some(d),
+                Err(e) => return Err(e),
+            };
+        }
+        // clap does not allow both
+        if let Some(name) = m.value_of("sticky") {
+            params.idempotency = match data_dir(&m, "state") {
+                Ok(d) => Some(IdempotencyParams::sticky(receiver, name, d)),
+                Err(e) => return Err(e),
+            };
+        } else if let Some(key) = m.value_of("idempotency_key") {
+            let state = data_dir(&m, "state")?;
+            // validated by clap
+            let ttl = m
+                .value_of("idempotency_ttl")
+                .map(|v| parse_duration(v).unwrap())
+                .unwrap_or_else(|| Duration::hours(24));
+            params.idempotency = Some(IdempotencyParams::new(
+                key,
+                ttl,
+                m.is_present("upsert"),
+                state,
+            ));
+        }
         trace!("send params: {:?}", params);
         Ok(params)
     }
 }
+
+/// Reads `thumbnail` and `auto_thumbnail` arguments.
+fn thumbnail_params(m: &ArgMatches<'static>) -> Option<ThumbnailParams> {
+    m.value_of("thumbnail")
+        .map(|v| ThumbnailParams::new(PathBuf::from(v), m.is_present("auto_thumbnail")))
+}
+
+/// Reads `template`, `vars_json` and `var` arguments, where a `var`
+/// overrides the same variable in `vars_json`.
+fn template_params(m: &ArgMatches<'static>) -> Result<Option<TemplateParams>, OperationError> {
+    let file = match m.value_of("template") {
+        Some(f) => PathBuf::from(f),
+        None => return Ok(None),
+    };
+
+    let mut vars = match m.value_of("vars_json") {
+        Some(path) => {
+            let content = match fs::read_to_string(path) {
+                Ok(c) => c,
+                Err(e) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdFsReadError as i32,
+                        format!("Could not read the variables.\nPath: {}", path),
+                        Some(e),
+                    ))
+                }
+            };
+            match serde_json::from_str::<Value>(&content) {
+                Ok(Value::Object(o)) => o,
+                Ok(_) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        format!("The variables must be a JSON object.\nPath: {}", path),
+                        None::<&str>,
+                    ))
+                }
+                Err(e) => {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdInvalidValue as i32,
+                        format!("The variables are not valid JSON.\nPath: {}", path),
+                        Some(e),
+                    ))
+                }
+            }
+        }
+        None => Map::new(),
+    };
+
+    // validated to have a key before the first =
+    for var in m.values_of("var").into_iter().flatten() {
+        if let Some((key, value)) = var.split_once('=') {
+            vars.insert(key.to_owned(), Value::String(value.to_owned()));
+        }
+    }
+
+    Ok(Some(TemplateParams::new(file, vars)))
+}
+
+/// Reads the text of a message or a caption, which is rendered from
+/// `template` with the format of the message if present. HTML is checked
+/// against what Telegram takes, after it is sanitized with `sanitize`.
+fn message_text(m: &ArgMatches<'static>) -> Result<Option<String>, OperationError> {
+    let template = template_params(m)?;
+
+    let format = MessageFormat::from(m.value_of("format").unwrap_or("markdown"));
+    let text = match template {
+        Some(t) => match t.render(format) {
+            Ok(r) => Some(r),
+            Err(e) => return Err(e),
+        },
+        None => m.value_of("message").map(|v| v.to_string()),
+    };
+
+    // the text is taken as is with entities
+    match (text, format) {
+        (Some(t), MessageFormat::HTML) if !m.is_present("entities_json") => {
+            let t = match m.is_present("sanitize") {
+                true => html::sanitize(&t),
+                false => t,
+            };
+            html::validate(&t).map(|_| Some(t))
+        }
+        (text, _) => Ok(text),
+    }
+}
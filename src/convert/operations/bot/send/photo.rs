@@ -2,6 +2,7 @@ use std::{convert::TryFrom, path::PathBuf};
 
 use clap::ArgMatches;
 
+use super::message_text;
 use crate::operations::{
     bot::{
         send::{
@@ -34,10 +35,9 @@ impl TryFrom<ArgMatches<'static>> for PhotoParams {
         debug!("Converting ArgMatches to PhotoParams...");
         trace!("arg matches: {:?}", m);
 
-        let params = PhotoParams::new(
-            PathBuf::from(m.value_of("file").unwrap()),
-            m.value_of("message").map_or(None, |v| Some(v.to_string())),
-        );
+        let message = message_text(&m)?;
+
+        let params = PhotoParams::new(PathBuf::from(m.value_of("file").unwrap()), message);
         trace!("photo params: {:?}", params);
         Ok(params)
     }
@@ -2,6 +2,7 @@ use std::{convert::TryFrom, path::PathBuf};
 
 use clap::ArgMatches;
 
+use super::{message_text, thumbnail_params};
 use crate::operations::{
     bot::{
         send::{
@@ -34,13 +35,16 @@ impl TryFrom<ArgMatches<'static>> for VideoParams {
         debug!("Converting ArgMatches to VideoParams...");
         trace!("arg matches: {:?}", m);
 
+        let message = message_text(&m)?;
+
         let params = VideoParams::new(
             PathBuf::from(m.value_of("file").unwrap()),
-            m.value_of("message").map_or(None, |v| Some(v.to_string())),
-            m.value_of("horizontal")
-                .map_or(None, |v| Some(v.parse().unwrap())),
-            m.value_of("vertical")
-                .map_or(None, |v| Some(v.parse().unwrap())),
+            message,
+            m.value_of("width").map(|v| v.parse().unwrap()),
+            m.value_of("height").map(|v| v.parse().unwrap()),
+            m.value_of("duration").map(|v| v.parse().unwrap()),
+            m.is_present("supports_streaming"),
+            thumbnail_params(&m),
         );
         trace!("video params: {:?}", params);
         Ok(params)
@@ -0,0 +1,83 @@
+use std::convert::TryFrom;
+
+use clap::{AppSettings, ArgMatches};
+
+use crate::{
+    cli::{dispatch, get_app},
+    operations::{
+        daemon::{DaemonOperation, DaemonParams},
+        CommonExitCodes, OperationError,
+    },
+};
+
+use super::{batch::clap_error_reason, schedule::spool_dir};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Parses the arguments of a spool entry as if they were given on the command
+/// line and runs the operation.
+fn run_args(args: &[String]) -> Result<(), OperationError> {
+    let argv = std::iter::once(String::from("tgcli")).chain(args.iter().cloned());
+    match get_app()
+        .global_setting(AppSettings::ColorNever)
+        .get_matches_from_safe(argv)
+    {
+        Ok(m) => dispatch(&m),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!("The arguments are not valid: {}", clap_error_reason(&e)),
+            Some(e),
+        )),
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for DaemonParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to DaemonParams...");
+        trace!("arg matches: {:?}", m);
+
+        let spool = spool_dir(&m)?;
+
+        let mut extra_args = Vec::new();
+        if let Some(t) = m.value_of("token") {
+            extra_args.push(format!("--token={}", t));
+        }
+        if let Some(u) = m.value_of("api_url") {
+            extra_args.push(format!("--api-url={}", u));
+        }
+        if m.is_present("local_server") {
+            extra_args.push(String::from("--local-server"));
+        }
+
+        let params = DaemonParams::new(spool, m.is_present("once"), extra_args, run_args);
+        trace!("daemon params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for DaemonOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to DaemonOperation...");
+
+        match DaemonParams::try_from(m) {
+            Ok(p) => Ok(DaemonOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
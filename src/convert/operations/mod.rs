@@ -1,8 +1,10 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, env, path::PathBuf};
 
 use clap::ArgMatches;
 
-use crate::operations::{OperationError, RootParams};
+use crate::operations::{
+    CommonExitCodes, DryRunParams, EmitFormat, OperationError, ProgressMode, RootParams,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -18,7 +20,38 @@ use crate::operations::{OperationError, RootParams};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod batch;
 pub mod bot;
+pub mod daemon;
+pub mod outbox;
+pub mod schedule;
+
+/// Reads `<name>_dir` argument, which defaults to `tgcli/<name>` in the local
+/// data directory of the user.
+pub(crate) fn data_dir(m: &ArgMatches<'static>, name: &str) -> Result<PathBuf, OperationError> {
+    let dir = match m.value_of(format!("{}_dir", name)) {
+        Some(d) => Some(PathBuf::from(shellexpand::tilde(d).to_string())),
+        None => dirs_next::data_local_dir().map(|d| d.join("tgcli").join(name)),
+    };
+
+    match (dir, env::current_dir()) {
+        // the daemon changes its working directory for each operation
+        (Some(d), Ok(cwd)) => Ok(cwd.join(d)),
+        (None, _) => Err(OperationError::new(
+            CommonExitCodes::ClapMissingValue as i32,
+            format!(
+                "Could not find a {} directory. Provide one with `--{}-dir`.",
+                name, name
+            ),
+            None::<&str>,
+        )),
+        (_, Err(e)) => Err(OperationError::new(
+            CommonExitCodes::StdFsReadError as i32,
+            "Could not get current working directory.",
+            Some(e),
+        )),
+    }
+}
 
 impl TryFrom<ArgMatches<'static>> for RootParams {
     type Error = OperationError;
@@ -26,7 +59,23 @@ impl TryFrom<ArgMatches<'static>> for RootParams {
     fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
         debug!("Converting ArgMatches to RootParams...");
         trace!("arg matches: {:?}", m);
-        let params = RootParams::new();
+        let progress = match m.value_of("progress") {
+            Some("json") => ProgressMode::Json,
+            Some("none") => ProgressMode::Hidden,
+            _ => ProgressMode::Bar,
+        };
+
+        // emitting the request in a format implies a dry run
+        let emit = match m.value_of("emit") {
+            Some("curl") => Some(EmitFormat::Curl),
+            Some("http") => Some(EmitFormat::Http),
+            Some(_) => Some(EmitFormat::Json),
+            None if m.is_present("dry_run") => Some(EmitFormat::Json),
+            None => None,
+        };
+        let dry_run = emit.map(|e| DryRunParams::new(e, m.is_present("show_token")));
+
+        let params = RootParams::new(progress, dry_run);
         trace!("root params: {:?}", params);
         Ok(params)
     }
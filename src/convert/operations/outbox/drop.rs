@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::{
+    convert::operations::data_dir,
+    operations::{
+        outbox::drop::{DropOutboxOperation, DropParams},
+        OperationError,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for DropParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to DropParams...");
+        trace!("arg matches: {:?}", m);
+
+        let outbox = data_dir(&m, "outbox")?;
+
+        // either ids or `all` is required by clap
+        let ids = m
+            .values_of("id")
+            .map(|v| v.map(String::from).collect::<Vec<String>>());
+
+        let params = DropParams::new(outbox, ids);
+        trace!("drop params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for DropOutboxOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to DropOutboxOperation...");
+
+        match DropParams::try_from(m) {
+            Ok(p) => Ok(DropOutboxOperation::new(p)),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use clap::ArgMatches;
+
+use crate::{
+    convert::operations::data_dir,
+    operations::{
+        bot::BotParams,
+        outbox::flush::{FlushOutboxOperation, FlushParams},
+        OperationError, RootParams,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+impl TryFrom<ArgMatches<'static>> for FlushParams {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to FlushParams...");
+        trace!("arg matches: {:?}", m);
+
+        let outbox = data_dir(&m, "outbox")?;
+
+        // validated by clap
+        let retries = m
+            .value_of("retries")
+            .map(|v| v.parse::<u32>().unwrap())
+            .unwrap_or(3);
+
+        let params = FlushParams::new(outbox, retries);
+        trace!("flush params: {:?}", params);
+        Ok(params)
+    }
+}
+
+impl TryFrom<ArgMatches<'static>> for FlushOutboxOperation {
+    type Error = OperationError;
+
+    fn try_from(m: ArgMatches<'static>) -> Result<Self, Self::Error> {
+        debug!("Converting ArgMatches to FlushOutboxOperation...");
+
+        let root_params = RootParams::try_from(m.clone())?;
+        let bot_params = BotParams::try_from(m.clone())?;
+        let flush_params = FlushParams::try_from(m)?;
+
+        Ok(FlushOutboxOperation::new((
+            root_params,
+            bot_params,
+            flush_params,
+        )))
+    }
+}
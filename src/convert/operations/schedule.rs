@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use chrono::Local;
+use clap::ArgMatches;
+
+use super::data_dir;
+use crate::{
+    convert::{parse_datetime, parse_duration},
+    operations::{
+        schedule::{next_occurrence, ScheduleParams},
+        OperationError,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The options that are not written to the spool with their values. The
+/// token and the URL of Bot API server are those of `daemon`.
+const UNSPOOLED_OPTIONS: &[&str] = &[
+    "--at",
+    "--in",
+    "--cron",
+    "--spool-dir",
+    "--token",
+    "-t",
+    "--api-url",
+];
+
+/// Reads `spool_dir` argument, which defaults to a directory in the local data
+/// directory of the user.
+pub(crate) fn spool_dir(m: &ArgMatches<'static>) -> Result<PathBuf, OperationError> {
+    data_dir(m, "spool")
+}
+
+/// Reads `at`, `in`, `cron` and `queue` arguments if the operation is
+/// scheduled.
+pub(crate) fn schedule_params(
+    m: &ArgMatches<'static>,
+) -> Result<Option<ScheduleParams>, OperationError> {
+    let cron = m.value_of("cron").map(String::from);
+    let at = match (m.value_of("at"), m.value_of("in"), &cron) {
+        // validated by clap
+        (Some(at), _, _) => parse_datetime(at).unwrap(),
+        (_, Some(delay), _) => Local::now() + parse_duration(delay).unwrap(),
+        (_, _, Some(cron)) => next_occurrence(cron, &Local::now())?,
+        _ if m.is_present("queue") => Local::now(),
+        _ => return Ok(None),
+    };
+
+    let queue = match m.is_present("queue") {
+        true => match spool_dir(m) {
+            Ok(d) => Some(d),
+            Err(e) => return Err(e),
+        },
+        false => None,
+    };
+
+    let params = ScheduleParams::new(at, cron, queue);
+    trace!("schedule params: {:?}", params);
+    Ok(Some(params))
+}
+
+/// The command line arguments of a queued operation, i.e. `args` without the
+/// program name and the scheduling, the token and the URL of Bot API server.
+/// `token` is the value parsed from `args`, which tells an attached `-t<token>`
+/// apart from any other argument.
+pub(crate) fn spooled_args(args: Vec<String>, token: Option<&str>) -> Vec<String> {
+    let mut spooled = Vec::new();
+    let mut args = args.into_iter().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            spooled.push(arg);
+            spooled.extend(args);
+            break;
+        }
+        if arg == "--queue" {
+            continue;
+        }
+
+        // the value is the next argument unless it is attached
+        if UNSPOOLED_OPTIONS.contains(&arg.as_str()) {
+            args.next();
+            continue;
+        }
+        let attached = UNSPOOLED_OPTIONS
+            .iter()
+            .any(|o| arg.starts_with(&format!("{}=", o)));
+        let attached_token = arg
+            .strip_prefix("-t")
+            .map(|v| v.strip_prefix('=').unwrap_or(v))
+            .is_some_and(|v| Some(v) == token);
+        if attached || attached_token {
+            continue;
+        }
+
+        spooled.push(arg);
+    }
+
+    spooled
+}
@@ -1,3 +1,9 @@
+use std::sync::OnceLock;
+
+use reqwest::blocking::Client;
+
+use crate::operations::{CommonExitCodes, OperationError};
+
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -12,5 +18,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod progress;
 pub mod request;
 pub mod response;
+
+/// The client shared by operations so that connections to the API server are
+/// reused, e.g. by the operations of a batch. It has no timeout since
+/// transferring large files may take longer than the default one.
+static TRANSFER_CLIENT: OnceLock<Client> = OnceLock::new();
+
+pub fn transfer_client() -> Result<Client, OperationError> {
+    if let Some(c) = TRANSFER_CLIENT.get() {
+        return Ok(c.clone());
+    }
+
+    match Client::builder().timeout(None).build() {
+        Ok(c) => Ok(TRANSFER_CLIENT.get_or_init(|| c).clone()),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::ReqwestConnectionError as i32,
+            "Could not initialize the HTTP client.",
+            Some(e),
+        )),
+    }
+}
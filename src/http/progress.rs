@@ -0,0 +1,191 @@
+use std::{
+    io::{self, Read},
+    time::{Duration, Instant},
+};
+#[cfg(feature = "async")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde_json::json;
+
+use crate::operations::ProgressMode;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The minimum interval between two JSON progress events.
+const EVENT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Reports the progress of a file transfer on stderr.
+#[derive(Debug)]
+pub struct Progress {
+    mode: ProgressMode,
+    name: String,
+    total: Option<u64>,
+    transferred: u64,
+    last_event: Option<Instant>,
+    finished: bool,
+    bar: ProgressBar,
+}
+
+impl Progress {
+    pub fn new(mode: ProgressMode, total: Option<u64>, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let bar = match mode {
+            ProgressMode::Bar => bar(total, name.clone()),
+            ProgressMode::Json | ProgressMode::Hidden => ProgressBar::hidden(),
+        };
+
+        Self {
+            mode,
+            name,
+            total,
+            transferred: 0,
+            last_event: None,
+            finished: false,
+            bar,
+        }
+    }
+
+    pub fn inc(&mut self, length: u64) {
+        self.transferred += length;
+        self.bar.inc(length);
+
+        let due = match self.last_event {
+            Some(t) => t.elapsed() >= EVENT_INTERVAL,
+            None => true,
+        };
+        if due {
+            self.emit("progress");
+        }
+    }
+
+    /// Completes the transfer. Calling it more than once has no effect.
+    pub fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        self.bar.finish_and_clear();
+        self.emit("done");
+    }
+
+    /// Stops reporting without completing the transfer.
+    pub fn abandon(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        self.bar.abandon();
+        self.emit("failed");
+    }
+
+    fn emit(&mut self, event: &str) {
+        if let ProgressMode::Json = self.mode {
+            eprintln!(
+                "{}",
+                json!({
+                    "event": event,
+                    "name": self.name,
+                    "transferred": self.transferred,
+                    "total": self.total,
+                })
+            );
+            self.last_event = Some(Instant::now());
+        }
+    }
+}
+
+/// A reader which reports the bytes read through it.
+#[derive(Debug)]
+pub struct ProgressReader<R> {
+    inner: R,
+    progress: Progress,
+}
+
+impl<R> ProgressReader<R> {
+    pub fn new(inner: R, progress: Progress) -> Self {
+        Self { inner, progress }
+    }
+
+    pub fn progress(&mut self) -> &mut Progress {
+        &mut self.progress
+    }
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.inner.read(buf) {
+            Ok(0) if !buf.is_empty() => {
+                self.progress.finish();
+                Ok(0)
+            }
+            Ok(n) => {
+                self.progress.inc(n as u64);
+                Ok(n)
+            }
+            Err(e) => {
+                self.progress.abandon();
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let filled = buf.filled().len();
+        match Pin::new(&mut self.inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(())) => {
+                match buf.filled().len() - filled {
+                    0 if buf.remaining() > 0 => self.progress.finish(),
+                    n => self.progress.inc(n as u64),
+                }
+                Poll::Ready(Ok(()))
+            }
+            Poll::Ready(Err(e)) => {
+                self.progress.abandon();
+                Poll::Ready(Err(e))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Creates a progress bar for transferring `length` bytes. The bar is
+/// drawn on stderr and stays hidden if stderr is not a terminal.
+fn bar(length: Option<u64>, message: String) -> ProgressBar {
+    let bar = match length {
+        Some(l) => ProgressBar::with_draw_target(Some(l), ProgressDrawTarget::stderr()).with_style(
+            ProgressStyle::with_template(
+                "{msg} [{bar:30}] {bytes}/{total_bytes} ({bytes_per_sec}, {eta})",
+            )
+            .expect("The progress template should be valid.")
+            .progress_chars("=> "),
+        ),
+        None => ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr()).with_style(
+            ProgressStyle::with_template("{spinner} {msg} {bytes} ({bytes_per_sec})")
+                .expect("The progress template should be valid."),
+        ),
+    };
+    bar.with_message(message)
+}
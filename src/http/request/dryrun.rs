@@ -0,0 +1,240 @@
+use std::{fs, path::PathBuf};
+
+use crate::operations::{bot::REDACTED_TOKEN, CommonExitCodes, OperationError, ProgressMode};
+
+use super::models::MultipartForm;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A part of a request that is printed instead of sent.
+#[derive(Debug, Serialize)]
+pub struct DryRunPart {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+    /// The size of the file in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mime_type: Option<String>,
+}
+
+/// A multipart form that records its parts instead of encoding them, so that
+/// the request can be reviewed without touching the network.
+#[derive(Debug, Default)]
+pub struct DryRunForm {
+    parts: Vec<DryRunPart>,
+}
+
+impl MultipartForm for DryRunForm {
+    fn with_text(mut self, name: &'static str, value: String) -> Self {
+        self.parts.push(DryRunPart {
+            name,
+            value: Some(value),
+            path: None,
+            file_name: None,
+            size: None,
+            mime_type: None,
+        });
+        self
+    }
+
+    fn with_file(
+        mut self,
+        name: &'static str,
+        path: PathBuf,
+        _progress: ProgressMode,
+    ) -> Result<Self, OperationError> {
+        let size = match fs::metadata(&path) {
+            Ok(m) => m.len(),
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!("Could not read {}.", path.display()),
+                    Some(e),
+                ))
+            }
+        };
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned());
+        let mime = mime_guess::from_path(&path).first_or_octet_stream();
+
+        self.parts.push(DryRunPart {
+            name,
+            value: None,
+            path: Some(path),
+            file_name,
+            size: Some(size),
+            mime_type: Some(mime.to_string()),
+        });
+        Ok(self)
+    }
+
+    fn with_bytes(
+        mut self,
+        name: &'static str,
+        bytes: Vec<u8>,
+        file_name: &'static str,
+        mime: &str,
+    ) -> Result<Self, OperationError> {
+        self.parts.push(DryRunPart {
+            name,
+            value: None,
+            path: None,
+            file_name: Some(String::from(file_name)),
+            size: Some(bytes.len() as u64),
+            mime_type: Some(String::from(mime)),
+        });
+        Ok(self)
+    }
+}
+
+/// The boundary between the parts of a printed HTTP transcript.
+const BOUNDARY: &str = "tgcli-dry-run";
+
+/// The full request an operation would send.
+#[derive(Debug, Serialize)]
+pub struct DryRunRequest {
+    method: &'static str,
+    /// The URL of the method with the bot token redacted.
+    url: String,
+    content_type: &'static str,
+    parts: Vec<DryRunPart>,
+}
+
+impl DryRunRequest {
+    pub fn new(url: String, form: DryRunForm) -> Self {
+        Self {
+            method: "POST",
+            url,
+            content_type: "multipart/form-data",
+            parts: form.parts,
+        }
+    }
+
+    /// A curl command reproducing the request. Files are referenced with
+    /// `-F name=@path`. A redacted token is read from `TELEGRAM_BOT_TOKEN`
+    /// environment variable so that the command still works.
+    pub fn to_curl(&self) -> String {
+        let redacted = format!("/bot{}/", REDACTED_TOKEN);
+        let url = match self.url.contains(&redacted) {
+            true => format!(
+                "\"{}\"",
+                double_quote_escape(&self.url).replace(&redacted, "/bot${TELEGRAM_BOT_TOKEN}/")
+            ),
+            false => shell_quote(&self.url),
+        };
+
+        let mut lines = vec![format!("curl -X {} {}", self.method, url)];
+        for part in &self.parts {
+            let line = match &part.value {
+                // unlike -F, a value starting with @ or < is not read from a file
+                Some(v) => format!(
+                    "--form-string {}",
+                    shell_quote(&format!("{}={}", part.name, v))
+                ),
+                None => {
+                    // a generated file, e.g. a fitted thumbnail, is only known by its name
+                    let path = match &part.path {
+                        Some(p) => p.to_string_lossy().into_owned(),
+                        None => part.file_name.clone().unwrap_or_default(),
+                    };
+                    let mut field = format!("{}=@{}", part.name, curl_file_name(&path));
+                    if let Some(m) = &part.mime_type {
+                        field.push_str(&format!(";type={}", m));
+                    }
+                    format!("-F {}", shell_quote(&field))
+                }
+            };
+            lines.push(line);
+        }
+
+        lines.join(" \\\n  ")
+    }
+
+    /// A raw HTTP/1.1 transcript of the request. The content of files is
+    /// replaced with a placeholder describing them.
+    pub fn to_http(&self) -> String {
+        let location = self.url.splitn(2, "://").last().unwrap_or_default();
+        let (host, path) = match location.find('/') {
+            Some(i) => (&location[..i], &location[i..]),
+            None => (location, "/"),
+        };
+
+        let mut lines = vec![
+            format!("{} {} HTTP/1.1", self.method, path),
+            format!("Host: {}", host),
+            format!("Content-Type: {}; boundary={}", self.content_type, BOUNDARY),
+            String::new(),
+        ];
+        for part in &self.parts {
+            lines.push(format!("--{}", BOUNDARY));
+            match &part.value {
+                Some(v) => {
+                    lines.push(format!(
+                        "Content-Disposition: form-data; name=\"{}\"",
+                        part.name
+                    ));
+                    lines.push(String::new());
+                    lines.push(v.clone());
+                }
+                None => {
+                    lines.push(format!(
+                        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"",
+                        part.name,
+                        part.file_name.as_deref().unwrap_or_default()
+                    ));
+                    if let Some(m) = &part.mime_type {
+                        lines.push(format!("Content-Type: {}", m));
+                    }
+                    lines.push(String::new());
+                    lines.push(match &part.path {
+                        Some(p) => format!("<{} bytes of {}>", part.size.unwrap_or(0), p.display()),
+                        None => format!("<{} bytes>", part.size.unwrap_or(0)),
+                    });
+                }
+            }
+        }
+        lines.push(format!("--{}--", BOUNDARY));
+        lines.push(String::new());
+
+        lines.join("\r\n")
+    }
+}
+
+/// Quotes a value for POSIX shells.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Escapes a value to be put in double quotes for POSIX shells.
+fn double_quote_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('`', "\\`")
+}
+
+/// Quotes a file name for `-F` of curl if it contains its separators.
+fn curl_file_name(path: &str) -> String {
+    match path.contains([';', ',', '"']) {
+        true => format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\"")),
+        false => String::from(path),
+    }
+}
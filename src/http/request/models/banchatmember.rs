@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::member::ban::BanMemberParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct BanChatMemberRequestModel {
+    chat_id: ChatId,
+    user_id: u64,
+    /// Unix timestamp of when the user will be unbanned.
+    until_date: Option<i64>,
+    revoke_messages: bool,
+}
+
+impl TryFrom<BanChatMemberRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: BanChatMemberRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting BanChatMemberRequestModel to Form...");
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("user_id", m.user_id.to_string());
+
+        let until_form = match m.until_date {
+            Some(d) => form.text("until_date", d.to_string()),
+            None => form,
+        };
+
+        let revoke_form = match m.revoke_messages {
+            true => until_form.text("revoke_messages", "true"),
+            false => until_form,
+        };
+
+        Ok(revoke_form)
+    }
+}
+
+impl From<BanMemberParams> for BanChatMemberRequestModel {
+    fn from(params: BanMemberParams) -> Self {
+        debug!("Converting BanMemberParams to BanChatMemberRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        BanChatMemberRequestModel {
+            chat_id,
+            user_id: params.2.user,
+            until_date: params.3.until.map(|d| d.timestamp()),
+            revoke_messages: params.3.revoke_messages,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::ChatOperationParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for the requests which only take `chat_id`, such as /leaveChat.
+pub struct ChatRequestModel {
+    chat_id: ChatId,
+}
+
+impl TryFrom<ChatRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: ChatRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting ChatRequestModel to Form...");
+        Ok(Form::new().text("chat_id", m.chat_id.to_string()))
+    }
+}
+
+impl From<ChatOperationParams> for ChatRequestModel {
+    fn from(params: ChatOperationParams) -> Self {
+        debug!("Converting ChatOperationParams to ChatRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        ChatRequestModel { chat_id }
+    }
+}
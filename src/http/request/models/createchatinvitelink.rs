@@ -0,0 +1,82 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::invite::CreateInviteLinkParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /createChatInviteLink request.
+pub struct CreateChatInviteLinkRequestModel {
+    chat_id: ChatId,
+    name: Option<String>,
+    /// Unix timestamp of when the link will expire.
+    expire_date: Option<i64>,
+    member_limit: Option<usize>,
+    creates_join_request: bool,
+}
+
+impl TryFrom<CreateChatInviteLinkRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: CreateChatInviteLinkRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting CreateChatInviteLinkRequestModel to Form...");
+        let form = Form::new().text("chat_id", m.chat_id.to_string());
+
+        let name_form = match m.name {
+            Some(n) => form.text("name", n),
+            None => form,
+        };
+
+        let expire_form = match m.expire_date {
+            Some(d) => name_form.text("expire_date", d.to_string()),
+            None => name_form,
+        };
+
+        let limit_form = match m.member_limit {
+            Some(l) => expire_form.text("member_limit", l.to_string()),
+            None => expire_form,
+        };
+
+        let join_request_form = match m.creates_join_request {
+            true => limit_form.text("creates_join_request", "true"),
+            false => limit_form,
+        };
+
+        Ok(join_request_form)
+    }
+}
+
+impl From<CreateInviteLinkParams> for CreateChatInviteLinkRequestModel {
+    fn from(params: CreateInviteLinkParams) -> Self {
+        debug!("Converting CreateInviteLinkParams to CreateChatInviteLinkRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        CreateChatInviteLinkRequestModel {
+            chat_id,
+            name: params.3.name,
+            expire_date: params.3.expire_date.map(|d| d.timestamp()),
+            member_limit: params.3.member_limit,
+            creates_join_request: params.3.creates_join_request,
+        }
+    }
+}
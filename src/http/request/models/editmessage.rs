@@ -0,0 +1,113 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::send::entities::MessageEntity, OperationError};
+
+use super::ParseMode;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The text or the caption a message sent before is edited to have.
+#[derive(Debug, Clone)]
+pub struct EditContent {
+    /// The method of Bot API, e.g. `editMessageText`.
+    method: &'static str,
+    /// The name of the field, e.g. `text`.
+    field: &'static str,
+    /// The caption is removed if absent.
+    value: Option<String>,
+    parse_mode: ParseMode,
+    /// The name of the field of entities, e.g. `entities`.
+    entities_field: &'static str,
+    /// Sent instead of `parse_mode` if present.
+    entities: Option<Vec<MessageEntity>>,
+}
+
+impl EditContent {
+    pub(super) fn text(
+        text: String,
+        parse_mode: ParseMode,
+        entities: Option<Vec<MessageEntity>>,
+    ) -> Self {
+        Self {
+            method: "editMessageText",
+            field: "text",
+            value: Some(text),
+            parse_mode,
+            entities_field: "entities",
+            entities,
+        }
+    }
+
+    pub(super) fn caption(caption: Option<String>, parse_mode: ParseMode) -> Self {
+        Self {
+            method: "editMessageCaption",
+            field: "caption",
+            value: caption,
+            parse_mode,
+            entities_field: "caption_entities",
+            entities: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EditMessageRequestModel {
+    chat_id: i64,
+    message_id: usize,
+    content: EditContent,
+}
+
+impl EditMessageRequestModel {
+    pub fn new(chat_id: i64, message_id: usize, content: EditContent) -> Self {
+        Self {
+            chat_id,
+            message_id,
+            content,
+        }
+    }
+
+    /// The method of Bot API editing the message.
+    pub fn method(&self) -> &'static str {
+        self.content.method
+    }
+}
+
+impl TryFrom<EditMessageRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: EditMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting EditMessageRequestModel to Form...");
+
+        let initial_form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("message_id", m.message_id.to_string());
+
+        let format_form = match m.content.entities {
+            Some(e) => {
+                initial_form.text(m.content.entities_field, serde_json::to_string(&e).unwrap())
+            }
+            None => initial_form.text("parse_mode", m.content.parse_mode.to_string()),
+        };
+
+        let content_form = match m.content.value {
+            Some(v) => format_form.text(m.content.field, v),
+            None => format_form,
+        };
+
+        Ok(content_form)
+    }
+}
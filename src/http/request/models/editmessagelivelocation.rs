@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::location::update::UpdateLocationParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /editMessageLiveLocation request.
+pub struct EditMessageLiveLocationRequestModel {
+    chat_id: ChatId,
+    message_id: usize,
+    latitude: f32,
+    longitude: f32,
+    heading: Option<usize>,
+    horizontal_accuracy: Option<f32>,
+    proximity_alert_radius: Option<usize>,
+}
+
+impl TryFrom<EditMessageLiveLocationRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: EditMessageLiveLocationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting EditMessageLiveLocationRequestModel to Form...");
+        let chat_id = m.chat_id.to_string();
+
+        let initial_form = Form::new()
+            .text("chat_id", chat_id)
+            .text("message_id", m.message_id.to_string())
+            .text("latitude", m.latitude.to_string())
+            .text("longitude", m.longitude.to_string());
+
+        let heading_form = match m.heading {
+            Some(h) => initial_form.text("heading", h.to_string()),
+            None => initial_form,
+        };
+
+        let accuracy_form = match m.horizontal_accuracy {
+            Some(a) => heading_form.text("horizontal_accuracy", a.to_string()),
+            None => heading_form,
+        };
+
+        let proximity_form = match m.proximity_alert_radius {
+            Some(p) => accuracy_form.text("proximity_alert_radius", p.to_string()),
+            None => accuracy_form,
+        };
+
+        Ok(proximity_form)
+    }
+}
+
+impl From<UpdateLocationParams> for EditMessageLiveLocationRequestModel {
+    fn from(params: UpdateLocationParams) -> Self {
+        debug!("Converting UpdateLocationParams to EditMessageLiveLocationRequestModel...");
+
+        let chat_id = match params.2.receiver.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.receiver),
+        };
+
+        let message_id = params.2.message_id;
+        let latitude = params.3.latitude;
+        let longitude = params.3.longitude;
+        let heading = params.3.heading;
+        let horizontal_accuracy = params.3.horizontal_accuracy;
+        let proximity_alert_radius = params.3.proximity_alert_radius;
+
+        EditMessageLiveLocationRequestModel {
+            chat_id,
+            message_id,
+            latitude,
+            longitude,
+            heading,
+            horizontal_accuracy,
+            proximity_alert_radius,
+        }
+    }
+}
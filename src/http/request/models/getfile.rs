@@ -0,0 +1,43 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::file::FileParams, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /getFile request.
+pub struct GetFileRequestModel {
+    file_id: String,
+}
+
+impl TryFrom<GetFileRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: GetFileRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting GetFileRequestModel to Form...");
+        Ok(Form::new().text("file_id", m.file_id))
+    }
+}
+
+impl From<FileParams> for GetFileRequestModel {
+    fn from(params: FileParams) -> Self {
+        debug!("Converting FileParams to GetFileRequestModel...");
+        GetFileRequestModel {
+            file_id: params.file_id,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::member::MemberParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct MemberRequestModel {
+    chat_id: ChatId,
+    user_id: u64,
+}
+
+impl TryFrom<MemberRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: MemberRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting MemberRequestModel to Form...");
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("user_id", m.user_id.to_string());
+        Ok(form)
+    }
+}
+
+impl From<MemberParams> for MemberRequestModel {
+    fn from(params: MemberParams) -> Self {
+        debug!("Converting MemberParams to MemberRequestModel...");
+
+        let chat_id = match params.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.chat),
+        };
+
+        MemberRequestModel {
+            chat_id,
+            user_id: params.user,
+        }
+    }
+}
@@ -1,4 +1,14 @@
-use std::{path, string};
+use std::{fs::File, path, string};
+
+use reqwest::blocking::multipart::{Form, Part};
+#[cfg(feature = "async")]
+use reqwest::multipart as nonblocking;
+
+use crate::{
+    http::progress::{Progress, ProgressReader},
+    media::thumbnail,
+    operations::{CommonExitCodes, OperationError, ProgressMode},
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -14,6 +24,18 @@ use std::{path, string};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod banchatmember;
+pub mod chat;
+pub mod createchatinvitelink;
+pub mod editmessage;
+pub mod editmessagelivelocation;
+pub mod getfile;
+pub mod member;
+pub mod mycommands;
+pub mod pinchatmessage;
+pub mod promotechatmember;
+pub mod restrictchatmember;
+pub mod sendanimation;
 pub mod sendaudio;
 pub mod senddocument;
 pub mod sendlocation;
@@ -21,8 +43,16 @@ pub mod sendmessage;
 pub mod sendphoto;
 pub mod sendpoll;
 pub mod sendvideo;
+pub mod setchatdescription;
+pub mod setchatphoto;
+pub mod setchattitle;
+pub mod setmycommands;
+pub mod setprofile;
+pub mod stopmessagelivelocation;
+pub mod unbanchatmember;
+pub mod unpinchatmessage;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// What the type of ChatId is.
 enum ChatId {
     Int(usize),
@@ -38,7 +68,7 @@ impl string::ToString for ChatId {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// Which format Telegram should handle the message text in.
 enum ParseMode {
     Markdown,
@@ -54,7 +84,7 @@ impl string::ToString for ParseMode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// The file that will be sent to Telegram.
 enum InputFile {
     /// Local file.
@@ -64,3 +94,182 @@ enum InputFile {
     /// The id of file that was sent before.
     Id(String),
 }
+
+fn form_error(e: reqwest::Error) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::ReqwestFormError as i32,
+        "An error occured while attaching file to request form.",
+        Some(e),
+    )
+}
+
+/// Opens a local file to be uploaded, yielding its length, name and MIME type.
+fn open_upload(path: &path::Path) -> Result<(File, u64, String, String), OperationError> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!("Could not read {}.", path.display()),
+                Some(e),
+            ))
+        }
+    };
+    let length = match file.metadata() {
+        Ok(m) => m.len(),
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!("Could not read {}.", path.display()),
+                Some(e),
+            ))
+        }
+    };
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+
+    Ok((file, length, file_name, mime.to_string()))
+}
+
+/// A multipart form a request model can be converted into, so that the same
+/// model serves both the blocking and, with `async` feature, the non-blocking client.
+pub trait MultipartForm: Sized {
+    fn with_text(self, name: &'static str, value: String) -> Self;
+    /// Streams a local file and reports the progress of the upload.
+    fn with_file(
+        self,
+        name: &'static str,
+        path: path::PathBuf,
+        progress: ProgressMode,
+    ) -> Result<Self, OperationError>;
+    fn with_bytes(
+        self,
+        name: &'static str,
+        bytes: Vec<u8>,
+        file_name: &'static str,
+        mime: &str,
+    ) -> Result<Self, OperationError>;
+}
+
+impl MultipartForm for Form {
+    fn with_text(self, name: &'static str, value: String) -> Self {
+        self.text(name, value)
+    }
+
+    fn with_file(
+        self,
+        name: &'static str,
+        path: path::PathBuf,
+        progress: ProgressMode,
+    ) -> Result<Self, OperationError> {
+        match file_part(path, progress) {
+            Ok(part) => Ok(self.part(name, part)),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn with_bytes(
+        self,
+        name: &'static str,
+        bytes: Vec<u8>,
+        file_name: &'static str,
+        mime: &str,
+    ) -> Result<Self, OperationError> {
+        match Part::bytes(bytes).file_name(file_name).mime_str(mime) {
+            Ok(part) => Ok(self.part(name, part)),
+            Err(e) => Err(form_error(e)),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl MultipartForm for nonblocking::Form {
+    fn with_text(self, name: &'static str, value: String) -> Self {
+        self.text(name, value)
+    }
+
+    fn with_file(
+        self,
+        name: &'static str,
+        path: path::PathBuf,
+        progress: ProgressMode,
+    ) -> Result<Self, OperationError> {
+        let (file, length, file_name, mime) = open_upload(&path)?;
+
+        let reader = ProgressReader::new(
+            tokio::fs::File::from_std(file),
+            Progress::new(progress, Some(length), &file_name),
+        );
+        let stream = tokio_util::io::ReaderStream::new(reader);
+        match nonblocking::Part::stream_with_length(reqwest::Body::wrap_stream(stream), length)
+            .file_name(file_name)
+            .mime_str(&mime)
+        {
+            Ok(part) => Ok(self.part(name, part)),
+            Err(e) => Err(form_error(e)),
+        }
+    }
+
+    fn with_bytes(
+        self,
+        name: &'static str,
+        bytes: Vec<u8>,
+        file_name: &'static str,
+        mime: &str,
+    ) -> Result<Self, OperationError> {
+        match nonblocking::Part::bytes(bytes)
+            .file_name(file_name)
+            .mime_str(mime)
+        {
+            Ok(part) => Ok(self.part(name, part)),
+            Err(e) => Err(form_error(e)),
+        }
+    }
+}
+
+/// Streams a local file into a part so that the upload progress can be reported.
+pub(crate) fn file_part(
+    path: path::PathBuf,
+    progress: ProgressMode,
+) -> Result<Part, OperationError> {
+    let (file, length, file_name, mime) = open_upload(&path)?;
+
+    let reader = ProgressReader::new(file, Progress::new(progress, Some(length), &file_name));
+    match Part::reader_with_length(reader, length)
+        .file_name(file_name)
+        .mime_str(&mime)
+    {
+        Ok(part) => Ok(part),
+        Err(e) => Err(form_error(e)),
+    }
+}
+
+/// Attaches a thumbnail to the form. A local thumbnail is fitted into
+/// the limits of Telegram if `fit` is true.
+fn thumbnail_form<F: MultipartForm>(
+    form: F,
+    thumbnail: Option<InputFile>,
+    fit: bool,
+) -> Result<F, OperationError> {
+    match thumbnail {
+        Some(InputFile::Local(p)) if fit => {
+            let bytes = thumbnail::fit(&p)?;
+
+            form.with_bytes("thumbnail", bytes, "thumbnail.jpg", "image/jpeg")
+        }
+        Some(InputFile::Local(p)) => {
+            if !thumbnail::is_acceptable(&p) {
+                warn!("The thumbnail must be a JPEG of at most 320x320 and 200 kilobytes or Telegram ignores it. Use `--auto-thumbnail` to fit it.");
+            }
+
+            form.with_file("thumbnail", p, ProgressMode::Hidden)
+        }
+        Some(InputFile::Remote(u)) => Ok(form.with_text("thumbnail", u.to_string())),
+        Some(InputFile::Id(i)) => Ok(form.with_text("thumbnail", i)),
+        None => Ok(form),
+    }
+}
@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+use serde_json::Value;
+
+use crate::operations::{bot::commands::CommandsOperationParams, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct MyCommandsRequestModel {
+    scope: Value,
+    language_code: Option<String>,
+}
+
+impl TryFrom<MyCommandsRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: MyCommandsRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting MyCommandsRequestModel to Form...");
+        let form = Form::new().text("scope", m.scope.to_string());
+
+        let language_form = match m.language_code {
+            Some(l) => form.text("language_code", l),
+            None => form,
+        };
+
+        Ok(language_form)
+    }
+}
+
+impl From<CommandsOperationParams> for MyCommandsRequestModel {
+    fn from(params: CommandsOperationParams) -> Self {
+        debug!("Converting CommandsOperationParams to MyCommandsRequestModel...");
+
+        MyCommandsRequestModel {
+            scope: params.2.scope.to_json(),
+            language_code: params.2.language_code,
+        }
+    }
+}
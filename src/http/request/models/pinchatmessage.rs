@@ -0,0 +1,64 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::pin::PinMessageParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /pinChatMessage request.
+pub struct PinChatMessageRequestModel {
+    chat_id: ChatId,
+    message_id: usize,
+    disable_notification: bool,
+}
+
+impl TryFrom<PinChatMessageRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: PinChatMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting PinChatMessageRequestModel to Form...");
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("message_id", m.message_id.to_string());
+
+        let notification_form = match m.disable_notification {
+            true => form.text("disable_notification", "true"),
+            false => form,
+        };
+
+        Ok(notification_form)
+    }
+}
+
+impl From<PinMessageParams> for PinChatMessageRequestModel {
+    fn from(params: PinMessageParams) -> Self {
+        debug!("Converting PinMessageParams to PinChatMessageRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        PinChatMessageRequestModel {
+            chat_id,
+            message_id: params.3.message_id,
+            disable_notification: params.3.silent,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{
+    bot::member::promote::{AdministratorRight, PromoteMemberParams},
+    OperationError,
+};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct PromoteChatMemberRequestModel {
+    chat_id: ChatId,
+    user_id: u64,
+    rights: Vec<AdministratorRight>,
+    is_anonymous: bool,
+}
+
+impl TryFrom<PromoteChatMemberRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: PromoteChatMemberRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting PromoteChatMemberRequestModel to Form...");
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("user_id", m.user_id.to_string())
+            .text("is_anonymous", m.is_anonymous.to_string());
+
+        // every right is explicit so that the missing ones are revoked
+        let rights_form = AdministratorRight::ALL.iter().fold(form, |f, r| {
+            f.text(r.field(), m.rights.contains(r).to_string())
+        });
+
+        Ok(rights_form)
+    }
+}
+
+impl From<PromoteMemberParams> for PromoteChatMemberRequestModel {
+    fn from(params: PromoteMemberParams) -> Self {
+        debug!("Converting PromoteMemberParams to PromoteChatMemberRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        PromoteChatMemberRequestModel {
+            chat_id,
+            user_id: params.2.user,
+            rights: params.3.rights,
+            is_anonymous: params.3.anonymous,
+        }
+    }
+}
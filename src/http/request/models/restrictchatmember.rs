@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+use serde_json::{Map, Value};
+
+use crate::operations::{
+    bot::member::restrict::{ChatPermission, RestrictMemberParams},
+    OperationError,
+};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct RestrictChatMemberRequestModel {
+    chat_id: ChatId,
+    user_id: u64,
+    permissions: Vec<ChatPermission>,
+    /// Unix timestamp of when the restrictions will be lifted.
+    until_date: Option<i64>,
+}
+
+impl TryFrom<RestrictChatMemberRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: RestrictChatMemberRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting RestrictChatMemberRequestModel to Form...");
+
+        // every permission is explicit so that the result does not depend on the defaults of the chat
+        let permissions: Map<String, Value> = ChatPermission::ALL
+            .iter()
+            .map(|p| (p.field().to_owned(), Value::Bool(m.permissions.contains(p))))
+            .collect();
+
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("user_id", m.user_id.to_string())
+            .text("permissions", Value::Object(permissions).to_string())
+            .text("use_independent_chat_permissions", "true");
+
+        let until_form = match m.until_date {
+            Some(d) => form.text("until_date", d.to_string()),
+            None => form,
+        };
+
+        Ok(until_form)
+    }
+}
+
+impl From<RestrictMemberParams> for RestrictChatMemberRequestModel {
+    fn from(params: RestrictMemberParams) -> Self {
+        debug!("Converting RestrictMemberParams to RestrictChatMemberRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        RestrictChatMemberRequestModel {
+            chat_id,
+            user_id: params.2.user,
+            permissions: params.3.permissions,
+            until_date: params.3.until.map(|d| d.timestamp()),
+        }
+    }
+}
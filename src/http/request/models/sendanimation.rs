@@ -0,0 +1,178 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    media,
+    operations::{
+        bot::send::{self, animation::SendAnimationParams},
+        OperationError, ProgressMode,
+    },
+};
+
+use super::{
+    editmessage::EditContent, thumbnail_form, ChatId, InputFile, MultipartForm, ParseMode,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug, Clone)]
+/// A model for /sendAnimation request.
+pub struct SendAnimationRequestModel {
+    chat_id: ChatId,
+    animation: InputFile,
+    width: Option<usize>,
+    height: Option<usize>,
+    duration: Option<usize>,
+    thumbnail: Option<InputFile>,
+    fit_thumbnail: bool,
+    caption: Option<String>,
+    parse_mode: ParseMode,
+    disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+    progress: ProgressMode,
+}
+
+impl SendAnimationRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::caption(self.caption.clone(), self.parse_mode.clone())
+    }
+
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
+
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("parse_mode", parse_mode);
+
+        let caption_form = match self.caption {
+            Some(c) => initial_form.with_text("caption", c),
+            None => initial_form,
+        };
+
+        let animation_form = match self.animation {
+            InputFile::Local(p) => caption_form.with_file("animation", p, self.progress)?,
+            InputFile::Remote(u) => caption_form.with_text("animation", u.to_string()),
+            InputFile::Id(i) => caption_form.with_text("animation", i),
+        };
+
+        let width_form = match self.width {
+            Some(w) => animation_form.with_text("width", w.to_string()),
+            None => animation_form,
+        };
+
+        let height_form = match self.height {
+            Some(h) => width_form.with_text("height", h.to_string()),
+            None => width_form,
+        };
+
+        let duration_form = match self.duration {
+            Some(d) => height_form.with_text("duration", d.to_string()),
+            None => height_form,
+        };
+
+        let thumbnail_form = thumbnail_form(duration_form, self.thumbnail, self.fit_thumbnail)?;
+
+        let notification_form = match self.disable_notification {
+            true => thumbnail_form.with_text("disable_notification", "true".to_owned()),
+            false => thumbnail_form,
+        };
+
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
+}
+
+impl TryFrom<SendAnimationRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendAnimationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAnimationRequestModel to Form...");
+        m.into_form(Form::new())
+    }
+}
+
+impl TryFrom<SendAnimationRequestModel> for DryRunForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendAnimationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAnimationRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendAnimationRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendAnimationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAnimationRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
+    }
+}
+
+impl From<SendAnimationParams> for SendAnimationRequestModel {
+    fn from(params: SendAnimationParams) -> Self {
+        debug!("Converting SendAnimationParams to SendAnimationRequestModel...");
+
+        let chat_id = match params.2.receiver.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.receiver),
+        };
+
+        let caption = params.3.message.map(|c| params.2.format.convert(c));
+
+        let parse_mode = match params.2.format {
+            send::MessageFormat::Markdown => ParseMode::Markdown,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
+        };
+
+        // explicit values take precedence over the probed ones
+        let probed = match (params.3.width, params.3.height, params.3.duration) {
+            (Some(_), Some(_), Some(_)) => media::MediaInfo::default(),
+            _ => media::probe(&params.3.file).unwrap_or_default(),
+        };
+
+        let width = params.3.width.or(probed.width);
+        let height = params.3.height.or(probed.height);
+        let duration = params.3.duration.or(probed.duration);
+        let animation = InputFile::Local(params.3.file);
+        let fit_thumbnail = params.3.thumbnail.as_ref().is_some_and(|t| t.fit);
+        let thumbnail = params.3.thumbnail.map(|t| InputFile::Local(t.file));
+
+        let disable_notification = params.2.silent;
+
+        SendAnimationRequestModel {
+            chat_id,
+            caption,
+            parse_mode,
+            animation,
+            width,
+            height,
+            duration,
+            thumbnail,
+            fit_thumbnail,
+            disable_notification,
+            reply_to_message_id: params.2.reply_to,
+            progress: params.0.progress,
+        }
+    }
+}
@@ -2,12 +2,18 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{
-    bot::send::{self, audio::SendAudioParams},
-    CommonExitCodes, OperationError,
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    media,
+    operations::{
+        bot::send::{self, audio::SendAudioParams},
+        OperationError, ProgressMode,
+    },
 };
 
-use super::{ChatId, InputFile, ParseMode};
+use super::{
+    editmessage::EditContent, thumbnail_form, ChatId, InputFile, MultipartForm, ParseMode,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -23,66 +29,102 @@ use super::{ChatId, InputFile, ParseMode};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SendAudioRequestModel {
     chat_id: ChatId,
     audio: InputFile,
     performer: Option<String>,
     title: Option<String>,
+    duration: Option<usize>,
+    thumbnail: Option<InputFile>,
+    fit_thumbnail: bool,
     caption: Option<String>,
     parse_mode: ParseMode,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+    progress: ProgressMode,
 }
 
-impl TryFrom<SendAudioRequestModel> for Form {
-    type Error = OperationError;
-
-    fn try_from(m: SendAudioRequestModel) -> Result<Self, Self::Error> {
-        debug!("Converting SendAudioRequestModel to Form...");
+impl SendAudioRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::caption(self.caption.clone(), self.parse_mode.clone())
+    }
 
-        let chat_id = m.chat_id.to_string();
-        let parse_mode = m.parse_mode.to_string();
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("parse_mode", parse_mode);
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("parse_mode", parse_mode);
 
-        let caption_form = match m.caption {
-            Some(c) => initial_form.text("caption", c),
+        let caption_form = match self.caption {
+            Some(c) => initial_form.with_text("caption", c),
             None => initial_form,
         };
 
-        let audio_form = match m.audio {
-            InputFile::Local(p) => match caption_form.file("audio", p) {
-                Ok(file) => file,
-                Err(e) => {
-                    return Err(OperationError::new(
-                        CommonExitCodes::ReqwestFormError as i32,
-                        "An error occured while attaching file to request form.",
-                        Some(e),
-                    ))
-                }
-            },
-            InputFile::Remote(u) => caption_form.text("audio", u.to_string()),
-            InputFile::Id(i) => caption_form.text("audio", i),
+        let audio_form = match self.audio {
+            InputFile::Local(p) => caption_form.with_file("audio", p, self.progress)?,
+            InputFile::Remote(u) => caption_form.with_text("audio", u.to_string()),
+            InputFile::Id(i) => caption_form.with_text("audio", i),
         };
 
-        let performer_form = match m.performer {
-            Some(p) => audio_form.text("performer", p),
+        let performer_form = match self.performer {
+            Some(p) => audio_form.with_text("performer", p),
             None => audio_form,
         };
 
-        let title_form = match m.title {
-            Some(t) => performer_form.text("title", t),
+        let title_form = match self.title {
+            Some(t) => performer_form.with_text("title", t),
             None => performer_form,
         };
 
-        let notification_form = match m.disable_notification {
-            true => title_form.text("disable_notification", "true"),
-            false => title_form,
+        let duration_form = match self.duration {
+            Some(d) => title_form.with_text("duration", d.to_string()),
+            None => title_form,
         };
 
-        Ok(notification_form)
+        let thumbnail_form = thumbnail_form(duration_form, self.thumbnail, self.fit_thumbnail)?;
+
+        let notification_form = match self.disable_notification {
+            true => thumbnail_form.with_text("disable_notification", "true".to_owned()),
+            false => thumbnail_form,
+        };
+
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
+}
+
+impl TryFrom<SendAudioRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendAudioRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAudioRequestModel to Form...");
+        m.into_form(Form::new())
+    }
+}
+
+impl TryFrom<SendAudioRequestModel> for DryRunForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendAudioRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAudioRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendAudioRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendAudioRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendAudioRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
     }
 }
 
@@ -95,14 +137,22 @@ impl From<SendAudioParams> for SendAudioRequestModel {
             Err(_) => ChatId::Str(params.2.receiver),
         };
 
-        let caption = params.3.message;
+        let caption = params.3.message.map(|c| params.2.format.convert(c));
 
         let parse_mode = match params.2.format {
             send::MessageFormat::Markdown => ParseMode::Markdown,
-            send::MessageFormat::HTML => ParseMode::HTML,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
+        };
+
+        // an explicit duration takes precedence over the probed one
+        let duration = match params.3.duration {
+            Some(d) => Some(d),
+            None => media::probe(&params.3.file).and_then(|i| i.duration),
         };
 
         let audio = InputFile::Local(params.3.file);
+        let fit_thumbnail = params.3.thumbnail.as_ref().is_some_and(|t| t.fit);
+        let thumbnail = params.3.thumbnail.map(|t| InputFile::Local(t.file));
         let performer = params.3.performer;
         let title = params.3.title;
         let disable_notification = params.2.silent;
@@ -114,7 +164,12 @@ impl From<SendAudioParams> for SendAudioRequestModel {
             audio,
             performer,
             title,
+            duration,
+            thumbnail,
+            fit_thumbnail,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
+            progress: params.0.progress,
         }
     }
 }
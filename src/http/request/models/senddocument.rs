@@ -2,12 +2,17 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{
-    bot::send::{self, document::SendDocumentParams},
-    CommonExitCodes, OperationError,
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    operations::{
+        bot::send::{self, document::SendDocumentParams},
+        OperationError, ProgressMode,
+    },
 };
 
-use super::{ChatId, InputFile, ParseMode};
+use super::{
+    editmessage::EditContent, thumbnail_form, ChatId, InputFile, MultipartForm, ParseMode,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -23,73 +28,95 @@ use super::{ChatId, InputFile, ParseMode};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// A model for /sendDocument request.
 pub struct SendDocumentRequestModel {
     chat_id: ChatId,
     document: InputFile,
     thumbnail: Option<InputFile>,
+    fit_thumbnail: bool,
     caption: Option<String>,
     parse_mode: ParseMode,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+    progress: ProgressMode,
 }
 
-impl TryFrom<SendDocumentRequestModel> for Form {
-    type Error = OperationError;
+impl SendDocumentRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::caption(self.caption.clone(), self.parse_mode.clone())
+    }
 
-    fn try_from(m: SendDocumentRequestModel) -> Result<Self, Self::Error> {
-        debug!("Converting SendDocumentRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
-        let parse_mode = m.parse_mode.to_string();
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("parse_mode", parse_mode);
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("parse_mode", parse_mode);
 
-        let caption_form = match m.caption {
-            Some(c) => initial_form.text("caption", c),
+        let caption_form = match self.caption {
+            Some(c) => initial_form.with_text("caption", c),
             None => initial_form,
         };
 
-        let document_form = match m.document {
-            InputFile::Local(p) => match caption_form.file("document", p) {
-                Ok(file) => file,
-                Err(e) => {
-                    return Err(OperationError::new(
-                        CommonExitCodes::ReqwestFormError as i32,
-                        "An error occured while attaching file to request form.",
-                        Some(e),
-                    ))
-                }
-            },
-            InputFile::Remote(u) => caption_form.text("document", u.to_string()),
-            InputFile::Id(i) => caption_form.text("document", i),
+        let document_form = match self.document {
+            InputFile::Local(p) => caption_form.with_file("document", p, self.progress)?,
+            InputFile::Remote(u) => caption_form.with_text("document", u.to_string()),
+            InputFile::Id(i) => caption_form.with_text("document", i),
         };
 
-        let thumbnail_form = match m.thumbnail {
-            Some(inputfile) => match inputfile {
-                InputFile::Local(p) => match document_form.file("thumbnail", p) {
-                    Ok(file) => file,
-                    Err(e) => {
-                        return Err(OperationError::new(
-                            CommonExitCodes::ReqwestFormError as i32,
-                            "An error occured while attaching file to request form.",
-                            Some(e),
-                        ))
-                    }
-                },
-                InputFile::Remote(u) => document_form.text("thumbnail", u.to_string()),
-                InputFile::Id(i) => document_form.text("thumbnail", i),
-            },
-            None => document_form,
-        };
+        let thumbnail_form = thumbnail_form(document_form, self.thumbnail, self.fit_thumbnail)?;
 
-        let notification_form = match m.disable_notification {
-            true => thumbnail_form.text("disable_notification", "true"),
+        let notification_form = match self.disable_notification {
+            true => thumbnail_form.with_text("disable_notification", "true".to_owned()),
             false => thumbnail_form,
         };
 
-        Ok(notification_form)
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
+}
+
+impl TryFrom<SendDocumentRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendDocumentRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendDocumentRequestModel to Form...");
+        m.into_form(Form::new())
+    }
+}
+
+impl TryFrom<SendDocumentRequestModel> for DryRunForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendDocumentRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendDocumentRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendDocumentRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendDocumentRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendDocumentRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
+    }
+}
+
+#[cfg(feature = "async")]
+impl TryFrom<SendDocumentRequestModel> for reqwest::multipart::Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendDocumentRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendDocumentRequestModel to async Form...");
+        m.into_form(reqwest::multipart::Form::new())
     }
 }
 
@@ -102,19 +129,17 @@ impl From<SendDocumentParams> for SendDocumentRequestModel {
             Err(_) => ChatId::Str(params.2.receiver),
         };
 
-        let caption = params.3.message;
+        let caption = params.3.message.map(|c| params.2.format.convert(c));
 
         let parse_mode = match params.2.format {
             send::MessageFormat::Markdown => ParseMode::Markdown,
-            send::MessageFormat::HTML => ParseMode::HTML,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
         };
 
         let document = InputFile::Local(params.3.file);
 
-        let thumbnail = match params.3.thumbnail {
-            Some(p) => Some(InputFile::Local(p)),
-            None => None,
-        };
+        let fit_thumbnail = params.3.thumbnail.as_ref().is_some_and(|t| t.fit);
+        let thumbnail = params.3.thumbnail.map(|t| InputFile::Local(t.file));
 
         let disable_notification = params.2.silent;
 
@@ -124,7 +149,10 @@ impl From<SendDocumentParams> for SendDocumentRequestModel {
             parse_mode,
             document,
             thumbnail,
+            fit_thumbnail,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
+            progress: params.0.progress,
         }
     }
 }
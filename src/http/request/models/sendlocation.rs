@@ -2,9 +2,12 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{bot::send::location::SendLocationParams, OperationError};
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    operations::{bot::send::location::SendLocationParams, OperationError},
+};
 
-use super::ChatId;
+use super::{ChatId, MultipartForm};
 
 // Copyright 2021 Eray Erdin
 //
@@ -20,12 +23,60 @@ use super::ChatId;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SendLocationRequestModel {
     chat_id: ChatId,
     latitude: f32,
     longitude: f32,
+    live_period: Option<usize>,
+    heading: Option<usize>,
+    horizontal_accuracy: Option<f32>,
+    proximity_alert_radius: Option<usize>,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+}
+
+impl SendLocationRequestModel {
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("latitude", self.latitude.to_string())
+            .with_text("longitude", self.longitude.to_string());
+
+        let live_period_form = match self.live_period {
+            Some(l) => initial_form.with_text("live_period", l.to_string()),
+            None => initial_form,
+        };
+
+        let heading_form = match self.heading {
+            Some(h) => live_period_form.with_text("heading", h.to_string()),
+            None => live_period_form,
+        };
+
+        let accuracy_form = match self.horizontal_accuracy {
+            Some(a) => heading_form.with_text("horizontal_accuracy", a.to_string()),
+            None => heading_form,
+        };
+
+        let proximity_form = match self.proximity_alert_radius {
+            Some(p) => accuracy_form.with_text("proximity_alert_radius", p.to_string()),
+            None => accuracy_form,
+        };
+
+        let notification_form = match self.disable_notification {
+            true => proximity_form.with_text("disable_notification", "true".to_owned()),
+            false => proximity_form,
+        };
+
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
 }
 
 impl TryFrom<SendLocationRequestModel> for Form {
@@ -33,19 +84,25 @@ impl TryFrom<SendLocationRequestModel> for Form {
 
     fn try_from(m: SendLocationRequestModel) -> Result<Self, Self::Error> {
         debug!("Converting SendLocationRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
+        m.into_form(Form::new())
+    }
+}
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("latitude", m.latitude.to_string())
-            .text("longitude", m.longitude.to_string());
+impl TryFrom<SendLocationRequestModel> for DryRunForm {
+    type Error = OperationError;
 
-        let notification_form = match m.disable_notification {
-            true => initial_form.text("disable_notification", "true"),
-            false => initial_form,
-        };
+    fn try_from(m: SendLocationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendLocationRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
 
-        Ok(notification_form)
+impl TryFrom<SendLocationRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendLocationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendLocationRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
     }
 }
 
@@ -60,13 +117,22 @@ impl From<SendLocationParams> for SendLocationRequestModel {
 
         let latitude = params.3.latitude;
         let longitude = params.3.longitude;
+        let live_period = params.3.live_period;
+        let heading = params.3.heading;
+        let horizontal_accuracy = params.3.horizontal_accuracy;
+        let proximity_alert_radius = params.3.proximity_alert_radius;
         let disable_notification = params.2.silent;
 
         SendLocationRequestModel {
             chat_id,
             latitude,
             longitude,
+            live_period,
+            heading,
+            horizontal_accuracy,
+            proximity_alert_radius,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
         }
     }
 }
@@ -2,12 +2,15 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{
-    bot::send::{self, message::SendMessageParams},
-    OperationError,
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    operations::{
+        bot::send::{self, entities::MessageEntity, message::SendMessageParams},
+        OperationError,
+    },
 };
 
-use super::{ChatId, ParseMode};
+use super::{editmessage::EditContent, ChatId, MultipartForm, ParseMode};
 
 // Copyright 2021 Eray Erdin
 //
@@ -23,13 +26,54 @@ use super::{ChatId, ParseMode};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// A model for /sendMessage request.
 pub struct SendMessageRequestModel {
     chat_id: ChatId,
     text: String,
     parse_mode: ParseMode,
+    /// Sent instead of `parse_mode` if present.
+    entities: Option<Vec<MessageEntity>>,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+}
+
+impl SendMessageRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::text(
+            self.text.clone(),
+            self.parse_mode.clone(),
+            self.entities.clone(),
+        )
+    }
+
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
+
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("text", self.text);
+
+        // the text is taken as is with entities
+        let format_form = match self.entities {
+            Some(e) => initial_form.with_text("entities", serde_json::to_string(&e).unwrap()),
+            None => initial_form.with_text("parse_mode", parse_mode),
+        };
+
+        let notification_form = match self.disable_notification {
+            true => format_form.with_text("disable_notification", "true".to_owned()),
+            false => format_form,
+        };
+
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
 }
 
 impl TryFrom<SendMessageRequestModel> for Form {
@@ -37,20 +81,35 @@ impl TryFrom<SendMessageRequestModel> for Form {
 
     fn try_from(m: SendMessageRequestModel) -> Result<Self, Self::Error> {
         debug!("Converting SendMessageRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
-        let parse_mode = m.parse_mode.to_string();
+        m.into_form(Form::new())
+    }
+}
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("text", m.text)
-            .text("parse_mode", parse_mode);
+impl TryFrom<SendMessageRequestModel> for DryRunForm {
+    type Error = OperationError;
 
-        let notification_form = match m.disable_notification {
-            true => initial_form.text("disable_notification", "true"),
-            false => initial_form,
-        };
+    fn try_from(m: SendMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendMessageRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendMessageRequestModel> for OutboxForm {
+    type Error = OperationError;
 
-        Ok(notification_form)
+    fn try_from(m: SendMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendMessageRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
+    }
+}
+
+#[cfg(feature = "async")]
+impl TryFrom<SendMessageRequestModel> for reqwest::multipart::Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendMessageRequestModel to async Form...");
+        m.into_form(reqwest::multipart::Form::new())
     }
 }
 
@@ -63,11 +122,15 @@ impl From<SendMessageParams> for SendMessageRequestModel {
             Err(_) => ChatId::Str(params.2.receiver),
         };
 
-        let text = params.3.message;
+        // the text is taken as is with entities
+        let text = match params.3.entities.is_some() {
+            true => params.3.message,
+            false => params.2.format.convert(params.3.message),
+        };
 
         let parse_mode = match params.2.format {
             send::MessageFormat::Markdown => ParseMode::Markdown,
-            send::MessageFormat::HTML => ParseMode::HTML,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
         };
 
         let disable_notification = params.2.silent;
@@ -76,7 +139,9 @@ impl From<SendMessageParams> for SendMessageRequestModel {
             chat_id,
             text,
             parse_mode,
+            entities: params.3.entities,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
         }
     }
 }
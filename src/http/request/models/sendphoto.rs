@@ -2,12 +2,15 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{
-    bot::send::{self, photo::SendPhotoParams},
-    CommonExitCodes, OperationError,
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    operations::{
+        bot::send::{self, photo::SendPhotoParams},
+        OperationError, ProgressMode,
+    },
 };
 
-use super::{ChatId, InputFile, ParseMode};
+use super::{editmessage::EditContent, ChatId, InputFile, MultipartForm, ParseMode};
 
 // Copyright 2021 Eray Erdin
 //
@@ -23,53 +26,90 @@ use super::{ChatId, InputFile, ParseMode};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SendPhotoRequestModel {
     chat_id: ChatId,
     photo: InputFile,
     caption: Option<String>,
     parse_mode: ParseMode,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+    progress: ProgressMode,
 }
 
-impl TryFrom<SendPhotoRequestModel> for Form {
-    type Error = OperationError;
+impl SendPhotoRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::caption(self.caption.clone(), self.parse_mode.clone())
+    }
 
-    fn try_from(m: SendPhotoRequestModel) -> Result<Self, Self::Error> {
-        debug!("Converting SendPhotoRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
-        let parse_mode = m.parse_mode.to_string();
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("parse_mode", parse_mode);
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("parse_mode", parse_mode);
 
-        let caption_form = match m.caption {
-            Some(c) => initial_form.text("caption", c),
+        let caption_form = match self.caption {
+            Some(c) => initial_form.with_text("caption", c),
             None => initial_form,
         };
 
-        let photo_form = match m.photo {
-            InputFile::Local(p) => match caption_form.file("photo", p) {
-                Ok(file) => file,
-                Err(e) => {
-                    return Err(OperationError::new(
-                        CommonExitCodes::ReqwestFormError as i32,
-                        "An error occured while attaching file to request form.",
-                        Some(e),
-                    ))
-                }
-            },
-            InputFile::Remote(u) => caption_form.text("photo", u.to_string()),
-            InputFile::Id(i) => caption_form.text("photo", i),
+        let photo_form = match self.photo {
+            InputFile::Local(p) => caption_form.with_file("photo", p, self.progress)?,
+            InputFile::Remote(u) => caption_form.with_text("photo", u.to_string()),
+            InputFile::Id(i) => caption_form.with_text("photo", i),
         };
 
-        let notification_form = match m.disable_notification {
-            true => photo_form.text("disable_notification", "true"),
+        let notification_form = match self.disable_notification {
+            true => photo_form.with_text("disable_notification", "true".to_owned()),
             false => photo_form,
         };
 
-        Ok(notification_form)
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
+}
+
+impl TryFrom<SendPhotoRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendPhotoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPhotoRequestModel to Form...");
+        m.into_form(Form::new())
+    }
+}
+
+impl TryFrom<SendPhotoRequestModel> for DryRunForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendPhotoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPhotoRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendPhotoRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendPhotoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPhotoRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
+    }
+}
+
+#[cfg(feature = "async")]
+impl TryFrom<SendPhotoRequestModel> for reqwest::multipart::Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendPhotoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPhotoRequestModel to async Form...");
+        m.into_form(reqwest::multipart::Form::new())
     }
 }
 
@@ -82,11 +122,11 @@ impl From<SendPhotoParams> for SendPhotoRequestModel {
             Err(_) => ChatId::Str(params.2.receiver),
         };
 
-        let caption = params.3.message;
+        let caption = params.3.message.map(|c| params.2.format.convert(c));
 
         let parse_mode = match params.2.format {
             send::MessageFormat::Markdown => ParseMode::Markdown,
-            send::MessageFormat::HTML => ParseMode::HTML,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
         };
 
         let photo = InputFile::Local(params.3.file);
@@ -99,6 +139,8 @@ impl From<SendPhotoParams> for SendPhotoRequestModel {
             parse_mode,
             photo,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
+            progress: params.0.progress,
         }
     }
 }
@@ -3,9 +3,12 @@ use std::convert::TryFrom;
 use reqwest::blocking::multipart::Form;
 use serde_json::json;
 
-use crate::operations::{bot::send::poll::SendPollParams, OperationError};
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    operations::{bot::send::poll::SendPollParams, OperationError},
+};
 
-use super::ChatId;
+use super::{ChatId, MultipartForm};
 
 // Copyright 2021 Eray Erdin
 //
@@ -21,12 +24,37 @@ use super::ChatId;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SendPollRequestModel {
     chat_id: ChatId,
     question: String,
     options: Vec<String>,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+}
+
+impl SendPollRequestModel {
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let options = json!(self.options).to_string();
+
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("question", self.question)
+            .with_text("options", options);
+
+        let notification_form = match self.disable_notification {
+            true => initial_form.with_text("disable_notification", "true".to_owned()),
+            false => initial_form,
+        };
+
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
 }
 
 impl TryFrom<SendPollRequestModel> for Form {
@@ -34,20 +62,25 @@ impl TryFrom<SendPollRequestModel> for Form {
 
     fn try_from(m: SendPollRequestModel) -> Result<Self, Self::Error> {
         debug!("Converting SendPollRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
-        let options = json!(m.options).to_string();
+        m.into_form(Form::new())
+    }
+}
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("question", m.question)
-            .text("options", options);
+impl TryFrom<SendPollRequestModel> for DryRunForm {
+    type Error = OperationError;
 
-        let notification_form = match m.disable_notification {
-            true => initial_form.text("disable_notification", "true"),
-            false => initial_form,
-        };
+    fn try_from(m: SendPollRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPollRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
 
-        Ok(notification_form)
+impl TryFrom<SendPollRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendPollRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendPollRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
     }
 }
 
@@ -69,6 +102,7 @@ impl From<SendPollParams> for SendPollRequestModel {
             question,
             options,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
         }
     }
 }
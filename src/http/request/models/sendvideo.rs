@@ -2,12 +2,18 @@ use std::convert::TryFrom;
 
 use reqwest::blocking::multipart::Form;
 
-use crate::operations::{
-    bot::send::{self, video::SendVideoParams},
-    CommonExitCodes, OperationError,
+use crate::{
+    http::request::{dryrun::DryRunForm, outbox::OutboxForm},
+    media,
+    operations::{
+        bot::send::{self, video::SendVideoParams},
+        OperationError, ProgressMode,
+    },
 };
 
-use super::{ChatId, InputFile, ParseMode};
+use super::{
+    editmessage::EditContent, thumbnail_form, ChatId, InputFile, MultipartForm, ParseMode,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -23,65 +29,108 @@ use super::{ChatId, InputFile, ParseMode};
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SendVideoRequestModel {
     chat_id: ChatId,
     video: InputFile,
     width: Option<usize>,
     height: Option<usize>,
+    duration: Option<usize>,
+    supports_streaming: bool,
+    thumbnail: Option<InputFile>,
+    fit_thumbnail: bool,
     caption: Option<String>,
     parse_mode: ParseMode,
     disable_notification: bool,
+    reply_to_message_id: Option<usize>,
+    progress: ProgressMode,
 }
 
-impl TryFrom<SendVideoRequestModel> for Form {
-    type Error = OperationError;
+impl SendVideoRequestModel {
+    /// What the message sent before is edited to have instead.
+    pub fn edit_content(&self) -> EditContent {
+        EditContent::caption(self.caption.clone(), self.parse_mode.clone())
+    }
 
-    fn try_from(m: SendVideoRequestModel) -> Result<Self, Self::Error> {
-        debug!("Converting to SendVideoRequestModel to Form...");
-        let chat_id = m.chat_id.to_string();
-        let parse_mode = m.parse_mode.to_string();
+    fn into_form<F: MultipartForm>(self, form: F) -> Result<F, OperationError> {
+        let chat_id = self.chat_id.to_string();
+        let parse_mode = self.parse_mode.to_string();
 
-        let initial_form = Form::new()
-            .text("chat_id", chat_id)
-            .text("parse_mode", parse_mode);
+        let initial_form = form
+            .with_text("chat_id", chat_id)
+            .with_text("parse_mode", parse_mode);
 
-        let caption_form = match m.caption {
-            Some(c) => initial_form.text("caption", c),
+        let caption_form = match self.caption {
+            Some(c) => initial_form.with_text("caption", c),
             None => initial_form,
         };
 
-        let video_form = match m.video {
-            InputFile::Local(p) => match caption_form.file("video", p) {
-                Ok(file) => file,
-                Err(e) => {
-                    return Err(OperationError::new(
-                        CommonExitCodes::ReqwestFormError as i32,
-                        "An error occured while attaching file to request form.",
-                        Some(e),
-                    ))
-                }
-            },
-            InputFile::Remote(u) => caption_form.text("video", u.to_string()),
-            InputFile::Id(i) => caption_form.text("video", i),
+        let video_form = match self.video {
+            InputFile::Local(p) => caption_form.with_file("video", p, self.progress)?,
+            InputFile::Remote(u) => caption_form.with_text("video", u.to_string()),
+            InputFile::Id(i) => caption_form.with_text("video", i),
         };
 
-        let width_form = match m.width {
-            Some(w) => video_form.text("width", w.to_string()),
+        let width_form = match self.width {
+            Some(w) => video_form.with_text("width", w.to_string()),
             None => video_form,
         };
 
-        let height_form = match m.height {
-            Some(h) => width_form.text("height", h.to_string()),
+        let height_form = match self.height {
+            Some(h) => width_form.with_text("height", h.to_string()),
             None => width_form,
         };
 
-        let notification_form = match m.disable_notification {
-            true => height_form.text("disable_notification", "true"),
-            false => height_form,
+        let duration_form = match self.duration {
+            Some(d) => height_form.with_text("duration", d.to_string()),
+            None => height_form,
+        };
+
+        let streaming_form = match self.supports_streaming {
+            true => duration_form.with_text("supports_streaming", "true".to_owned()),
+            false => duration_form,
+        };
+
+        let thumbnail_form = thumbnail_form(streaming_form, self.thumbnail, self.fit_thumbnail)?;
+
+        let notification_form = match self.disable_notification {
+            true => thumbnail_form.with_text("disable_notification", "true".to_owned()),
+            false => thumbnail_form,
         };
 
-        Ok(notification_form)
+        let reply_form = match self.reply_to_message_id {
+            Some(id) => notification_form.with_text("reply_to_message_id", id.to_string()),
+            None => notification_form,
+        };
+
+        Ok(reply_form)
+    }
+}
+
+impl TryFrom<SendVideoRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SendVideoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting to SendVideoRequestModel to Form...");
+        m.into_form(Form::new())
+    }
+}
+
+impl TryFrom<SendVideoRequestModel> for DryRunForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendVideoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendVideoRequestModel to DryRunForm...");
+        m.into_form(DryRunForm::default())
+    }
+}
+
+impl TryFrom<SendVideoRequestModel> for OutboxForm {
+    type Error = OperationError;
+
+    fn try_from(m: SendVideoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SendVideoRequestModel to OutboxForm...");
+        m.into_form(OutboxForm::default())
     }
 }
 
@@ -94,16 +143,26 @@ impl From<SendVideoParams> for SendVideoRequestModel {
             Err(_) => ChatId::Str(params.2.receiver),
         };
 
-        let caption = params.3.message;
+        let caption = params.3.message.map(|c| params.2.format.convert(c));
 
         let parse_mode = match params.2.format {
             send::MessageFormat::Markdown => ParseMode::Markdown,
-            send::MessageFormat::HTML => ParseMode::HTML,
+            send::MessageFormat::HTML | send::MessageFormat::CommonMark => ParseMode::HTML,
+        };
+
+        // explicit values take precedence over the probed ones
+        let probed = match (params.3.width, params.3.height, params.3.duration) {
+            (Some(_), Some(_), Some(_)) => media::MediaInfo::default(),
+            _ => media::probe(&params.3.file).unwrap_or_default(),
         };
 
+        let width = params.3.width.or(probed.width);
+        let height = params.3.height.or(probed.height);
+        let duration = params.3.duration.or(probed.duration);
+        let supports_streaming = params.3.supports_streaming;
         let video = InputFile::Local(params.3.file);
-        let width = params.3.horizontal;
-        let height = params.3.vertical;
+        let fit_thumbnail = params.3.thumbnail.as_ref().is_some_and(|t| t.fit);
+        let thumbnail = params.3.thumbnail.map(|t| InputFile::Local(t.file));
 
         let disable_notification = params.2.silent;
 
@@ -114,7 +173,13 @@ impl From<SendVideoParams> for SendVideoRequestModel {
             video,
             width,
             height,
+            duration,
+            supports_streaming,
+            thumbnail,
+            fit_thumbnail,
             disable_notification,
+            reply_to_message_id: params.2.reply_to,
+            progress: params.0.progress,
         }
     }
 }
@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::description::SetChatDescriptionParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /setChatDescription request.
+pub struct SetChatDescriptionRequestModel {
+    chat_id: ChatId,
+    description: Option<String>,
+}
+
+impl TryFrom<SetChatDescriptionRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SetChatDescriptionRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SetChatDescriptionRequestModel to Form...");
+        let form = Form::new().text("chat_id", m.chat_id.to_string());
+
+        let description_form = match m.description {
+            Some(d) => form.text("description", d),
+            None => form,
+        };
+
+        Ok(description_form)
+    }
+}
+
+impl From<SetChatDescriptionParams> for SetChatDescriptionRequestModel {
+    fn from(params: SetChatDescriptionParams) -> Self {
+        debug!("Converting SetChatDescriptionParams to SetChatDescriptionRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        SetChatDescriptionRequestModel {
+            chat_id,
+            description: params.3.description,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::photo::SetChatPhotoParams, OperationError, ProgressMode};
+
+use super::{file_part, ChatId, InputFile};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /setChatPhoto request.
+pub struct SetChatPhotoRequestModel {
+    chat_id: ChatId,
+    photo: InputFile,
+    progress: ProgressMode,
+}
+
+impl TryFrom<SetChatPhotoRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SetChatPhotoRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SetChatPhotoRequestModel to Form...");
+        let form = Form::new().text("chat_id", m.chat_id.to_string());
+
+        // chat photos can only be uploaded as a new file
+        let photo_form = match m.photo {
+            InputFile::Local(p) => match file_part(p, m.progress) {
+                Ok(part) => form.part("photo", part),
+                Err(e) => return Err(e),
+            },
+            InputFile::Remote(u) => form.text("photo", u.to_string()),
+            InputFile::Id(i) => form.text("photo", i),
+        };
+
+        Ok(photo_form)
+    }
+}
+
+impl From<SetChatPhotoParams> for SetChatPhotoRequestModel {
+    fn from(params: SetChatPhotoParams) -> Self {
+        debug!("Converting SetChatPhotoParams to SetChatPhotoRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        SetChatPhotoRequestModel {
+            chat_id,
+            photo: InputFile::Local(params.3.file),
+            progress: params.0.progress,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+use serde_json::Value;
+
+use crate::{
+    http::response::models::botcommand::BotCommandModel,
+    operations::{bot::commands::CommandsParams, CommonExitCodes, OperationError},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct SetMyCommandsRequestModel {
+    commands: Vec<BotCommandModel>,
+    scope: Value,
+    language_code: Option<String>,
+}
+
+impl TryFrom<SetMyCommandsRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SetMyCommandsRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SetMyCommandsRequestModel to Form...");
+        let commands = match serde_json::to_string(&m.commands) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::ReqwestFormError as i32,
+                    "Could not serialize the commands.",
+                    Some(e),
+                ))
+            }
+        };
+
+        let form = Form::new()
+            .text("commands", commands)
+            .text("scope", m.scope.to_string());
+
+        let language_form = match m.language_code {
+            Some(l) => form.text("language_code", l),
+            None => form,
+        };
+
+        Ok(language_form)
+    }
+}
+
+impl From<(CommandsParams, Vec<BotCommandModel>)> for SetMyCommandsRequestModel {
+    fn from(params: (CommandsParams, Vec<BotCommandModel>)) -> Self {
+        debug!("Converting CommandsParams to SetMyCommandsRequestModel...");
+
+        SetMyCommandsRequestModel {
+            commands: params.1,
+            scope: params.0.scope.to_json(),
+            language_code: params.0.language_code,
+        }
+    }
+}
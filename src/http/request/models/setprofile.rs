@@ -0,0 +1,64 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{
+    bot::profile::{ProfileField, ProfileOperationParams},
+    OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The request model of /setMyName, /setMyDescription and /setMyShortDescription.
+#[derive(Debug)]
+pub struct SetProfileRequestModel {
+    field: ProfileField,
+    value: Option<String>,
+    language_code: Option<String>,
+}
+
+impl TryFrom<SetProfileRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: SetProfileRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting SetProfileRequestModel to Form...");
+        let form = Form::new();
+
+        // an empty form removes the value
+        let value_form = match m.value {
+            Some(v) => form.text(m.field.parameter(), v),
+            None => form,
+        };
+
+        let language_form = match m.language_code {
+            Some(l) => value_form.text("language_code", l),
+            None => value_form,
+        };
+
+        Ok(language_form)
+    }
+}
+
+impl From<ProfileOperationParams> for SetProfileRequestModel {
+    fn from(params: ProfileOperationParams) -> Self {
+        debug!("Converting ProfileOperationParams to SetProfileRequestModel...");
+
+        SetProfileRequestModel {
+            field: params.2.field,
+            value: params.2.value,
+            language_code: params.2.language_code,
+        }
+    }
+}
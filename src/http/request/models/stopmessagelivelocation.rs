@@ -0,0 +1,61 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::location::stop::StopLocationParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /stopMessageLiveLocation request.
+pub struct StopMessageLiveLocationRequestModel {
+    chat_id: ChatId,
+    message_id: usize,
+}
+
+impl TryFrom<StopMessageLiveLocationRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: StopMessageLiveLocationRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting StopMessageLiveLocationRequestModel to Form...");
+        let chat_id = m.chat_id.to_string();
+
+        let form = Form::new()
+            .text("chat_id", chat_id)
+            .text("message_id", m.message_id.to_string());
+
+        Ok(form)
+    }
+}
+
+impl From<StopLocationParams> for StopMessageLiveLocationRequestModel {
+    fn from(params: StopLocationParams) -> Self {
+        debug!("Converting StopLocationParams to StopMessageLiveLocationRequestModel...");
+
+        let chat_id = match params.2.receiver.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.receiver),
+        };
+
+        let message_id = params.2.message_id;
+
+        StopMessageLiveLocationRequestModel {
+            chat_id,
+            message_id,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::member::unban::UnbanMemberParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct UnbanChatMemberRequestModel {
+    chat_id: ChatId,
+    user_id: u64,
+    only_if_banned: bool,
+}
+
+impl TryFrom<UnbanChatMemberRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: UnbanChatMemberRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting UnbanChatMemberRequestModel to Form...");
+        let form = Form::new()
+            .text("chat_id", m.chat_id.to_string())
+            .text("user_id", m.user_id.to_string());
+
+        let banned_form = match m.only_if_banned {
+            true => form.text("only_if_banned", "true"),
+            false => form,
+        };
+
+        Ok(banned_form)
+    }
+}
+
+impl From<UnbanMemberParams> for UnbanChatMemberRequestModel {
+    fn from(params: UnbanMemberParams) -> Self {
+        debug!("Converting UnbanMemberParams to UnbanChatMemberRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        UnbanChatMemberRequestModel {
+            chat_id,
+            user_id: params.2.user,
+            only_if_banned: params.3.only_if_banned,
+        }
+    }
+}
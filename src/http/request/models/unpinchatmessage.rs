@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{bot::chat::pin::UnpinMessageParams, OperationError};
+
+use super::ChatId;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// A model for /unpinChatMessage request.
+pub struct UnpinChatMessageRequestModel {
+    chat_id: ChatId,
+    message_id: Option<usize>,
+}
+
+impl TryFrom<UnpinChatMessageRequestModel> for Form {
+    type Error = OperationError;
+
+    fn try_from(m: UnpinChatMessageRequestModel) -> Result<Self, Self::Error> {
+        debug!("Converting UnpinChatMessageRequestModel to Form...");
+        let form = Form::new().text("chat_id", m.chat_id.to_string());
+
+        let message_form = match m.message_id {
+            Some(i) => form.text("message_id", i.to_string()),
+            None => form,
+        };
+
+        Ok(message_form)
+    }
+}
+
+impl From<UnpinMessageParams> for UnpinChatMessageRequestModel {
+    fn from(params: UnpinMessageParams) -> Self {
+        debug!("Converting UnpinMessageParams to UnpinChatMessageRequestModel...");
+
+        let chat_id = match params.2.chat.parse::<usize>() {
+            Ok(v) => ChatId::Int(v),
+            Err(_) => ChatId::Str(params.2.chat),
+        };
+
+        UnpinChatMessageRequestModel {
+            chat_id,
+            message_id: params.3.message_id,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, FixedOffset, Local};
+use reqwest::blocking::multipart::Form;
+
+use crate::operations::{schedule::entry_id, CommonExitCodes, OperationError, ProgressMode};
+
+use super::models::{file_part, MultipartForm};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The file of an entry in the outbox with the request.
+const ENTRY_FILE: &str = "entry.json";
+
+/// The content of a file that is copied into the outbox.
+#[derive(Debug)]
+enum Attachment {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxPart {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    /// The copy of the file relative to the directory of the entry, which has
+    /// the name of the original.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<PathBuf>,
+}
+
+/// A form that is kept in the outbox. Files are only copied once it is
+/// kept.
+#[derive(Debug, Default)]
+pub struct OutboxForm {
+    parts: Vec<OutboxPart>,
+    attachments: Vec<(PathBuf, Attachment)>,
+}
+
+impl OutboxForm {
+    fn with_attachment(
+        mut self,
+        name: &'static str,
+        file_name: &str,
+        attachment: Attachment,
+    ) -> Self {
+        // every file has its own directory so that their names never clash
+        let file = PathBuf::from(self.parts.len().to_string()).join(file_name);
+        self.parts.push(OutboxPart {
+            name: String::from(name),
+            value: None,
+            file: Some(file.clone()),
+        });
+        self.attachments.push((file, attachment));
+        self
+    }
+}
+
+impl MultipartForm for OutboxForm {
+    fn with_text(mut self, name: &'static str, value: String) -> Self {
+        self.parts.push(OutboxPart {
+            name: String::from(name),
+            value: Some(value),
+            file: None,
+        });
+        self
+    }
+
+    fn with_file(
+        self,
+        name: &'static str,
+        path: PathBuf,
+        _progress: ProgressMode,
+    ) -> Result<Self, OperationError> {
+        let file_name = match path.file_name() {
+            Some(n) => n.to_string_lossy().to_string(),
+            None => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsInvalidFilename as i32,
+                    format!("{} is not a file.", path.display()),
+                    None::<&str>,
+                ))
+            }
+        };
+        Ok(self.with_attachment(name, &file_name, Attachment::Path(path)))
+    }
+
+    fn with_bytes(
+        self,
+        name: &'static str,
+        bytes: Vec<u8>,
+        file_name: &'static str,
+        _mime: &str,
+    ) -> Result<Self, OperationError> {
+        Ok(self.with_attachment(name, file_name, Attachment::Bytes(bytes)))
+    }
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::StdFsWriteError as i32,
+        format!("Could not write to the outbox.\nPath: {}", path.display()),
+        Some(e),
+    )
+}
+
+/// A request that failed and is kept in the outbox to be sent later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// The method of Bot API, e.g. `sendMessage`.
+    pub method: String,
+    parts: Vec<OutboxPart>,
+    pub created: DateTime<FixedOffset>,
+    /// How many times the request has failed.
+    pub attempts: u32,
+    pub last_error: String,
+}
+
+impl OutboxEntry {
+    /// Keeps a failed request in `outbox` with copies of its files and returns
+    /// the id of the entry.
+    pub fn keep(
+        outbox: &Path,
+        method: &str,
+        form: OutboxForm,
+        error: &OperationError,
+    ) -> Result<String, OperationError> {
+        let id = entry_id();
+        // the entry is only visible once it is complete
+        let temp_dir = outbox.join(format!("{}.tmp", id));
+
+        for (file, attachment) in form.attachments {
+            let path = temp_dir.join(&file);
+            if let Err(e) = fs::create_dir_all(path.parent().unwrap()) {
+                return Err(write_error(&path, e));
+            }
+            let result = match attachment {
+                Attachment::Path(p) => fs::copy(p, &path).map(|_| ()),
+                Attachment::Bytes(b) => fs::write(&path, b),
+            };
+            if let Err(e) = result {
+                return Err(write_error(&path, e));
+            }
+        }
+
+        let entry = OutboxEntry {
+            method: String::from(method),
+            parts: form.parts,
+            created: Local::now().into(),
+            attempts: 1,
+            last_error: error.to_string(),
+        };
+        match entry.write(&temp_dir) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let dir = outbox.join(&id);
+        match fs::rename(&temp_dir, &dir) {
+            Ok(_) => Ok(id),
+            Err(e) => Err(write_error(&dir, e)),
+        }
+    }
+
+    /// The entries in `outbox` by their ids, in the order they were kept.
+    pub fn list(outbox: &Path) -> Result<Vec<(String, OutboxEntry)>, OperationError> {
+        let dir = match fs::read_dir(outbox) {
+            Ok(d) => d,
+            // nothing has been kept yet
+            Err(_) if !outbox.exists() => return Ok(vec![]),
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!("Could not read the outbox.\nPath: {}", outbox.display()),
+                    Some(e),
+                ))
+            }
+        };
+
+        let mut ids: Vec<String> = dir
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().join(ENTRY_FILE).is_file())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|id| !id.ends_with(".tmp"))
+            .collect();
+        ids.sort();
+
+        let mut entries = Vec::new();
+        for id in ids {
+            let path = outbox.join(&id).join(ENTRY_FILE);
+            let entry = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|c| serde_json::from_str(&c).map_err(|e| e.to_string()));
+            match entry {
+                Ok(e) => entries.push((id, e)),
+                Err(e) => warn!("Skipping {}: {}", id, e),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Writes the entry into `dir` as a whole.
+    pub fn write(&self, dir: &Path) -> Result<(), OperationError> {
+        if let Err(e) = fs::create_dir_all(dir) {
+            return Err(write_error(dir, e));
+        }
+
+        let path = dir.join(ENTRY_FILE);
+        let temp_path = dir.join(format!("{}.tmp", ENTRY_FILE));
+        let content = serde_json::to_string_pretty(self).unwrap();
+        match fs::write(&temp_path, content).and_then(|_| fs::rename(&temp_path, &path)) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(write_error(&path, e)),
+        }
+    }
+
+    /// Removes the entry with `id` and its files from `outbox`.
+    pub fn remove(outbox: &Path, id: &str) -> Result<(), OperationError> {
+        let dir = outbox.join(id);
+        match fs::remove_dir_all(&dir) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(write_error(&dir, e)),
+        }
+    }
+
+    /// The form of the request with the files of the entry in `dir`.
+    pub fn form(&self, dir: &Path, progress: ProgressMode) -> Result<Form, OperationError> {
+        let mut form = Form::new();
+        for part in self.parts.iter().cloned() {
+            form = match (part.value, part.file) {
+                (Some(v), _) => form.text(part.name, v),
+                (None, Some(f)) => match file_part(dir.join(f), progress) {
+                    Ok(p) => form.part(part.name, p),
+                    Err(e) => return Err(e),
+                },
+                (None, None) => form,
+            };
+        }
+        Ok(form)
+    }
+
+    /// The value of a text part, e.g. `chat_id`.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|p| p.name == name)
+            .and_then(|p| p.value.as_deref())
+    }
+}
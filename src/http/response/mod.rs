@@ -12,8 +12,98 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use reqwest::{blocking::Response, StatusCode};
+
+use crate::operations::{CommonExitCodes, OperationError, TelegramError};
+
+use self::models::{message::MessageModel, GenericResponseModel};
+
 pub mod models;
 
+/// Converts a failed response into an `OperationError::Telegram` with the
+/// error code, description and parameters Telegram provides.
+pub fn failed_response_error(response: Response) -> OperationError {
+    let status = response.status();
+    failed_body_error(
+        status,
+        response.json::<GenericResponseModel<MessageModel>>(),
+    )
+}
+
+fn failed_body_error(
+    status: StatusCode,
+    body: Result<GenericResponseModel<MessageModel>, reqwest::Error>,
+) -> OperationError {
+    match body {
+        Ok(i) => match i.description {
+            Some(d) => OperationError::Telegram(TelegramError::new(
+                i.error_code.unwrap_or_else(|| status.as_u16()),
+                d,
+                i.parameters,
+            )),
+            None => OperationError::new(
+                CommonExitCodes::TelegramAPIMissingDescription as i32,
+                "No description was provided by Telegram for this error.",
+                None::<&str>,
+            ),
+        },
+        Err(e) => OperationError::new(
+            CommonExitCodes::SerdeDeserializationError as i32,
+            "An error occurred while deserializing the response.",
+            Some(e),
+        ),
+    }
+}
+
+/// The non-blocking counterpart of `failed_response_error`.
+#[cfg(feature = "async")]
+pub async fn failed_async_response_error(response: reqwest::Response) -> OperationError {
+    let status = response.status();
+    failed_body_error(
+        status,
+        response.json::<GenericResponseModel<MessageModel>>().await,
+    )
+}
+
+/// The non-blocking counterpart of `handle_response!`, yielding the `result`
+/// of response deserialized into `T`.
+#[cfg(feature = "async")]
+pub async fn async_response_result<T: serde::de::DeserializeOwned>(
+    response: Result<reqwest::Response, reqwest::Error>,
+) -> Result<T, OperationError> {
+    let r = match response {
+        Ok(r) => r,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::ReqwestConnectionError as i32,
+                "An error occured while connecting to Telegram API.",
+                Some(e),
+            ))
+        }
+    };
+
+    if !r.status().is_success() {
+        return Err(failed_async_response_error(r).await);
+    }
+
+    trace!("response: {:?}", r);
+    match r.json::<GenericResponseModel<T>>().await {
+        Ok(GenericResponseModel {
+            result: Some(m), ..
+        }) => Ok(m),
+        Ok(_) => Err(OperationError::new(
+            CommonExitCodes::TelegramAPIMissingResult as i32,
+            "No result was provided by Telegram for this request.",
+            None::<&str>,
+        )),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::SerdeDeserializationError as i32,
+            "An error occurred while deserializing the response.",
+            Some(e),
+        )),
+    }
+}
+
 #[macro_export]
 macro_rules! handle_response {
     ($response:ident, on_success => $success:expr, on_failure => $failure:expr) => {
@@ -24,36 +114,63 @@ macro_rules! handle_response {
                     $success;
                     Ok(())
                 } else {
-                    use crate::http::response::models::{
-                        message::MessageModel, GenericResponseModel,
-                    };
                     $failure;
-                    use crate::operations::CommonExitCodes;
-                    use crate::operations::OperationError;
-                    match r.json::<GenericResponseModel<MessageModel>>() {
-                        Ok(i) => match i.description {
-                            Some(d) => Err(OperationError::new(
-                                CommonExitCodes::TelegramAPIBadRequest as i32,
-                                &d,
-                                Some(&d),
-                            )),
-                            None => Err(OperationError::new(
-                                CommonExitCodes::TelegramAPIMissingDescription as i32,
-                                "No description was provided by Telegram for this error.",
+                    Err($crate::http::response::failed_response_error(r))
+                }
+            }
+            Err(e) => {
+                use $crate::operations::CommonExitCodes;
+                use $crate::operations::OperationError;
+                $failure;
+                Err(OperationError::new(
+                    CommonExitCodes::ReqwestConnectionError as i32,
+                    "An error occured while connecting to Telegram API.",
+                    Some(e),
+                ))
+            }
+        }
+    };
+    // yields the `result` of response deserialized into `$model`
+    ($response:ident => $model:ty, on_success => $success:expr, on_failure => $failure:expr) => {
+        match $response {
+            Ok(r) => {
+                use $crate::http::response::models::GenericResponseModel;
+                use $crate::operations::CommonExitCodes;
+                use $crate::operations::OperationError;
+                if r.status().is_success() {
+                    trace!("response: {:?}", r);
+                    match r.json::<GenericResponseModel<$model>>() {
+                        Ok(GenericResponseModel {
+                            result: Some(m), ..
+                        }) => {
+                            $success;
+                            Ok(m)
+                        }
+                        Ok(_) => {
+                            $failure;
+                            Err(OperationError::new(
+                                CommonExitCodes::TelegramAPIMissingResult as i32,
+                                "No result was provided by Telegram for this request.",
                                 None::<&str>,
-                            )),
-                        },
-                        Err(e) => Err(OperationError::new(
-                            CommonExitCodes::SerdeDeserializationError as i32,
-                            "An error occurred while deserializing the response.",
-                            Some(e),
-                        )),
+                            ))
+                        }
+                        Err(e) => {
+                            $failure;
+                            Err(OperationError::new(
+                                CommonExitCodes::SerdeDeserializationError as i32,
+                                "An error occurred while deserializing the response.",
+                                Some(e),
+                            ))
+                        }
                     }
+                } else {
+                    $failure;
+                    Err($crate::http::response::failed_response_error(r))
                 }
             }
             Err(e) => {
-                use crate::operations::CommonExitCodes;
-                use crate::operations::OperationError;
+                use $crate::operations::CommonExitCodes;
+                use $crate::operations::OperationError;
                 $failure;
                 Err(OperationError::new(
                     CommonExitCodes::ReqwestConnectionError as i32,
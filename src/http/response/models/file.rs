@@ -0,0 +1,21 @@
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug, Deserialize)]
+pub struct FileModel {
+    pub file_unique_id: String,
+    pub file_size: Option<u64>,
+    /// Use `https://api.telegram.org/file/bot<token>/<file_path>` to download the file.
+    pub file_path: Option<String>,
+}
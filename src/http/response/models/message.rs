@@ -1,3 +1,7 @@
+use serde_json::{Map, Value};
+
+use super::user::UserModel;
+
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -12,5 +16,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Deserialize)]
-pub struct MessageModel;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatModel {
+    pub id: i64,
+    /// One of `private`, `group`, `supergroup` or `channel`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: Option<String>,
+    pub username: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageModel {
+    pub message_id: usize,
+    /// Unix timestamp of when the message was sent.
+    pub date: i64,
+    pub chat: ChatModel,
+    pub from: Option<UserModel>,
+    pub text: Option<String>,
+    pub caption: Option<String>,
+    /// The rest of the message, e.g. `document` or `photo`.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
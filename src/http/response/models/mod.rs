@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod botcommand;
+pub mod file;
+pub mod invitelink;
 pub mod message;
+pub mod user;
+
+use crate::operations::ResponseParameters;
 
 #[derive(Debug, Deserialize)]
 pub struct GenericResponseModel<T> {
     ok: bool,
-    error_code: usize,
+    pub error_code: Option<u16>,
     pub description: Option<String>,
-    result: Option<T>,
+    pub parameters: Option<ResponseParameters>,
+    pub result: Option<T>,
 }
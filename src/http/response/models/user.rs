@@ -0,0 +1,29 @@
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserModel {
+    pub id: u64,
+    pub is_bot: bool,
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub username: Option<String>,
+    pub language_code: Option<String>,
+    /// Only present for the bot itself.
+    pub can_join_groups: Option<bool>,
+    /// Only present for the bot itself.
+    pub can_read_all_group_messages: Option<bool>,
+    /// Only present for the bot itself.
+    pub supports_inline_queries: Option<bool>,
+}
@@ -21,9 +21,16 @@ extern crate serde;
 #[macro_use]
 extern crate async_trait;
 
+pub mod bot;
 pub mod cli;
 pub mod convert;
 mod http;
+mod media;
 pub mod operations;
 
-const API_ROOT_URL: &str = "https://api.telegram.org/bot";
+#[cfg(feature = "async")]
+pub use bot::AsyncBot;
+pub use bot::Bot;
+
+/// The official Bot API server.
+const API_URL: &str = "https://api.telegram.org";
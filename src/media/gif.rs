@@ -0,0 +1,78 @@
+use std::io::{Read, Seek};
+
+use super::{le_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF https://www.w3.org/Graphics/GIF/spec-gif89a.txt
+
+/// Skips data sub-blocks until the block terminator.
+fn skip_sub_blocks<R: Read>(r: &mut R) -> Option<()> {
+    loop {
+        match read_bytes(r, 1)?[0] {
+            0 => return Some(()),
+            size => {
+                read_bytes(r, size as usize)?;
+            }
+        }
+    }
+}
+
+fn color_table_length(flags: u8) -> usize {
+    match flags & 0x80 {
+        0 => 0,
+        _ => 3 * (1 << ((flags & 0x07) + 1)),
+    }
+}
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    let header = read_bytes(r, 13)?;
+    let width = le_uint(&header[6..8]) as usize;
+    let height = le_uint(&header[8..10]) as usize;
+    read_bytes(r, color_table_length(header[10]))?;
+
+    // delays are in hundredths of a second
+    let mut delay = 0;
+    loop {
+        match read_bytes(r, 1)?[0] {
+            // extension
+            0x21 => {
+                let label = read_bytes(r, 1)?[0];
+                if label == 0xf9 {
+                    let control = read_bytes(r, 5)?;
+                    delay += le_uint(&control[2..4]);
+                    read_bytes(r, 1)?;
+                } else {
+                    skip_sub_blocks(r)?;
+                }
+            }
+            // image
+            0x2c => {
+                let descriptor = read_bytes(r, 9)?;
+                read_bytes(r, color_table_length(descriptor[8]) + 1)?;
+                skip_sub_blocks(r)?;
+            }
+            // trailer
+            0x3b => break,
+            _ => return None,
+        }
+    }
+
+    Some(MediaInfo {
+        duration: seconds(delay as f64 / 100.0),
+        width: Some(width),
+        height: Some(height),
+    })
+}
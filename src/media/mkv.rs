@@ -0,0 +1,149 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{be_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF https://www.matroska.org/technical/elements.html
+
+const SEGMENT: u64 = 0x18538067;
+const INFO: u64 = 0x1549a966;
+const TIMESTAMP_SCALE: u64 = 0x2ad7b1;
+const DURATION: u64 = 0x4489;
+const TRACKS: u64 = 0x1654ae6b;
+const TRACK_ENTRY: u64 = 0xae;
+const VIDEO: u64 = 0xe0;
+const PIXEL_WIDTH: u64 = 0xb0;
+const PIXEL_HEIGHT: u64 = 0xba;
+const CLUSTER: u64 = 0x1f43b675;
+
+struct Element {
+    id: u64,
+    /// The offset where the content of element starts.
+    start: u64,
+    /// The offset where the element ends. `None` if the size is unknown.
+    end: Option<u64>,
+}
+
+/// Reads a variable-length integer, returns its value with and without the length marker.
+fn read_vint<R: Read>(r: &mut R) -> Option<(u64, u64, u32)> {
+    let first = read_bytes(r, 1)?[0];
+    let length = first.leading_zeros() + 1;
+    if length > 8 {
+        return None;
+    }
+
+    let rest = read_bytes(r, length as usize - 1)?;
+    let raw = be_uint(&[&[first], rest.as_slice()].concat());
+    let value = raw & !(1 << (7 * length));
+    Some((raw, value, length))
+}
+
+fn read_element<R: Read + Seek>(r: &mut R, limit: Option<u64>) -> Option<Element> {
+    let offset = r.stream_position().ok()?;
+    if limit.is_some_and(|l| offset >= l) {
+        return None;
+    }
+
+    let (id, _, _) = read_vint(r)?;
+    let (_, size, length) = read_vint(r)?;
+    let start = r.stream_position().ok()?;
+    // all bits set means the size is unknown
+    let end = match size == (1 << (7 * length)) - 1 {
+        true => None,
+        false => Some(start + size),
+    };
+
+    Some(Element { id, start, end })
+}
+
+fn read_uint<R: Read>(r: &mut R, element: &Element) -> Option<u64> {
+    let size = element.end? - element.start;
+    Some(be_uint(&read_bytes(r, size as usize)?))
+}
+
+fn read_float<R: Read>(r: &mut R, element: &Element) -> Option<f64> {
+    let bytes = read_bytes(r, (element.end? - element.start) as usize)?;
+    match bytes.len() {
+        4 => Some(f32::from_bits(be_uint(&bytes) as u32) as f64),
+        8 => Some(f64::from_bits(be_uint(&bytes))),
+        _ => None,
+    }
+}
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    let header = read_element(r, None)?;
+    r.seek(SeekFrom::Start(header.end?)).ok()?;
+
+    let segment = read_element(r, None)?;
+    if segment.id != SEGMENT {
+        return None;
+    }
+
+    let mut info = MediaInfo::default();
+    let mut scale = 1_000_000;
+    let mut duration = None;
+
+    while let Some(element) = read_element(r, segment.end) {
+        match element.id {
+            INFO => {
+                while let Some(child) = read_element(r, element.end) {
+                    match child.id {
+                        TIMESTAMP_SCALE => scale = read_uint(r, &child)?,
+                        DURATION => duration = read_float(r, &child),
+                        _ => (),
+                    }
+                    r.seek(SeekFrom::Start(child.end?)).ok()?;
+                }
+            }
+            TRACKS => {
+                while let Some(entry) = read_element(r, element.end) {
+                    if entry.id == TRACK_ENTRY {
+                        while let Some(child) = read_element(r, entry.end) {
+                            if child.id == VIDEO && info.width.is_none() {
+                                while let Some(video) = read_element(r, child.end) {
+                                    match video.id {
+                                        PIXEL_WIDTH => {
+                                            info.width = read_uint(r, &video).map(|v| v as usize)
+                                        }
+                                        PIXEL_HEIGHT => {
+                                            info.height = read_uint(r, &video).map(|v| v as usize)
+                                        }
+                                        _ => (),
+                                    }
+                                    r.seek(SeekFrom::Start(video.end?)).ok()?;
+                                }
+                            }
+                            r.seek(SeekFrom::Start(child.end?)).ok()?;
+                        }
+                    }
+                    r.seek(SeekFrom::Start(entry.end?)).ok()?;
+                }
+            }
+            // metadata is stored before media data
+            CLUSTER => break,
+            _ => (),
+        }
+
+        match element.end {
+            Some(end) => r.seek(SeekFrom::Start(end)).ok()?,
+            None => break,
+        };
+    }
+
+    // duration is in the unit of timestamp scale, which is nanoseconds
+    info.duration = duration.and_then(|d| seconds(d * scale as f64 / 1e9));
+    Some(info)
+}
@@ -0,0 +1,94 @@
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod gif;
+mod mkv;
+mod mp3;
+mod mp4;
+mod ogg;
+mod wav;
+
+pub mod thumbnail;
+
+#[derive(Debug, Default)]
+/// Metadata of a media file that Telegram uses to render previews.
+pub struct MediaInfo {
+    /// Duration in seconds.
+    pub duration: Option<usize>,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+}
+
+/// Reads the metadata of a media file from its headers.
+///
+/// Supports MP4/MOV, Matroska/WebM, GIF, WAV, OGG and MP3 files. Returns
+/// `None` if the file is unknown or its headers cannot be read.
+pub fn probe(path: &Path) -> Option<MediaInfo> {
+    debug!("Probing media file {}...", path.to_string_lossy());
+
+    let mut file = File::open(path).ok()?;
+    let mut magic = [0u8; 12];
+    file.read_exact(&mut magic).ok()?;
+    file.seek(SeekFrom::Start(0)).ok()?;
+
+    let info = if &magic[4..8] == b"ftyp" {
+        mp4::probe(&mut file)
+    } else if magic[..4] == [0x1a, 0x45, 0xdf, 0xa3] {
+        mkv::probe(&mut file)
+    } else if &magic[..6] == b"GIF87a" || &magic[..6] == b"GIF89a" {
+        gif::probe(&mut file)
+    } else if &magic[..4] == b"RIFF" && &magic[8..12] == b"WAVE" {
+        wav::probe(&mut file)
+    } else if &magic[..4] == b"OggS" {
+        ogg::probe(&mut file)
+    } else if &magic[..3] == b"ID3" || (magic[0] == 0xff && magic[1] & 0xe0 == 0xe0) {
+        mp3::probe(&mut file)
+    } else {
+        None
+    };
+
+    match &info {
+        Some(i) => trace!("media info: {:?}", i),
+        None => debug!("Could not probe the media file."),
+    }
+    info
+}
+
+/// Rounds seconds to the nearest integer as Telegram expects.
+fn seconds(value: f64) -> Option<usize> {
+    match value.is_finite() && value >= 0.0 {
+        true => Some(value.round() as usize),
+        false => None,
+    }
+}
+
+fn read_bytes<R: Read>(r: &mut R, length: usize) -> Option<Vec<u8>> {
+    let mut buffer = vec![0u8; length];
+    r.read_exact(&mut buffer).ok()?;
+    Some(buffer)
+}
+
+fn be_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, b| (acc << 8) | *b as u64)
+}
+
+fn le_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |acc, b| (acc << 8) | *b as u64)
+}
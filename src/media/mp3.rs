@@ -0,0 +1,143 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{be_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF http://www.mp3-tech.org/programmer/frame_header.html
+
+/// How many bytes after tags are searched for the first frame.
+const SEARCH_LENGTH: usize = 65536;
+
+const MPEG1_BITRATES: [u64; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+const MPEG2_BITRATES: [u64; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+const MPEG1_SAMPLE_RATES: [u64; 3] = [44100, 48000, 32000];
+
+struct FrameHeader {
+    mpeg1: bool,
+    mono: bool,
+    /// In kbps.
+    bitrate: u64,
+    sample_rate: u64,
+}
+
+impl FrameHeader {
+    /// Parses a layer III frame header.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes[0] != 0xff || bytes[1] & 0xe0 != 0xe0 {
+            return None;
+        }
+
+        let version = (bytes[1] >> 3) & 0b11;
+        let layer = (bytes[1] >> 1) & 0b11;
+        let bitrate_index = (bytes[2] >> 4) as usize;
+        let sample_rate_index = ((bytes[2] >> 2) & 0b11) as usize;
+
+        // reserved version, layers other than III and invalid indices
+        if version == 0b01 || layer != 0b01 || bitrate_index == 0 || bitrate_index == 15 {
+            return None;
+        }
+
+        let base_rate = *MPEG1_SAMPLE_RATES.get(sample_rate_index)?;
+        let (mpeg1, bitrate, sample_rate) = match version {
+            0b11 => (true, MPEG1_BITRATES[bitrate_index], base_rate),
+            0b10 => (false, MPEG2_BITRATES[bitrate_index], base_rate / 2),
+            // MPEG 2.5
+            _ => (false, MPEG2_BITRATES[bitrate_index], base_rate / 4),
+        };
+
+        Some(FrameHeader {
+            mpeg1,
+            mono: bytes[3] >> 6 == 0b11,
+            bitrate,
+            sample_rate,
+        })
+    }
+
+    fn samples_per_frame(&self) -> u64 {
+        match self.mpeg1 {
+            true => 1152,
+            false => 576,
+        }
+    }
+
+    /// Where Xing header starts after the frame header.
+    fn side_info_length(&self) -> usize {
+        match (self.mpeg1, self.mono) {
+            (true, false) => 32,
+            (true, true) | (false, false) => 17,
+            (false, true) => 9,
+        }
+    }
+}
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    let length = r.seek(SeekFrom::End(0)).ok()?;
+    r.seek(SeekFrom::Start(0)).ok()?;
+
+    // skip ID3v2 tag, its size is a syncsafe integer
+    let tag = read_bytes(r, 10)?;
+    let audio_start = match &tag[..3] {
+        b"ID3" => {
+            let size = tag[6..10]
+                .iter()
+                .fold(0u64, |acc, b| (acc << 7) | (*b & 0x7f) as u64);
+            let footer = match tag[5] & 0x10 {
+                0 => 0,
+                _ => 10,
+            };
+            10 + size + footer
+        }
+        _ => 0,
+    };
+
+    r.seek(SeekFrom::Start(audio_start)).ok()?;
+    let mut buffer = vec![];
+    r.take(SEARCH_LENGTH as u64).read_to_end(&mut buffer).ok()?;
+
+    let (offset, header) = buffer
+        .windows(4)
+        .enumerate()
+        .find_map(|(i, w)| FrameHeader::parse(w).map(|h| (i, h)))?;
+    let frame = &buffer[offset..];
+
+    // variable bitrate files declare the frame count in Xing or VBRI headers
+    let xing_offset = 4 + header.side_info_length();
+    let frames = match frame.get(xing_offset..xing_offset + 12) {
+        Some(x) if (&x[..4] == b"Xing" || &x[..4] == b"Info") && x[7] & 1 == 1 => {
+            Some(be_uint(&x[8..12]))
+        }
+        _ => match frame.get(36..54) {
+            Some(v) if &v[..4] == b"VBRI" => Some(be_uint(&v[14..18])),
+            _ => None,
+        },
+    };
+
+    let duration = match frames {
+        Some(f) => f as f64 * header.samples_per_frame() as f64 / header.sample_rate as f64,
+        // constant bitrate
+        None => {
+            let audio_length = length - audio_start - offset as u64;
+            audio_length as f64 * 8.0 / (header.bitrate as f64 * 1000.0)
+        }
+    };
+
+    Some(MediaInfo {
+        duration: seconds(duration),
+        ..MediaInfo::default()
+    })
+}
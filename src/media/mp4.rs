@@ -0,0 +1,135 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{be_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/QTFFChap2/qtff2.html
+
+struct BoxHeader {
+    kind: [u8; 4],
+    /// The offset where the content of box starts.
+    start: u64,
+    /// The offset where the box ends.
+    end: u64,
+}
+
+fn read_box_header<R: Read + Seek>(r: &mut R, limit: u64) -> Option<BoxHeader> {
+    let offset = r.stream_position().ok()?;
+    if offset.checked_add(8)? > limit {
+        return None;
+    }
+
+    let header = read_bytes(r, 8)?;
+    let kind = [header[4], header[5], header[6], header[7]];
+    let (size, header_length) = match be_uint(&header[..4]) {
+        // 64-bit size follows the type
+        1 => (be_uint(&read_bytes(r, 8)?), 16),
+        // box extends to the end
+        0 => (limit - offset, 8),
+        s => (s, 8),
+    };
+
+    if size < header_length {
+        return None;
+    }
+
+    // a malformed size must not overflow the offset
+    Some(BoxHeader {
+        kind,
+        start: offset + header_length,
+        end: offset.checked_add(size)?,
+    })
+}
+
+/// Finds the first child box of `kind` between the current offset and `limit`.
+fn find_box<R: Read + Seek>(r: &mut R, kind: &[u8; 4], limit: u64) -> Option<BoxHeader> {
+    loop {
+        let header = read_box_header(r, limit)?;
+        if &header.kind == kind {
+            return Some(header);
+        }
+        r.seek(SeekFrom::Start(header.end)).ok()?;
+    }
+}
+
+/// Reads duration in seconds from a `mvhd` box.
+fn movie_duration<R: Read + Seek>(r: &mut R, mvhd: &BoxHeader) -> Option<f64> {
+    r.seek(SeekFrom::Start(mvhd.start)).ok()?;
+    let version = read_bytes(r, 4)?[0];
+    let (timescale, duration) = match version {
+        1 => {
+            let b = read_bytes(r, 28)?;
+            (be_uint(&b[16..20]), be_uint(&b[20..28]))
+        }
+        _ => {
+            let b = read_bytes(r, 16)?;
+            (be_uint(&b[8..12]), be_uint(&b[12..16]))
+        }
+    };
+
+    match timescale {
+        0 => None,
+        t => Some(duration as f64 / t as f64),
+    }
+}
+
+/// Reads width and height from a `tkhd` box.
+fn track_dimensions<R: Read + Seek>(r: &mut R, tkhd: &BoxHeader) -> Option<(usize, usize)> {
+    r.seek(SeekFrom::Start(tkhd.start)).ok()?;
+    let version = read_bytes(r, 4)?[0];
+    // creation, modification, track id, reserved and duration
+    let skip = match version {
+        1 => 32,
+        _ => 20,
+    };
+    // reserved, layer, alternate group, volume, reserved and matrix
+    r.seek(SeekFrom::Current(skip + 52)).ok()?;
+    let b = read_bytes(r, 8)?;
+
+    // 16.16 fixed-point numbers
+    match (be_uint(&b[..4]) >> 16, be_uint(&b[4..]) >> 16) {
+        (0, _) | (_, 0) => None,
+        (w, h) => Some((w as usize, h as usize)),
+    }
+}
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    let length = r.seek(SeekFrom::End(0)).ok()?;
+    r.seek(SeekFrom::Start(0)).ok()?;
+
+    let moov = find_box(r, b"moov", length)?;
+    let mut info = MediaInfo::default();
+
+    r.seek(SeekFrom::Start(moov.start)).ok()?;
+    while let Some(child) = read_box_header(r, moov.end) {
+        match &child.kind {
+            b"mvhd" => info.duration = movie_duration(r, &child).and_then(seconds),
+            b"trak" if info.width.is_none() => {
+                r.seek(SeekFrom::Start(child.start)).ok()?;
+                if let Some(tkhd) = find_box(r, b"tkhd", child.end) {
+                    if let Some((w, h)) = track_dimensions(r, &tkhd) {
+                        info.width = Some(w);
+                        info.height = Some(h);
+                    }
+                }
+            }
+            _ => (),
+        }
+        r.seek(SeekFrom::Start(child.end)).ok()?;
+    }
+
+    Some(info)
+}
@@ -0,0 +1,56 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{le_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF https://xiph.org/ogg/doc/framing.html
+
+/// How many bytes from the end are searched for the last page.
+const TAIL_LENGTH: u64 = 65536;
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    let header = read_bytes(r, 27)?;
+    let segments = read_bytes(r, header[26] as usize)?;
+    let packet = read_bytes(r, segments.iter().map(|s| *s as usize).sum())?;
+
+    // granule positions are in samples
+    let (rate, pre_skip) = if packet.starts_with(b"\x01vorbis") && packet.len() >= 16 {
+        (le_uint(&packet[12..16]), 0)
+    } else if packet.starts_with(b"OpusHead") && packet.len() >= 12 {
+        // opus always uses 48kHz granule positions
+        (48000, le_uint(&packet[10..12]))
+    } else {
+        return None;
+    };
+
+    let length = r.seek(SeekFrom::End(0)).ok()?;
+    let tail_start = length.saturating_sub(TAIL_LENGTH);
+    r.seek(SeekFrom::Start(tail_start)).ok()?;
+    let tail = read_bytes(r, (length - tail_start) as usize)?;
+
+    let last_page = tail.windows(4).rposition(|w| w == b"OggS")?;
+    let granule = tail.get(last_page + 6..last_page + 14).map(le_uint)?;
+
+    let duration = match rate {
+        0 => None,
+        r => seconds(granule.saturating_sub(pre_skip) as f64 / r as f64),
+    };
+
+    Some(MediaInfo {
+        duration,
+        ..MediaInfo::default()
+    })
+}
@@ -0,0 +1,99 @@
+use std::{fs, io::Cursor, path::Path};
+
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, ImageFormat, Rgb, RgbImage};
+
+use crate::operations::{CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// REF https://core.telegram.org/bots/api#sendvideo
+
+/// The maximum width or height of a thumbnail.
+const MAX_SIDE: u32 = 320;
+/// The maximum size of a thumbnail in bytes.
+const MAX_SIZE: usize = 200 * 1024;
+
+/// Checks if Telegram accepts the image as a thumbnail. Telegram
+/// ignores thumbnails that are not JPEG, larger than 320x320 or
+/// 200 kilobytes.
+pub fn is_acceptable(path: &Path) -> bool {
+    let size = match fs::metadata(path) {
+        Ok(m) => m.len() as usize,
+        Err(_) => return false,
+    };
+    let is_jpeg = matches!(ImageFormat::from_path(path), Ok(ImageFormat::Jpeg));
+    let fits = match image::image_dimensions(path) {
+        Ok((w, h)) => w <= MAX_SIDE && h <= MAX_SIDE,
+        Err(_) => false,
+    };
+
+    is_jpeg && fits && size <= MAX_SIZE
+}
+
+/// Downscales and encodes an image as a JPEG that Telegram accepts
+/// as a thumbnail.
+pub fn fit(path: &Path) -> Result<Vec<u8>, OperationError> {
+    debug!("Fitting {} as a thumbnail...", path.to_string_lossy());
+
+    let image = match image::open(path) {
+        Ok(i) => i,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::ImageDecodeError as i32,
+                format!(
+                    "Could not read the thumbnail image.\nPath: {}",
+                    path.to_string_lossy()
+                ),
+                Some(e),
+            ))
+        }
+    };
+
+    let image = match image.width() > MAX_SIDE || image.height() > MAX_SIDE {
+        true => image.resize(MAX_SIDE, MAX_SIDE, FilterType::Lanczos3),
+        false => image,
+    };
+
+    // jpeg has no transparency, blend it onto white
+    let rgba = image.to_rgba8();
+    let rgb = RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let blend = |c: u8| ((c as u16 * a as u16 + 255 * (255 - a as u16)) / 255) as u8;
+        Rgb([blend(r), blend(g), blend(b)])
+    });
+
+    for quality in (30..=90).rev().step_by(10) {
+        let mut buffer = Cursor::new(vec![]);
+        if let Err(e) = JpegEncoder::new_with_quality(&mut buffer, quality).encode_image(&rgb) {
+            return Err(OperationError::new(
+                CommonExitCodes::ImageEncodeError as i32,
+                "Could not encode the thumbnail.",
+                Some(e),
+            ));
+        }
+
+        let bytes = buffer.into_inner();
+        trace!("thumbnail size on quality {}: {}", quality, bytes.len());
+        if bytes.len() <= MAX_SIZE {
+            return Ok(bytes);
+        }
+    }
+
+    Err(OperationError::new(
+        CommonExitCodes::ImageEncodeError as i32,
+        "Could not fit the thumbnail into 200 kilobytes.",
+        None::<&str>,
+    ))
+}
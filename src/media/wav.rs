@@ -0,0 +1,54 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use super::{le_uint, read_bytes, seconds, MediaInfo};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub(super) fn probe<R: Read + Seek>(r: &mut R) -> Option<MediaInfo> {
+    // skip RIFF header
+    r.seek(SeekFrom::Start(12)).ok()?;
+
+    let mut byte_rate = None;
+    loop {
+        let header = read_bytes(r, 8)?;
+        let size = le_uint(&header[4..]);
+
+        match &header[..4] {
+            b"fmt " => {
+                let format = read_bytes(r, 12)?;
+                byte_rate = Some(le_uint(&format[8..12]));
+                r.seek(SeekFrom::Current(size as i64 - 12)).ok()?;
+            }
+            b"data" => {
+                let duration = match byte_rate? {
+                    0 => None,
+                    b => seconds(size as f64 / b as f64),
+                };
+                return Some(MediaInfo {
+                    duration,
+                    ..MediaInfo::default()
+                });
+            }
+            _ => {
+                r.seek(SeekFrom::Current(size as i64)).ok()?;
+            }
+        }
+
+        // chunks are aligned to two bytes
+        if size % 2 == 1 {
+            r.seek(SeekFrom::Current(1)).ok()?;
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use clap::ArgMatches;
+use serde_json::{json, Value};
+
+use crate::{
+    http::transfer_client,
+    operations::{
+        bot::{me::get_me, send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Runs the operation of parsed arguments, as the command line does.
+pub type Dispatch = fn(&ArgMatches<'static>) -> Result<(), OperationError>;
+
+/// An operation in a manifest.
+#[derive(Debug)]
+pub struct BatchLine {
+    /// The line of the operation in a JSON Lines manifest or its position in a YAML one.
+    pub number: usize,
+    /// The name of the operation, e.g. `send.photo`.
+    pub op: String,
+    /// The arguments of the operation, or why they are not valid.
+    pub matches: Result<ArgMatches<'static>, OperationError>,
+}
+
+impl BatchLine {
+    pub fn new(
+        number: usize,
+        op: &str,
+        matches: Result<ArgMatches<'static>, OperationError>,
+    ) -> Self {
+        Self {
+            number,
+            op: String::from(op),
+            matches,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchParams {
+    pub lines: Vec<BatchLine>,
+    /// How many operations run at the same time.
+    pub jobs: usize,
+    pub continue_on_error: bool,
+    pub dispatch: Dispatch,
+}
+
+impl BatchParams {
+    pub fn new(
+        lines: Vec<BatchLine>,
+        jobs: usize,
+        continue_on_error: bool,
+        dispatch: Dispatch,
+    ) -> Self {
+        Self {
+            lines,
+            jobs,
+            continue_on_error,
+            dispatch,
+        }
+    }
+}
+
+pub type BatchOperationParams = (RootParams, BotParams, BatchParams);
+
+#[derive(Debug)]
+pub struct BatchOperation {
+    params: BatchOperationParams,
+}
+
+impl BatchOperation {
+    pub fn new(params: BatchOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+/// The failures of a batch so far.
+#[derive(Default)]
+struct Failures {
+    count: usize,
+    last: Option<OperationError>,
+}
+
+#[async_trait]
+impl SendOperation for BatchOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, batch) = self.params;
+        let total = batch.lines.len();
+        info!("📋 Running {} operation(s)...", total);
+
+        // an invalid token would fail every line, so it fails once instead
+        if root_params.dry_run.is_none() {
+            let client = transfer_client()?;
+            get_me::<Value>(&client, &bot_params)?;
+        }
+
+        let queue = Mutex::new(batch.lines.into_iter().collect::<VecDeque<BatchLine>>());
+        let stopped = AtomicBool::new(false);
+        let failures = Mutex::new(Failures::default());
+
+        let run = || loop {
+            if stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            let line = match queue.lock().unwrap().pop_front() {
+                Some(l) => l,
+                None => break,
+            };
+
+            let result = match line.matches {
+                Ok(m) => (batch.dispatch)(&m),
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(_) => println!(
+                    "{}",
+                    json!({ "line": line.number, "op": line.op, "ok": true })
+                ),
+                Err(e) => {
+                    error!("☠️ Line {} ({}): {}", line.number, line.op, e);
+                    println!(
+                        "{}",
+                        json!({
+                            "line": line.number,
+                            "op": line.op,
+                            "ok": false,
+                            "exit_code": e.exit_code(),
+                            "error": e.to_string(),
+                        })
+                    );
+
+                    if !batch.continue_on_error {
+                        stopped.store(true, Ordering::SeqCst);
+                    }
+                    let mut failures = failures.lock().unwrap();
+                    failures.count += 1;
+                    failures.last = Some(e);
+                }
+            }
+        };
+
+        thread::scope(|s| {
+            for _ in 0..batch.jobs.max(1) {
+                s.spawn(run);
+            }
+        });
+
+        let failures = failures.into_inner().unwrap();
+        let skipped = queue.into_inner().unwrap().len();
+        if skipped > 0 {
+            warn!("Skipped {} operation(s) after the failure.", skipped);
+        }
+
+        match failures.last {
+            Some(e) => {
+                error!(
+                    "☠️ Failed for {} of {} operation(s).",
+                    failures.count, total
+                );
+                Err(e)
+            }
+            None => {
+                info!("📦 Successfully ran {} operation(s).", total);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::convert::TryInto;
+
+use serde_json::Value;
+
+use crate::{
+    handle_response,
+    http::{request::models::chat::ChatRequestModel, transfer_client},
+    operations::{bot::send::SendOperation, OperationError},
+};
+
+use super::ChatOperationParams;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct GetAdministratorsOperation {
+    params: ChatOperationParams,
+}
+
+impl GetAdministratorsOperation {
+    pub fn new(params: ChatOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for GetAdministratorsOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("👮 Getting administrators...");
+
+        let url = self.params.1.method_url("getChatAdministrators");
+        trace!("url: {}", url);
+
+        let req_instance: ChatRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        let administrators = handle_response!(response => Vec<Value>, on_success => {
+            debug!("Received the administrators.");
+        }, on_failure => {
+            error!("☠️ An error occurred while getting the administrators.");
+        })?;
+
+        // a chat member per line so that each can be processed separately
+        for administrator in administrators {
+            println!("{}", administrator);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use std::convert::TryInto;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::{
+            chat::ChatRequestModel, createchatinvitelink::CreateChatInviteLinkRequestModel,
+        },
+        response::models::invitelink::ChatInviteLinkModel,
+        transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{ChatOperationParams, ChatParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct ExportInviteLinkOperation {
+    params: ChatOperationParams,
+}
+
+impl ExportInviteLinkOperation {
+    pub fn new(params: ChatOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for ExportInviteLinkOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🔗 Exporting invite link...");
+
+        let url = self.params.1.method_url("exportChatInviteLink");
+        trace!("url: {}", url);
+
+        let req_instance: ChatRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        let link = handle_response!(response => String, on_success => {
+            info!("📦 Successfully exported invite link.");
+        }, on_failure => {
+            error!("☠️ An error occurred while exporting the invite link.");
+        })?;
+
+        println!("{}", link);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct InviteLinkParams {
+    /// The name of the link, visible to administrators only.
+    pub name: Option<String>,
+    pub expire_date: Option<DateTime<Utc>>,
+    /// How many users can join with the link, between 1 and 99999.
+    pub member_limit: Option<usize>,
+    /// Users joining with the link need to be approved by administrators.
+    pub creates_join_request: bool,
+}
+
+impl InviteLinkParams {
+    pub fn new(
+        name: Option<String>,
+        expire_date: Option<DateTime<Utc>>,
+        member_limit: Option<usize>,
+        creates_join_request: bool,
+    ) -> Self {
+        Self {
+            name,
+            expire_date,
+            member_limit,
+            creates_join_request,
+        }
+    }
+}
+
+pub type CreateInviteLinkParams = (RootParams, BotParams, ChatParams, InviteLinkParams);
+
+#[derive(Debug)]
+pub struct CreateInviteLinkOperation {
+    params: CreateInviteLinkParams,
+}
+
+impl CreateInviteLinkOperation {
+    pub fn new(params: CreateInviteLinkParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for CreateInviteLinkOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🔗 Creating invite link...");
+
+        let url = self.params.1.method_url("createChatInviteLink");
+        trace!("url: {}", url);
+
+        let req_instance: CreateChatInviteLinkRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        let link = handle_response!(response => ChatInviteLinkModel, on_success => {
+            info!("📦 Successfully created invite link.");
+        }, on_failure => {
+            error!("☠️ An error occurred while creating the invite link.");
+        })?;
+
+        println!("{}", link.invite_link);
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::operations::{bot::BotParams, RootParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod administrators;
+pub mod description;
+pub mod invite;
+pub mod leave;
+pub mod membercount;
+pub mod photo;
+pub mod pin;
+pub mod title;
+
+#[derive(Debug)]
+pub struct ChatParams {
+    /// The id of the chat or the username of the channel, e.g. `@channel`.
+    pub chat: String,
+}
+
+impl ChatParams {
+    pub fn new(chat: &str) -> Self {
+        Self {
+            chat: String::from(chat),
+        }
+    }
+}
+
+/// Parameters of the operations which only need the chat.
+pub type ChatOperationParams = (RootParams, BotParams, ChatParams);
@@ -0,0 +1,112 @@
+use std::{convert::TryInto, path::PathBuf};
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::{chat::ChatRequestModel, setchatphoto::SetChatPhotoRequestModel},
+        transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{ChatOperationParams, ChatParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct PhotoParams {
+    pub file: PathBuf,
+}
+
+impl PhotoParams {
+    pub fn new(file: PathBuf) -> Self {
+        Self { file }
+    }
+}
+
+pub type SetChatPhotoParams = (RootParams, BotParams, ChatParams, PhotoParams);
+
+#[derive(Debug)]
+pub struct SetChatPhotoOperation {
+    params: SetChatPhotoParams,
+}
+
+impl SetChatPhotoOperation {
+    pub fn new(params: SetChatPhotoParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for SetChatPhotoOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🖼️ Setting chat photo...");
+
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        let url = self.params.1.method_url("setChatPhoto");
+        trace!("url: {}", url);
+
+        let req_instance: SetChatPhotoRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully set chat photo.");
+        }, on_failure => {
+            error!("☠️ An error occurred while setting the chat photo.");
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteChatPhotoOperation {
+    params: ChatOperationParams,
+}
+
+impl DeleteChatPhotoOperation {
+    pub fn new(params: ChatOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for DeleteChatPhotoOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🗑️ Deleting chat photo...");
+
+        let url = self.params.1.method_url("deleteChatPhoto");
+        trace!("url: {}", url);
+
+        let req_instance: ChatRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully deleted chat photo.");
+        }, on_failure => {
+            error!("☠️ An error occurred while deleting the chat photo.");
+        })
+    }
+}
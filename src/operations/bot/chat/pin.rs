@@ -0,0 +1,163 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::{
+            chat::ChatRequestModel, pinchatmessage::PinChatMessageRequestModel,
+            unpinchatmessage::UnpinChatMessageRequestModel,
+        },
+        transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{ChatOperationParams, ChatParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct PinParams {
+    pub message_id: usize,
+    /// Pins without notifying the members of the chat.
+    pub silent: bool,
+}
+
+impl PinParams {
+    pub fn new(message_id: usize, silent: bool) -> Self {
+        Self { message_id, silent }
+    }
+}
+
+pub type PinMessageParams = (RootParams, BotParams, ChatParams, PinParams);
+
+#[derive(Debug)]
+pub struct PinMessageOperation {
+    params: PinMessageParams,
+}
+
+impl PinMessageOperation {
+    pub fn new(params: PinMessageParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for PinMessageOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📌 Pinning message...");
+
+        let url = self.params.1.method_url("pinChatMessage");
+        trace!("url: {}", url);
+
+        let req_instance: PinChatMessageRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully pinned message.");
+        }, on_failure => {
+            error!("☠️ An error occurred while pinning the message.");
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct UnpinParams {
+    /// Unpins the most recent pinned message if absent.
+    pub message_id: Option<usize>,
+}
+
+impl UnpinParams {
+    pub fn new(message_id: Option<usize>) -> Self {
+        Self { message_id }
+    }
+}
+
+pub type UnpinMessageParams = (RootParams, BotParams, ChatParams, UnpinParams);
+
+#[derive(Debug)]
+pub struct UnpinMessageOperation {
+    params: UnpinMessageParams,
+}
+
+impl UnpinMessageOperation {
+    pub fn new(params: UnpinMessageParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for UnpinMessageOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📌 Unpinning message...");
+
+        let url = self.params.1.method_url("unpinChatMessage");
+        trace!("url: {}", url);
+
+        let req_instance: UnpinChatMessageRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully unpinned message.");
+        }, on_failure => {
+            error!("☠️ An error occurred while unpinning the message.");
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct UnpinAllMessagesOperation {
+    params: ChatOperationParams,
+}
+
+impl UnpinAllMessagesOperation {
+    pub fn new(params: ChatOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for UnpinAllMessagesOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📌 Unpinning all messages...");
+
+        let url = self.params.1.method_url("unpinAllChatMessages");
+        trace!("url: {}", url);
+
+        let req_instance: ChatRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully unpinned all messages.");
+        }, on_failure => {
+            error!("☠️ An error occurred while unpinning all messages.");
+        })
+    }
+}
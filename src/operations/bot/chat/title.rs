@@ -0,0 +1,75 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::{request::models::setchattitle::SetChatTitleRequestModel, transfer_client},
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::ChatParams;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct TitleParams {
+    pub title: String,
+}
+
+impl TitleParams {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: String::from(title),
+        }
+    }
+}
+
+pub type SetChatTitleParams = (RootParams, BotParams, ChatParams, TitleParams);
+
+#[derive(Debug)]
+pub struct SetChatTitleOperation {
+    params: SetChatTitleParams,
+}
+
+impl SetChatTitleOperation {
+    pub fn new(params: SetChatTitleParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for SetChatTitleOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🏷️ Setting chat title...");
+
+        let url = self.params.1.method_url("setChatTitle");
+        trace!("url: {}", url);
+
+        let req_instance: SetChatTitleRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully set chat title.");
+        }, on_failure => {
+            error!("☠️ An error occurred while setting the chat title.");
+        })
+    }
+}
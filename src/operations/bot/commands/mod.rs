@@ -0,0 +1,78 @@
+use serde_json::{json, Value};
+
+use crate::operations::{bot::BotParams, RootParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod delete;
+pub mod get;
+pub mod set;
+
+/// Which users see the commands.
+#[derive(Debug, Clone)]
+pub enum CommandScope {
+    Default,
+    AllPrivateChats,
+    AllGroupChats,
+    AllChatAdministrators,
+    Chat(String),
+    ChatAdministrators(String),
+    ChatMember(String, u64),
+}
+
+impl CommandScope {
+    /// The `BotCommandScope` object of Bot API.
+    pub fn to_json(&self) -> Value {
+        match self {
+            CommandScope::Default => json!({"type": "default"}),
+            CommandScope::AllPrivateChats => json!({"type": "all_private_chats"}),
+            CommandScope::AllGroupChats => json!({"type": "all_group_chats"}),
+            CommandScope::AllChatAdministrators => json!({"type": "all_chat_administrators"}),
+            CommandScope::Chat(c) => json!({"type": "chat", "chat_id": chat_id(c)}),
+            CommandScope::ChatAdministrators(c) => {
+                json!({"type": "chat_administrators", "chat_id": chat_id(c)})
+            }
+            CommandScope::ChatMember(c, u) => {
+                json!({"type": "chat_member", "chat_id": chat_id(c), "user_id": u})
+            }
+        }
+    }
+}
+
+fn chat_id(chat: &str) -> Value {
+    match chat.parse::<i64>() {
+        Ok(v) => json!(v),
+        Err(_) => json!(chat),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandsParams {
+    pub scope: CommandScope,
+    /// The commands apply to all users of the scope without a dedicated list
+    /// for their language if absent.
+    pub language_code: Option<String>,
+}
+
+impl CommandsParams {
+    pub fn new(scope: CommandScope, language_code: Option<String>) -> Self {
+        Self {
+            scope,
+            language_code,
+        }
+    }
+}
+
+pub type CommandsOperationParams = (RootParams, BotParams, CommandsParams);
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashSet,
+    convert::TryInto,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::setmycommands::SetMyCommandsRequestModel,
+        response::models::botcommand::BotCommandModel, transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        CommonExitCodes, OperationError, RootParams,
+    },
+};
+
+use super::CommandsParams;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+const MAX_COMMANDS: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct CommandsFile {
+    commands: Vec<BotCommandModel>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JsonCommandsFile {
+    List(Vec<BotCommandModel>),
+    Table(CommandsFile),
+}
+
+fn invalid_commands_error(message: impl std::fmt::Display) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::StdInvalidValue as i32,
+        "The commands file is not valid.",
+        Some(message.to_string()),
+    )
+}
+
+/// Parses a list of commands. TOML files have a `[[commands]]` table per
+/// command while JSON files are either a list or an object with `commands`.
+pub fn parse_commands(content: &str, toml: bool) -> Result<Vec<BotCommandModel>, OperationError> {
+    let commands = if toml {
+        match toml::from_str::<CommandsFile>(content) {
+            Ok(f) => f.commands,
+            Err(e) => return Err(invalid_commands_error(e)),
+        }
+    } else {
+        match serde_json::from_str::<JsonCommandsFile>(content) {
+            Ok(JsonCommandsFile::List(c)) => c,
+            Ok(JsonCommandsFile::Table(f)) => f.commands,
+            Err(e) => return Err(invalid_commands_error(e)),
+        }
+    };
+
+    if commands.len() > MAX_COMMANDS {
+        return Err(invalid_commands_error(format!(
+            "A bot can have at most {} commands.",
+            MAX_COMMANDS
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    for c in &commands {
+        let valid_name = (1..=32).contains(&c.command.len())
+            && c.command
+                .chars()
+                .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_');
+        if !valid_name {
+            return Err(invalid_commands_error(format!(
+                "`{}` must be 1 to 32 lowercase letters, digits or underscores.",
+                c.command
+            )));
+        }
+        if !(1..=256).contains(&c.description.chars().count()) {
+            return Err(invalid_commands_error(format!(
+                "The description of `{}` must be between 1 and 256 characters.",
+                c.command
+            )));
+        }
+        if !seen.insert(&c.command) {
+            return Err(invalid_commands_error(format!(
+                "`{}` is defined more than once.",
+                c.command
+            )));
+        }
+    }
+
+    Ok(commands)
+}
+
+/// Reads the commands from a TOML or JSON file, decided by its extension.
+pub fn read_commands(path: &Path) -> Result<Vec<BotCommandModel>, OperationError> {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!("Could not read {}.", path.display()),
+                Some(e),
+            ))
+        }
+    };
+
+    let toml = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("toml"));
+    parse_commands(&content, toml)
+}
+
+#[derive(Debug)]
+pub struct SetCommandsParams {
+    pub file: PathBuf,
+}
+
+impl SetCommandsParams {
+    pub fn new(file: PathBuf) -> Self {
+        Self { file }
+    }
+}
+
+pub type CommandsSetParams = (RootParams, BotParams, CommandsParams, SetCommandsParams);
+
+#[derive(Debug)]
+pub struct SetCommandsOperation {
+    params: CommandsSetParams,
+}
+
+impl SetCommandsOperation {
+    pub fn new(params: CommandsSetParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for SetCommandsOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📜 Setting commands...");
+
+        let (_, bot_params, commands_params, set_params) = self.params;
+        let commands = read_commands(&set_params.file)?;
+        debug!("{} command(s) were read.", commands.len());
+
+        let url = bot_params.method_url("setMyCommands");
+        trace!("url: {}", url);
+
+        let req_instance: SetMyCommandsRequestModel = (commands_params, commands).into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully set commands.");
+        }, on_failure => {
+            error!("☠️ An error occurred while setting the commands.");
+        })
+    }
+}
@@ -0,0 +1,369 @@
+use std::{
+    collections::HashSet,
+    convert::TryInto,
+    fs::{self, File},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use reqwest::blocking::Client;
+use serde_json::Value;
+
+use crate::{
+    handle_response,
+    http::{
+        progress::{Progress, ProgressReader},
+        request::models::getfile::GetFileRequestModel,
+        response::models::file::FileModel,
+        transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        CommonExitCodes, OperationError, ProgressMode, RootParams,
+    },
+};
+
+use super::FileParams;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub enum FileSource {
+    /// A single file with the given id.
+    Id(String),
+    /// Every attachment in an update or message JSON read from stdin.
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct GetFileParams {
+    pub source: FileSource,
+    /// The path to save the file into. A directory if the source is stdin.
+    pub output: Option<PathBuf>,
+}
+
+impl GetFileParams {
+    pub fn new(source: FileSource, output: Option<PathBuf>) -> Self {
+        Self { source, output }
+    }
+}
+
+/// A file found in an update or message.
+#[derive(Debug, PartialEq)]
+pub struct Attachment {
+    pub file_id: String,
+    pub file_unique_id: Option<String>,
+    pub file_name: Option<String>,
+}
+
+/// Collects the attachments of an update, a message or a list of them.
+/// Only the largest size of a photo is collected and thumbnails are skipped.
+pub fn attachments(value: &Value) -> Vec<Attachment> {
+    let mut found = vec![];
+    collect_attachments(value, &mut found);
+
+    let mut seen = HashSet::new();
+    found.retain(|a: &Attachment| {
+        seen.insert(
+            a.file_unique_id
+                .clone()
+                .unwrap_or_else(|| a.file_id.clone()),
+        )
+    });
+    found
+}
+
+fn collect_attachments(value: &Value, found: &mut Vec<Attachment>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(file_id)) = map.get("file_id") {
+                found.push(Attachment {
+                    file_id: file_id.clone(),
+                    file_unique_id: map
+                        .get("file_unique_id")
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                    file_name: map
+                        .get("file_name")
+                        .and_then(Value::as_str)
+                        .map(String::from),
+                });
+                return;
+            }
+
+            for (key, v) in map {
+                match (key.as_str(), v) {
+                    ("thumb" | "thumbnail", _) => continue,
+                    // photo sizes are ordered from the smallest to the largest
+                    ("photo" | "new_chat_photo", Value::Array(sizes)) => {
+                        if let Some(largest) = sizes.last() {
+                            collect_attachments(largest, found);
+                        }
+                    }
+                    _ => collect_attachments(v, found),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|i| collect_attachments(i, found)),
+        _ => (),
+    }
+}
+
+pub type FileGetParams = (RootParams, BotParams, GetFileParams);
+
+#[derive(Debug)]
+pub struct GetFileOperation {
+    params: FileGetParams,
+}
+
+impl GetFileOperation {
+    pub fn new(params: FileGetParams) -> Self {
+        Self { params }
+    }
+}
+
+fn get_file(
+    client: &Client,
+    bot_params: &BotParams,
+    file_id: &str,
+) -> Result<FileModel, OperationError> {
+    let url = bot_params.method_url("getFile");
+    trace!("url: {}", url);
+
+    let req_instance: GetFileRequestModel = FileParams::new(file_id).into();
+    let req_body = req_instance.try_into()?;
+    trace!("request body: {:?}", req_body);
+
+    let response = client.post(url).multipart(req_body).send();
+    handle_response!(response => FileModel, on_success => {
+        debug!("Received the file info of {}.", file_id);
+    }, on_failure => {
+        error!("☠️ An error occurred while getting the file info.");
+    })
+}
+
+fn download(
+    client: &Client,
+    bot_params: &BotParams,
+    progress: ProgressMode,
+    file: &FileModel,
+    destination: &Path,
+) -> Result<(), OperationError> {
+    let file_path = match &file.file_path {
+        Some(p) => p,
+        None => {
+            return Err(OperationError::new(
+                CommonExitCodes::TelegramAPIMissingResult as i32,
+                "Telegram did not provide a path to download the file.",
+                None::<&str>,
+            ))
+        }
+    };
+
+    // a local Bot API server gives the absolute path of the file on its machine
+    if Path::new(file_path).is_absolute() {
+        return copy_local(file_path, destination);
+    }
+
+    let url = bot_params.file_url(file_path);
+    trace!("url: {}", url);
+
+    let response = match client.get(url).send() {
+        Ok(r) => r,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::ReqwestConnectionError as i32,
+                "An error occured while connecting to Telegram API.",
+                Some(e),
+            ))
+        }
+    };
+    // getFile has accepted the token already, so a failure is about the file rather than the bot
+    if !response.status().is_success() {
+        error!("☠️ An error occurred while downloading the file.");
+        return Err(OperationError::new(
+            CommonExitCodes::ReqwestHttpError as i32,
+            format!(
+                "Could not download the file ({}). Its path may have expired, which is renewed by getting the file again.",
+                response.status()
+            ),
+            None::<&str>,
+        ));
+    }
+
+    let mut output = match File::create(destination) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsWriteError as i32,
+                format!("Could not create {}.", destination.display()),
+                Some(e),
+            ))
+        }
+    };
+
+    let length = response.content_length().or(file.file_size);
+    let mut reader = ProgressReader::new(
+        response,
+        Progress::new(progress, length, destination.display().to_string()),
+    );
+    match io::copy(&mut reader, &mut output) {
+        Ok(_) => reader.progress().finish(),
+        Err(e) => {
+            reader.progress().abandon();
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsWriteError as i32,
+                format!("An error occurred while saving {}.", destination.display()),
+                Some(e),
+            ));
+        }
+    }
+
+    info!("📦 Saved {}.", destination.display());
+    Ok(())
+}
+
+/// Copies a file that a local Bot API server keeps on this machine.
+fn copy_local(file_path: &str, destination: &Path) -> Result<(), OperationError> {
+    debug!("Copying {} from the local Bot API server...", file_path);
+    if let Err(e) = fs::copy(file_path, destination) {
+        return Err(OperationError::new(
+            CommonExitCodes::StdFsReadError as i32,
+            format!(
+                "Could not copy {} given by the local Bot API server, which must run on this machine.",
+                file_path
+            ),
+            Some(e),
+        ));
+    }
+
+    info!("📦 Saved {}.", destination.display());
+    Ok(())
+}
+
+/// `name` in `directory`, suffixed like `file (1).jpg` if it is in `taken`.
+fn unique_destination(directory: &Path, name: &Path, taken: &mut HashSet<PathBuf>) -> PathBuf {
+    let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = name
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut destination = directory.join(name);
+    let mut n = 1;
+    while !taken.insert(destination.clone()) {
+        destination = directory.join(format!("{} ({}){}", stem, n, extension));
+        n += 1;
+    }
+    destination
+}
+
+/// The name of the file on Telegram servers, e.g. `file_12.jpg`.
+fn remote_name(file: &FileModel) -> PathBuf {
+    file.file_path
+        .as_deref()
+        .and_then(|p| Path::new(p).file_name())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(&file.file_unique_id))
+}
+
+#[async_trait]
+impl SendOperation for GetFileOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, get_params) = self.params;
+
+        let client = transfer_client()?;
+
+        match get_params.source {
+            FileSource::Id(file_id) => {
+                info!("📥 Downloading file...");
+                let file = get_file(&client, &bot_params, &file_id)?;
+
+                let destination = match get_params.output {
+                    Some(o) if o.is_dir() => o.join(remote_name(&file)),
+                    Some(o) => o,
+                    None => remote_name(&file),
+                };
+                download(
+                    &client,
+                    &bot_params,
+                    root_params.progress,
+                    &file,
+                    &destination,
+                )
+            }
+            FileSource::Stdin => {
+                let mut input = String::new();
+                if let Err(e) = io::stdin().read_to_string(&mut input) {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdStdinReadError as i32,
+                        "Could not read from standard input.",
+                        Some(e),
+                    ));
+                }
+
+                let value: Value = match serde_json::from_str(&input) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Err(OperationError::new(
+                            CommonExitCodes::SerdeDeserializationError as i32,
+                            "Standard input is not a valid update or message JSON.",
+                            Some(e),
+                        ))
+                    }
+                };
+
+                let found = attachments(&value);
+                if found.is_empty() {
+                    warn!("No attachments were found in the input.");
+                    return Ok(());
+                }
+                info!("📥 Downloading {} file(s)...", found.len());
+
+                let directory = get_params.output.unwrap_or_else(|| PathBuf::from("."));
+                if let Err(e) = fs::create_dir_all(&directory) {
+                    return Err(OperationError::new(
+                        CommonExitCodes::StdFsWriteError as i32,
+                        format!("Could not create {}.", directory.display()),
+                        Some(e),
+                    ));
+                }
+
+                // attachments with the same name are saved side by side
+                let mut taken = HashSet::new();
+                for attachment in found {
+                    let file = get_file(&client, &bot_params, &attachment.file_id)?;
+
+                    // only the name is used so that a malicious name cannot escape the directory
+                    let name = attachment
+                        .file_name
+                        .as_deref()
+                        .and_then(|n| Path::new(n).file_name())
+                        .map(PathBuf::from)
+                        .unwrap_or_else(|| remote_name(&file));
+                    download(
+                        &client,
+                        &bot_params,
+                        root_params.progress,
+                        &file,
+                        &unique_destination(&directory, &name, &mut taken),
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
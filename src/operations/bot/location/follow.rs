@@ -0,0 +1,221 @@
+use std::{convert::TryInto, path::PathBuf, thread};
+
+use chrono::Duration;
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::{
+            editmessagelivelocation::EditMessageLiveLocationRequestModel,
+            sendlocation::SendLocationRequestModel,
+            stopmessagelivelocation::StopMessageLiveLocationRequestModel,
+        },
+        response::models::message::MessageModel,
+        transfer_client,
+    },
+    operations::{
+        bot::{
+            send::{location::LocationParams, MessageFormat, SendOperation, SendParams},
+            BotParams,
+        },
+        OperationError, RootParams,
+    },
+};
+
+use super::{
+    track::{Track, TrackFormat},
+    LiveLocationParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Points closer in time to the last update are coalesced into the next one, so
+/// that a dense track does not run into the rate limits of Telegram.
+const MIN_UPDATE_INTERVAL: i64 = 3;
+
+#[derive(Debug)]
+pub struct FollowParams {
+    pub receiver: String,
+    pub file: PathBuf,
+    pub format: TrackFormat,
+    /// Period in seconds for which the location will be updated.
+    /// Defaults to the duration of track if absent.
+    pub live_period: Option<usize>,
+    pub silent: bool,
+}
+
+impl FollowParams {
+    pub fn new(
+        receiver: &str,
+        file: PathBuf,
+        format: TrackFormat,
+        live_period: Option<usize>,
+        silent: bool,
+    ) -> Self {
+        Self {
+            receiver: String::from(receiver),
+            file,
+            format,
+            live_period,
+            silent,
+        }
+    }
+}
+
+pub type FollowLocationParams = (RootParams, BotParams, FollowParams);
+
+#[derive(Debug)]
+pub struct FollowLocationOperation {
+    params: FollowLocationParams,
+}
+
+impl FollowLocationOperation {
+    pub fn new(params: FollowLocationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for FollowLocationOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, follow_params) = self.params;
+
+        let track = Track::from_path(&follow_params.file, &follow_params.format)?;
+
+        // live period must cover the whole track, telegram accepts 60 to 86400 seconds
+        let live_period = follow_params
+            .live_period
+            .unwrap_or_else(|| track.duration().clamp(60, 86400) as usize);
+        if (live_period as i64) < track.duration() {
+            warn!("Live period is shorter than the track. The location will expire before the track ends.");
+        }
+
+        let client = transfer_client()?;
+        let (first, rest) = match track.points.split_first() {
+            Some(s) => s,
+            None => unreachable!("A track always has at least one point."),
+        };
+
+        info!("🛰️ Following a track of {} points...", track.points.len());
+
+        let url = bot_params.method_url("sendLocation");
+        trace!("url: {}", url);
+
+        let req_instance: SendLocationRequestModel = (
+            root_params.clone(),
+            bot_params.clone(),
+            SendParams::new(
+                &follow_params.receiver,
+                MessageFormat::Markdown,
+                follow_params.silent,
+            ),
+            LocationParams::new(
+                first.latitude,
+                first.longitude,
+                Some(live_period),
+                first.heading,
+                None,
+                None,
+            ),
+        )
+            .into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let response = client.post(url).multipart(req_body).send();
+        let message = handle_response!(response => MessageModel, on_success => {
+            info!("📦 Successfully sent live location.");
+        }, on_failure => {
+            error!("☠️ An error occured while sending the live location.");
+        })?;
+
+        let url = bot_params.method_url("editMessageLiveLocation");
+        trace!("url: {}", url);
+
+        let follow = || -> Result<(), OperationError> {
+            let mut previous = first;
+            let mut updated = first;
+            for (index, point) in rest.iter().enumerate() {
+                // replay at the recorded pace
+                let delay = (point.time - previous.time).to_std().unwrap_or_default();
+                debug!("Waiting {:?} for the next point...", delay);
+                thread::sleep(delay);
+                previous = point;
+
+                // the last point is always sent so that the track ends where it should
+                if index + 1 < rest.len()
+                    && point.time - updated.time < Duration::seconds(MIN_UPDATE_INTERVAL)
+                {
+                    debug!(
+                        "Skipping point #{} as it is too close to the last update.",
+                        index + 1
+                    );
+                    continue;
+                }
+                updated = point;
+
+                let req_instance: EditMessageLiveLocationRequestModel = (
+                    root_params.clone(),
+                    bot_params.clone(),
+                    LiveLocationParams::new(&follow_params.receiver, message.message_id),
+                    LocationParams::new(
+                        point.latitude,
+                        point.longitude,
+                        None,
+                        point.heading,
+                        None,
+                        None,
+                    ),
+                )
+                    .into();
+                let req_body = req_instance.try_into()?;
+                trace!("request body: {:?}", req_body);
+
+                let response = client.post(&url).multipart(req_body).send();
+                handle_response!(response, on_success => {
+                    debug!("Updated live location to point #{}.", index + 1);
+                }, on_failure => {
+                    error!("☠️ An error occured while updating the live location.");
+                })?;
+            }
+            Ok(())
+        };
+        let followed = follow();
+
+        let url = bot_params.method_url("stopMessageLiveLocation");
+        trace!("url: {}", url);
+
+        let req_instance: StopMessageLiveLocationRequestModel = (
+            root_params.clone(),
+            bot_params.clone(),
+            LiveLocationParams::new(&follow_params.receiver, message.message_id),
+        )
+            .into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let response = client.post(url).multipart(req_body).send();
+
+        // the live location is stopped even if following failed, the first error is returned
+        let stopped = handle_response!(response, on_success => {
+            if followed.is_ok() {
+                info!("📦 Successfully followed the track.");
+            }
+        }, on_failure => {
+            error!("☠️ An error occured while stopping the live location.");
+        });
+        followed.and(stopped)
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod follow;
+pub mod stop;
+pub mod track;
+pub mod update;
+
+#[derive(Debug)]
+pub struct LiveLocationParams {
+    pub receiver: String,
+    /// The id of the message with live location.
+    pub message_id: usize,
+}
+
+impl LiveLocationParams {
+    pub fn new(receiver: &str, message_id: usize) -> Self {
+        Self {
+            receiver: String::from(receiver),
+            message_id,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::stopmessagelivelocation::StopMessageLiveLocationRequestModel,
+        transfer_client,
+    },
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::LiveLocationParams;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub type StopLocationParams = (RootParams, BotParams, LiveLocationParams);
+
+#[derive(Debug)]
+pub struct StopLocationOperation {
+    params: StopLocationParams,
+}
+
+impl StopLocationOperation {
+    pub fn new(params: StopLocationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for StopLocationOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🛑 Stopping live location...");
+
+        let url = self.params.1.method_url("stopMessageLiveLocation");
+        trace!("url: {}", url);
+
+        let req_instance: StopMessageLiveLocationRequestModel = self.params.into();
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully stopped live location.");
+        }, on_failure => {
+            error!("☠️ An error occured while stopping the live location.");
+        })
+    }
+}
@@ -0,0 +1,319 @@
+use std::{fs, path::Path};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+use crate::operations::{CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+/// The format of a recorded track file.
+pub enum TrackFormat {
+    GPX,
+    NMEA,
+}
+
+#[derive(Debug)]
+/// A single recorded position of a track.
+pub struct TrackPoint {
+    pub latitude: f32,
+    pub longitude: f32,
+    pub time: DateTime<Utc>,
+    /// The direction of movement in degrees, between 1 and 360.
+    pub heading: Option<usize>,
+}
+
+#[derive(Debug)]
+/// A recorded track, ordered by time.
+pub struct Track {
+    pub points: Vec<TrackPoint>,
+}
+
+impl Track {
+    pub fn from_path(path: &Path, format: &TrackFormat) -> Result<Self, OperationError> {
+        debug!("Reading track from {}...", path.to_string_lossy());
+
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!(
+                        "Could not read the track file.\nPath: {}",
+                        path.to_string_lossy()
+                    ),
+                    Some(e),
+                ))
+            }
+        };
+
+        let mut points = match format {
+            TrackFormat::GPX => parse_gpx(&content),
+            TrackFormat::NMEA => parse_nmea(&content),
+        }?;
+
+        if points.is_empty() {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                "The track file does not contain any point.",
+                None::<&str>,
+            ));
+        }
+
+        points.sort_by_key(|p| p.time);
+        fill_headings(&mut points);
+
+        let track = Track { points };
+        trace!("track: {:?}", track);
+        Ok(track)
+    }
+
+    /// The time passed between the first and the last point in seconds.
+    pub fn duration(&self) -> i64 {
+        match (self.points.first(), self.points.last()) {
+            (Some(f), Some(l)) => (l.time - f.time).num_seconds(),
+            _ => 0,
+        }
+    }
+}
+
+fn invalid_track_error(message: impl std::fmt::Display) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::StdInvalidValue as i32,
+        "The track file is not valid.",
+        Some(message.to_string()),
+    )
+}
+
+/// Returns the value of an XML attribute inside a single tag.
+fn xml_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!("{}=", name);
+    let start = tag
+        .match_indices(&key)
+        .find(|(i, _)| *i == 0 || tag[..*i].ends_with(char::is_whitespace))
+        .map(|(i, _)| i + key.len())?;
+    let quote = tag[start..].chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+    let value = &tag[start + 1..];
+    value.find(quote).map(|end| &value[..end])
+}
+
+/// Returns the text content of the first XML element named `name`.
+fn xml_element<'a>(body: &'a str, name: &str) -> Option<&'a str> {
+    let open = format!("<{}>", name);
+    let close = format!("</{}>", name);
+    let start = body.find(&open)? + open.len();
+    let end = body[start..].find(&close)? + start;
+    Some(body[start..end].trim())
+}
+
+fn parse_gpx(content: &str) -> Result<Vec<TrackPoint>, OperationError> {
+    debug!("Parsing GPX track...");
+    let mut points = vec![];
+
+    for (index, chunk) in content.split("<trkpt").skip(1).enumerate() {
+        let tag_end = match chunk.find('>') {
+            Some(i) => i,
+            None => {
+                return Err(invalid_track_error(format!(
+                    "Track point #{} is not closed.",
+                    index
+                )))
+            }
+        };
+        let tag = &chunk[..tag_end];
+        let body = match tag.ends_with('/') {
+            true => "",
+            false => match chunk.find("</trkpt>") {
+                Some(i) => &chunk[tag_end..i],
+                None => {
+                    return Err(invalid_track_error(format!(
+                        "Track point #{} is not closed.",
+                        index
+                    )))
+                }
+            },
+        };
+
+        let latitude: f32 = match xml_attribute(tag, "lat").map(|v| v.parse()) {
+            Some(Ok(v)) => v,
+            _ => {
+                return Err(invalid_track_error(format!(
+                    "Track point #{} has no valid latitude.",
+                    index
+                )))
+            }
+        };
+
+        let longitude: f32 = match xml_attribute(tag, "lon").map(|v| v.parse()) {
+            Some(Ok(v)) => v,
+            _ => {
+                return Err(invalid_track_error(format!(
+                    "Track point #{} has no valid longitude.",
+                    index
+                )))
+            }
+        };
+
+        let time = match xml_element(body, "time").map(DateTime::parse_from_rfc3339) {
+            Some(Ok(t)) => t.with_timezone(&Utc),
+            _ => {
+                return Err(invalid_track_error(format!(
+                    "Track point #{} has no valid time.",
+                    index
+                )))
+            }
+        };
+
+        // `course` only exists in GPX 1.0
+        let heading = xml_element(body, "course")
+            .and_then(|c| c.parse::<f32>().ok())
+            .map(normalize_heading);
+
+        points.push(TrackPoint {
+            latitude,
+            longitude,
+            time,
+            heading,
+        });
+    }
+
+    Ok(points)
+}
+
+/// Converts NMEA `ddmm.mmmm` coordinates with a hemisphere into degrees.
+fn nmea_coordinate(value: &str, hemisphere: &str) -> Option<f32> {
+    let dot = value.find('.').unwrap_or(value.len());
+    if dot < 2 {
+        return None;
+    }
+    let degrees: f32 = value[..dot - 2].parse().ok()?;
+    let minutes: f32 = value[dot - 2..].parse().ok()?;
+    let coordinate = degrees + minutes / 60.0;
+
+    match hemisphere {
+        "N" | "E" => Some(coordinate),
+        "S" | "W" => Some(-coordinate),
+        _ => None,
+    }
+}
+
+fn parse_nmea(content: &str) -> Result<Vec<TrackPoint>, OperationError> {
+    debug!("Parsing NMEA track...");
+    let mut points = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let sentence = line.trim().split('*').next().unwrap_or_default();
+        let fields: Vec<&str> = sentence.split(',').collect();
+
+        // only recommended minimum sentences carry both the date and the position
+        if !fields[0].starts_with('$') || !fields[0].ends_with("RMC") {
+            continue;
+        }
+
+        if fields.len() < 10 {
+            return Err(invalid_track_error(format!(
+                "Sentence on line {} is incomplete.",
+                index + 1
+            )));
+        }
+
+        // `V` means the receiver had no fix
+        if fields[2] != "A" {
+            continue;
+        }
+
+        let latitude = match nmea_coordinate(fields[3], fields[4]) {
+            Some(v) => v,
+            None => {
+                return Err(invalid_track_error(format!(
+                    "Sentence on line {} has no valid latitude.",
+                    index + 1
+                )))
+            }
+        };
+
+        let longitude = match nmea_coordinate(fields[5], fields[6]) {
+            Some(v) => v,
+            None => {
+                return Err(invalid_track_error(format!(
+                    "Sentence on line {} has no valid longitude.",
+                    index + 1
+                )))
+            }
+        };
+
+        let time = match NaiveDateTime::parse_from_str(
+            &format!("{}{}", fields[9], fields[1]),
+            "%d%m%y%H%M%S%.f",
+        ) {
+            Ok(t) => Utc.from_utc_datetime(&t),
+            Err(e) => {
+                return Err(invalid_track_error(format!(
+                    "Sentence on line {} has no valid time. {}",
+                    index + 1,
+                    e
+                )))
+            }
+        };
+
+        let heading = fields[8].parse::<f32>().ok().map(normalize_heading);
+
+        points.push(TrackPoint {
+            latitude,
+            longitude,
+            time,
+            heading,
+        });
+    }
+
+    Ok(points)
+}
+
+/// Telegram accepts headings between 1 and 360.
+fn normalize_heading(degrees: f32) -> usize {
+    match degrees.rem_euclid(360.0).round() as usize {
+        0 => 360,
+        h => h,
+    }
+}
+
+/// The initial bearing from one point to another in degrees.
+fn bearing(from: &TrackPoint, to: &TrackPoint) -> f32 {
+    let (lat1, lat2) = (from.latitude.to_radians(), to.latitude.to_radians());
+    let delta_lon = (to.longitude - from.longitude).to_radians();
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+    y.atan2(x).to_degrees()
+}
+
+/// Infers missing headings from the direction towards the next point.
+fn fill_headings(points: &mut [TrackPoint]) {
+    for i in 0..points.len().saturating_sub(1) {
+        let stationary = points[i].latitude == points[i + 1].latitude
+            && points[i].longitude == points[i + 1].longitude;
+        if points[i].heading.is_none() && !stationary {
+            points[i].heading = Some(normalize_heading(bearing(&points[i], &points[i + 1])));
+        }
+    }
+
+    if let [.., previous, last] = points {
+        if last.heading.is_none() {
+            last.heading = previous.heading;
+        }
+    }
+}
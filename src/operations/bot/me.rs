@@ -0,0 +1,93 @@
+use reqwest::{blocking::Client, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    handle_response,
+    http::{response::failed_response_error, transfer_client},
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        CommonExitCodes, OperationError, RootParams,
+    },
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Telegram responds with 401 if the token is revoked and 404 if it is malformed.
+pub(crate) fn is_invalid_token(status: StatusCode) -> bool {
+    status == StatusCode::UNAUTHORIZED || status == StatusCode::NOT_FOUND
+}
+
+pub(crate) fn invalid_token_error(e: OperationError) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::TelegramAPIUnauthorized as i32,
+        "The bot token is invalid or has been revoked.",
+        Some(e),
+    )
+}
+
+/// Gets the bot itself, which also verifies the token. An invalid or revoked
+/// token is reported precisely instead of a generic bad request.
+pub fn get_me<T: DeserializeOwned>(
+    client: &Client,
+    bot_params: &BotParams,
+) -> Result<T, OperationError> {
+    let url = bot_params.method_url("getMe");
+    trace!("url: {}", url);
+
+    let response = match client.post(url).send() {
+        Ok(r) if is_invalid_token(r.status()) => {
+            return Err(invalid_token_error(failed_response_error(r)))
+        }
+        r => r,
+    };
+
+    handle_response!(response => T, on_success => {
+        debug!("Received the bot.");
+    }, on_failure => {
+        error!("☠️ An error occurred while getting the bot.");
+    })
+}
+
+pub type MeParams = (RootParams, BotParams);
+
+#[derive(Debug)]
+pub struct GetMeOperation {
+    params: MeParams,
+}
+
+impl GetMeOperation {
+    pub fn new(params: MeParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for GetMeOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🤖 Getting the bot...");
+
+        let client = transfer_client()?;
+        let me: Value = get_me(&client, &self.params.1)?;
+
+        info!(
+            "📦 The token belongs to @{}.",
+            me["username"].as_str().unwrap_or_default()
+        );
+        // the whole user so that the capabilities, e.g. `can_join_groups`, can be processed
+        println!("{}", me);
+        Ok(())
+    }
+}
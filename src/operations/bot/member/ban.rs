@@ -0,0 +1,93 @@
+use std::convert::TryInto;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    handle_response,
+    http::request::models::banchatmember::BanChatMemberRequestModel,
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{for_each_member, MemberParams, MembersParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug, Clone)]
+pub struct BanParams {
+    /// The user is banned forever if absent.
+    pub until: Option<DateTime<Utc>>,
+    /// Deletes all messages of the user in the chat.
+    pub revoke_messages: bool,
+}
+
+impl BanParams {
+    pub fn new(until: Option<DateTime<Utc>>, revoke_messages: bool) -> Self {
+        Self {
+            until,
+            revoke_messages,
+        }
+    }
+}
+
+pub type BanMembersParams = (RootParams, BotParams, MembersParams, BanParams);
+pub type BanMemberParams = (RootParams, BotParams, MemberParams, BanParams);
+
+#[derive(Debug)]
+pub struct BanMemberOperation {
+    params: BanMembersParams,
+}
+
+impl BanMemberOperation {
+    pub fn new(params: BanMembersParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for BanMemberOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, members_params, ban_params) = self.params;
+        info!("🔨 Banning {} user(s)...", members_params.users.len());
+
+        let url = bot_params.method_url("banChatMember");
+        trace!("url: {}", url);
+
+        for_each_member(&members_params, |client, member_params| {
+            let user = member_params.user;
+            let req_instance: BanChatMemberRequestModel = (
+                root_params.clone(),
+                bot_params.clone(),
+                member_params,
+                ban_params.clone(),
+            )
+                .into();
+            let req_body = match req_instance.try_into() {
+                Ok(f) => f,
+                Err(e) => return Err(e),
+            };
+            trace!("request body: {:?}", req_body);
+
+            let response = client.post(&url).multipart(req_body).send();
+            handle_response!(response, on_success => {
+                info!("📦 Banned {}.", user);
+            }, on_failure => {
+                debug!("Could not ban {}.", user);
+            })
+        })
+    }
+}
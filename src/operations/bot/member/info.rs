@@ -0,0 +1,65 @@
+use std::convert::TryInto;
+
+use serde_json::Value;
+
+use crate::{
+    handle_response,
+    http::request::models::member::MemberRequestModel,
+    operations::{bot::send::SendOperation, OperationError},
+};
+
+use super::{print_each_member, MembersOperationParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct GetMemberOperation {
+    params: MembersOperationParams,
+}
+
+impl GetMemberOperation {
+    pub fn new(params: MembersOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for GetMemberOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (_, bot_params, members_params) = self.params;
+        info!("🔎 Getting {} member(s)...", members_params.users.len());
+
+        let url = bot_params.method_url("getChatMember");
+        trace!("url: {}", url);
+
+        print_each_member(&members_params, |client, member_params| {
+            let user = member_params.user;
+            let req_instance: MemberRequestModel = member_params.into();
+            let req_body = match req_instance.try_into() {
+                Ok(f) => f,
+                Err(e) => return Err(e),
+            };
+            trace!("request body: {:?}", req_body);
+
+            let response = client.post(&url).multipart(req_body).send();
+            // a chat member per line so that each can be processed separately
+            handle_response!(response => Value, on_success => {
+                debug!("Received the member {}.", user);
+            }, on_failure => {
+                debug!("Could not get the member {}.", user);
+            })
+        })
+    }
+}
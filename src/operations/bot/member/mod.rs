@@ -0,0 +1,120 @@
+use reqwest::blocking::Client;
+use serde_json::{json, Value};
+
+use crate::{
+    http::transfer_client,
+    operations::{bot::BotParams, OperationError, RootParams},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod ban;
+pub mod info;
+pub mod promote;
+pub mod restrict;
+pub mod unban;
+
+/// The members of a chat to be moderated.
+#[derive(Debug, Clone)]
+pub struct MembersParams {
+    /// The id of the chat or the username of the channel, e.g. `@channel`.
+    pub chat: String,
+    pub users: Vec<u64>,
+}
+
+impl MembersParams {
+    pub fn new(chat: &str, users: Vec<u64>) -> Self {
+        Self {
+            chat: String::from(chat),
+            users,
+        }
+    }
+}
+
+/// A single member of a chat. Requests are sent per member.
+#[derive(Debug, Clone)]
+pub struct MemberParams {
+    pub chat: String,
+    pub user: u64,
+}
+
+impl MemberParams {
+    pub fn new(chat: &str, user: u64) -> Self {
+        Self {
+            chat: String::from(chat),
+            user,
+        }
+    }
+}
+
+pub type MembersOperationParams = (RootParams, BotParams, MembersParams);
+
+/// Runs `action` for every member and prints `{"id": …, "ok": true}` per member
+/// that succeeded. See [print_each_member] for failures.
+fn for_each_member<F>(members: &MembersParams, mut action: F) -> Result<(), OperationError>
+where
+    F: FnMut(&Client, MemberParams) -> Result<(), OperationError>,
+{
+    print_each_member(members, |client, member_params| {
+        let user = member_params.user;
+        action(client, member_params).map(|_| json!({ "id": user, "ok": true }))
+    })
+}
+
+/// Runs `action` for every member with a shared client and prints what it
+/// returns, a JSON object per line. A failure is printed as
+/// `{"id": …, "ok": false, …}` and does not stop the others so that a bulk
+/// operation is done as much as possible, but the last error is returned in
+/// the end.
+fn print_each_member<F>(members: &MembersParams, mut action: F) -> Result<(), OperationError>
+where
+    F: FnMut(&Client, MemberParams) -> Result<Value, OperationError>,
+{
+    let client = transfer_client()?;
+    let mut failures = 0;
+    let mut last_error = None;
+
+    for user in &members.users {
+        match action(&client, MemberParams::new(&members.chat, *user)) {
+            Ok(line) => println!("{}", line),
+            Err(e) => {
+                error!("☠️ {}: {}", user, e);
+                println!(
+                    "{}",
+                    json!({
+                        "id": user,
+                        "ok": false,
+                        "exit_code": e.exit_code(),
+                        "error": e.to_string(),
+                    })
+                );
+                failures += 1;
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) => {
+            error!(
+                "☠️ Failed for {} of {} user(s).",
+                failures,
+                members.users.len()
+            );
+            Err(e)
+        }
+        None => Ok(()),
+    }
+}
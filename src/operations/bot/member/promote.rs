@@ -0,0 +1,162 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::request::models::promotechatmember::PromoteChatMemberRequestModel,
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{for_each_member, MemberParams, MembersParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A right of an administrator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdministratorRight {
+    ManageChat,
+    DeleteMessages,
+    ManageVideoChats,
+    RestrictMembers,
+    PromoteMembers,
+    ChangeInfo,
+    InviteUsers,
+    PostMessages,
+    EditMessages,
+    PinMessages,
+    ManageTopics,
+}
+
+impl AdministratorRight {
+    pub const ALL: [AdministratorRight; 11] = [
+        AdministratorRight::ManageChat,
+        AdministratorRight::DeleteMessages,
+        AdministratorRight::ManageVideoChats,
+        AdministratorRight::RestrictMembers,
+        AdministratorRight::PromoteMembers,
+        AdministratorRight::ChangeInfo,
+        AdministratorRight::InviteUsers,
+        AdministratorRight::PostMessages,
+        AdministratorRight::EditMessages,
+        AdministratorRight::PinMessages,
+        AdministratorRight::ManageTopics,
+    ];
+
+    /// The name used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AdministratorRight::ManageChat => "manage-chat",
+            AdministratorRight::DeleteMessages => "delete-messages",
+            AdministratorRight::ManageVideoChats => "manage-video-chats",
+            AdministratorRight::RestrictMembers => "restrict-members",
+            AdministratorRight::PromoteMembers => "promote-members",
+            AdministratorRight::ChangeInfo => "change-info",
+            AdministratorRight::InviteUsers => "invite-users",
+            AdministratorRight::PostMessages => "post-messages",
+            AdministratorRight::EditMessages => "edit-messages",
+            AdministratorRight::PinMessages => "pin-messages",
+            AdministratorRight::ManageTopics => "manage-topics",
+        }
+    }
+
+    /// The parameter of /promoteChatMember in Bot API.
+    pub fn field(&self) -> &'static str {
+        match self {
+            AdministratorRight::ManageChat => "can_manage_chat",
+            AdministratorRight::DeleteMessages => "can_delete_messages",
+            AdministratorRight::ManageVideoChats => "can_manage_video_chats",
+            AdministratorRight::RestrictMembers => "can_restrict_members",
+            AdministratorRight::PromoteMembers => "can_promote_members",
+            AdministratorRight::ChangeInfo => "can_change_info",
+            AdministratorRight::InviteUsers => "can_invite_users",
+            AdministratorRight::PostMessages => "can_post_messages",
+            AdministratorRight::EditMessages => "can_edit_messages",
+            AdministratorRight::PinMessages => "can_pin_messages",
+            AdministratorRight::ManageTopics => "can_manage_topics",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        AdministratorRight::ALL
+            .iter()
+            .find(|r| r.name() == name)
+            .copied()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PromoteParams {
+    /// The rights the user gets. Every other right is revoked, which
+    /// demotes the user if none is given.
+    pub rights: Vec<AdministratorRight>,
+    /// Hides the administrator in the chat.
+    pub anonymous: bool,
+}
+
+impl PromoteParams {
+    pub fn new(rights: Vec<AdministratorRight>, anonymous: bool) -> Self {
+        Self { rights, anonymous }
+    }
+}
+
+pub type PromoteMembersParams = (RootParams, BotParams, MembersParams, PromoteParams);
+pub type PromoteMemberParams = (RootParams, BotParams, MemberParams, PromoteParams);
+
+#[derive(Debug)]
+pub struct PromoteMemberOperation {
+    params: PromoteMembersParams,
+}
+
+impl PromoteMemberOperation {
+    pub fn new(params: PromoteMembersParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for PromoteMemberOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, members_params, promote_params) = self.params;
+        info!("🎖️ Promoting {} user(s)...", members_params.users.len());
+
+        let url = bot_params.method_url("promoteChatMember");
+        trace!("url: {}", url);
+
+        for_each_member(&members_params, |client, member_params| {
+            let user = member_params.user;
+            let req_instance: PromoteChatMemberRequestModel = (
+                root_params.clone(),
+                bot_params.clone(),
+                member_params,
+                promote_params.clone(),
+            )
+                .into();
+            let req_body = match req_instance.try_into() {
+                Ok(f) => f,
+                Err(e) => return Err(e),
+            };
+            trace!("request body: {:?}", req_body);
+
+            let response = client.post(&url).multipart(req_body).send();
+            handle_response!(response, on_success => {
+                info!("📦 Promoted {}.", user);
+            }, on_failure => {
+                debug!("Could not promote {}.", user);
+            })
+        })
+    }
+}
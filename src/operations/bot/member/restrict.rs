@@ -0,0 +1,175 @@
+use std::convert::TryInto;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    handle_response,
+    http::request::models::restrictchatmember::RestrictChatMemberRequestModel,
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{for_each_member, MemberParams, MembersParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A permission of a chat member.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChatPermission {
+    Messages,
+    Audios,
+    Documents,
+    Photos,
+    Videos,
+    VideoNotes,
+    VoiceNotes,
+    Polls,
+    OtherMessages,
+    WebPagePreviews,
+    ChangeInfo,
+    InviteUsers,
+    PinMessages,
+    ManageTopics,
+}
+
+impl ChatPermission {
+    pub const ALL: [ChatPermission; 14] = [
+        ChatPermission::Messages,
+        ChatPermission::Audios,
+        ChatPermission::Documents,
+        ChatPermission::Photos,
+        ChatPermission::Videos,
+        ChatPermission::VideoNotes,
+        ChatPermission::VoiceNotes,
+        ChatPermission::Polls,
+        ChatPermission::OtherMessages,
+        ChatPermission::WebPagePreviews,
+        ChatPermission::ChangeInfo,
+        ChatPermission::InviteUsers,
+        ChatPermission::PinMessages,
+        ChatPermission::ManageTopics,
+    ];
+
+    /// The name used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChatPermission::Messages => "messages",
+            ChatPermission::Audios => "audios",
+            ChatPermission::Documents => "documents",
+            ChatPermission::Photos => "photos",
+            ChatPermission::Videos => "videos",
+            ChatPermission::VideoNotes => "video-notes",
+            ChatPermission::VoiceNotes => "voice-notes",
+            ChatPermission::Polls => "polls",
+            ChatPermission::OtherMessages => "other-messages",
+            ChatPermission::WebPagePreviews => "web-page-previews",
+            ChatPermission::ChangeInfo => "change-info",
+            ChatPermission::InviteUsers => "invite-users",
+            ChatPermission::PinMessages => "pin-messages",
+            ChatPermission::ManageTopics => "manage-topics",
+        }
+    }
+
+    /// The field of `ChatPermissions` in Bot API.
+    pub fn field(&self) -> &'static str {
+        match self {
+            ChatPermission::Messages => "can_send_messages",
+            ChatPermission::Audios => "can_send_audios",
+            ChatPermission::Documents => "can_send_documents",
+            ChatPermission::Photos => "can_send_photos",
+            ChatPermission::Videos => "can_send_videos",
+            ChatPermission::VideoNotes => "can_send_video_notes",
+            ChatPermission::VoiceNotes => "can_send_voice_notes",
+            ChatPermission::Polls => "can_send_polls",
+            ChatPermission::OtherMessages => "can_send_other_messages",
+            ChatPermission::WebPagePreviews => "can_add_web_page_previews",
+            ChatPermission::ChangeInfo => "can_change_info",
+            ChatPermission::InviteUsers => "can_invite_users",
+            ChatPermission::PinMessages => "can_pin_messages",
+            ChatPermission::ManageTopics => "can_manage_topics",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        ChatPermission::ALL
+            .iter()
+            .find(|p| p.name() == name)
+            .copied()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RestrictParams {
+    /// The permissions the user keeps. Every other permission is revoked.
+    pub permissions: Vec<ChatPermission>,
+    /// The user is restricted forever if absent.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl RestrictParams {
+    pub fn new(permissions: Vec<ChatPermission>, until: Option<DateTime<Utc>>) -> Self {
+        Self { permissions, until }
+    }
+}
+
+pub type RestrictMembersParams = (RootParams, BotParams, MembersParams, RestrictParams);
+pub type RestrictMemberParams = (RootParams, BotParams, MemberParams, RestrictParams);
+
+#[derive(Debug)]
+pub struct RestrictMemberOperation {
+    params: RestrictMembersParams,
+}
+
+impl RestrictMemberOperation {
+    pub fn new(params: RestrictMembersParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for RestrictMemberOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, members_params, restrict_params) = self.params;
+        info!("🔇 Restricting {} user(s)...", members_params.users.len());
+
+        let url = bot_params.method_url("restrictChatMember");
+        trace!("url: {}", url);
+
+        for_each_member(&members_params, |client, member_params| {
+            let user = member_params.user;
+            let req_instance: RestrictChatMemberRequestModel = (
+                root_params.clone(),
+                bot_params.clone(),
+                member_params,
+                restrict_params.clone(),
+            )
+                .into();
+            let req_body = match req_instance.try_into() {
+                Ok(f) => f,
+                Err(e) => return Err(e),
+            };
+            trace!("request body: {:?}", req_body);
+
+            let response = client.post(&url).multipart(req_body).send();
+            handle_response!(response, on_success => {
+                info!("📦 Restricted {}.", user);
+            }, on_failure => {
+                debug!("Could not restrict {}.", user);
+            })
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::request::models::unbanchatmember::UnbanChatMemberRequestModel,
+    operations::{
+        bot::{send::SendOperation, BotParams},
+        OperationError, RootParams,
+    },
+};
+
+use super::{for_each_member, MemberParams, MembersParams};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug, Clone)]
+pub struct UnbanParams {
+    /// Does nothing if the user is not banned. Otherwise, a member is removed
+    /// from the chat and can join again.
+    pub only_if_banned: bool,
+}
+
+impl UnbanParams {
+    pub fn new(only_if_banned: bool) -> Self {
+        Self { only_if_banned }
+    }
+}
+
+pub type UnbanMembersParams = (RootParams, BotParams, MembersParams, UnbanParams);
+pub type UnbanMemberParams = (RootParams, BotParams, MemberParams, UnbanParams);
+
+#[derive(Debug)]
+pub struct UnbanMemberOperation {
+    params: UnbanMembersParams,
+}
+
+impl UnbanMemberOperation {
+    pub fn new(params: UnbanMembersParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for UnbanMemberOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let (root_params, bot_params, members_params, unban_params) = self.params;
+        info!("🕊️ Unbanning {} user(s)...", members_params.users.len());
+
+        let url = bot_params.method_url("unbanChatMember");
+        trace!("url: {}", url);
+
+        for_each_member(&members_params, |client, member_params| {
+            let user = member_params.user;
+            let req_instance: UnbanChatMemberRequestModel = (
+                root_params.clone(),
+                bot_params.clone(),
+                member_params,
+                unban_params.clone(),
+            )
+                .into();
+            let req_body = match req_instance.try_into() {
+                Ok(f) => f,
+                Err(e) => return Err(e),
+            };
+            trace!("request body: {:?}", req_body);
+
+            let response = client.post(&url).multipart(req_body).send();
+            handle_response!(response, on_success => {
+                info!("📦 Unbanned {}.", user);
+            }, on_failure => {
+                debug!("Could not unban {}.", user);
+            })
+        })
+    }
+}
@@ -1,3 +1,7 @@
+use std::{fs, path::Path};
+
+use crate::operations::{CommonExitCodes, OperationError};
+
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -12,17 +16,129 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod chat;
+pub mod commands;
+pub mod file;
+pub mod location;
+pub mod me;
+pub mod member;
+pub mod profile;
 pub mod send;
 
-#[derive(Debug)]
+/// Bots can upload files up to 50 MB to the official API server.
+const UPLOAD_LIMIT: u64 = 50 * 1024 * 1024;
+/// A local Bot API server accepts uploads up to 2000 MB.
+const LOCAL_UPLOAD_LIMIT: u64 = 2000 * 1024 * 1024;
+/// What the token is replaced with in printed URLs.
+pub const REDACTED_TOKEN: &str = "<redacted>";
+
+#[derive(Debug, Clone)]
 pub struct BotParams {
     token: String,
+    /// The root URL of Bot API server, without a trailing slash.
+    api_url: String,
+    /// Whether the API server is a local Bot API server, which is told
+    /// explicitly since mirrors of the official one have the same limits.
+    local_server: bool,
 }
 
 impl BotParams {
-    pub fn new(token: &str) -> Self {
+    pub fn new(token: &str, api_url: &str) -> Self {
         Self {
             token: String::from(token),
+            api_url: String::from(api_url.trim_end_matches('/')),
+            local_server: false,
+        }
+    }
+
+    /// The same bot on a local Bot API server if `local_server` is true.
+    pub fn with_local_server(self, local_server: bool) -> Self {
+        Self {
+            local_server,
+            ..self
         }
     }
+
+    /// The id of the bot, which is the part of the token before the colon.
+    pub fn id(&self) -> &str {
+        self.token.split(':').next().unwrap_or_default()
+    }
+
+    /// The same bot on another Bot API server.
+    pub fn with_api_url(&self, api_url: &str) -> Self {
+        Self::new(&self.token, api_url).with_local_server(self.local_server)
+    }
+
+    /// The URL to call a Bot API method.
+    pub fn method_url(&self, method: &str) -> String {
+        format!(
+            "{api_url}/bot{token}/{method}",
+            api_url = self.api_url,
+            token = self.token,
+            method = method,
+        )
+    }
+
+    /// The URL to call a Bot API method with the token redacted, which is safe to print.
+    pub fn redacted_method_url(&self, method: &str) -> String {
+        format!(
+            "{api_url}/bot{token}/{method}",
+            api_url = self.api_url,
+            token = REDACTED_TOKEN,
+            method = method,
+        )
+    }
+
+    /// The URL to download a file with the path provided by `getFile`.
+    pub fn file_url(&self, file_path: &str) -> String {
+        format!(
+            "{api_url}/file/bot{token}/{file_path}",
+            api_url = self.api_url,
+            token = self.token,
+            file_path = file_path,
+        )
+    }
+
+    /// Whether the API server is a local Bot API server rather than the official one.
+    pub fn is_local_server(&self) -> bool {
+        self.local_server
+    }
+
+    /// The maximum size of a file that can be uploaded to the API server in bytes.
+    pub fn upload_limit(&self) -> u64 {
+        match self.is_local_server() {
+            true => LOCAL_UPLOAD_LIMIT,
+            false => UPLOAD_LIMIT,
+        }
+    }
+
+    /// Fails early if the file at `path` cannot be uploaded to the API server.
+    pub fn check_upload(&self, path: &Path) -> Result<(), OperationError> {
+        let size = match fs::metadata(path) {
+            Ok(m) => m.len(),
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!("Could not read {}.", path.display()),
+                    Some(e),
+                ))
+            }
+        };
+
+        let limit = self.upload_limit();
+        if size > limit {
+            return Err(OperationError::new(
+                CommonExitCodes::TelegramAPIFileTooLarge as i32,
+                format!(
+                    "{} is {} MB while the API server accepts files up to {} MB.",
+                    path.display(),
+                    size / 1024 / 1024,
+                    limit / 1024 / 1024,
+                ),
+                None::<&str>,
+            ));
+        }
+
+        Ok(())
+    }
 }
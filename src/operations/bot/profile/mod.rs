@@ -0,0 +1,101 @@
+use std::convert::TryInto;
+
+use crate::{
+    handle_response,
+    http::{request::models::setprofile::SetProfileRequestModel, transfer_client},
+    operations::{bot::BotParams, OperationError, RootParams},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod description;
+pub mod name;
+
+/// A text on the profile of the bot.
+#[derive(Debug, Clone, Copy)]
+pub enum ProfileField {
+    Name,
+    Description,
+    ShortDescription,
+}
+
+impl ProfileField {
+    pub fn method(&self) -> &'static str {
+        match self {
+            ProfileField::Name => "setMyName",
+            ProfileField::Description => "setMyDescription",
+            ProfileField::ShortDescription => "setMyShortDescription",
+        }
+    }
+
+    /// The name of the parameter holding the value.
+    pub fn parameter(&self) -> &'static str {
+        match self {
+            ProfileField::Name => "name",
+            ProfileField::Description => "description",
+            ProfileField::ShortDescription => "short_description",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ProfileField::Name => "name",
+            ProfileField::Description => "description",
+            ProfileField::ShortDescription => "short description",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProfileParams {
+    pub field: ProfileField,
+    /// Removes the value if absent.
+    pub value: Option<String>,
+    /// Sets the value for the users with the language if present.
+    pub language_code: Option<String>,
+}
+
+impl ProfileParams {
+    pub fn new(field: ProfileField, value: Option<String>, language_code: Option<String>) -> Self {
+        Self {
+            field,
+            value,
+            language_code,
+        }
+    }
+}
+
+pub type ProfileOperationParams = (RootParams, BotParams, ProfileParams);
+
+fn set_profile(params: ProfileOperationParams) -> Result<(), OperationError> {
+    let field = params.2.field;
+    info!("🪪 Setting the {}...", field.label());
+
+    let url = params.1.method_url(field.method());
+    trace!("url: {}", url);
+
+    let req_instance: SetProfileRequestModel = params.into();
+    let req_body = req_instance.try_into()?;
+    trace!("request body: {:?}", req_body);
+
+    let client = transfer_client()?;
+    let response = client.post(url).multipart(req_body).send();
+
+    handle_response!(response, on_success => {
+        info!("📦 Successfully set the {}.", field.label());
+    }, on_failure => {
+        error!("☠️ An error occurred while setting the {}.", field.label());
+    })
+}
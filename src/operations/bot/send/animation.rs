@@ -0,0 +1,115 @@
+use std::{convert::TryInto, path::PathBuf};
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::sendanimation::SendAnimationRequestModel,
+        response::models::message::MessageModel, transfer_client,
+    },
+    operations::{bot::BotParams, OperationError, RootParams},
+};
+
+use super::{
+    keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams, ThumbnailParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct AnimationParams {
+    pub file: PathBuf,
+    pub message: Option<String>,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+    /// Duration of the animation in seconds.
+    pub duration: Option<usize>,
+    pub thumbnail: Option<ThumbnailParams>,
+}
+
+impl AnimationParams {
+    pub fn new(
+        file: PathBuf,
+        message: Option<String>,
+        width: Option<usize>,
+        height: Option<usize>,
+        duration: Option<usize>,
+        thumbnail: Option<ThumbnailParams>,
+    ) -> Self {
+        Self {
+            file,
+            message,
+            width,
+            height,
+            duration,
+            thumbnail,
+        }
+    }
+}
+
+pub type SendAnimationParams = (RootParams, BotParams, SendParams, AnimationParams);
+
+#[derive(Debug)]
+pub struct SendAnimationOperation {
+    params: SendAnimationParams,
+}
+
+impl SendAnimationOperation {
+    pub fn new(params: SendAnimationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for SendAnimationOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🎞️ Sending animation...");
+
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendAnimationRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendAnimation", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendAnimation");
+        trace!("url: {}", url);
+
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
+        let req_instance: SendAnimationRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
+        let req_body = req_instance.try_into()?;
+        trace!("request body: {:?}", req_body);
+
+        let client = transfer_client()?;
+        let response = client.post(url).multipart(req_body).send();
+
+        let result = handle_response!(response => MessageModel, on_success => {
+            info!("📦 Successfully sent animation.");
+        }, on_failure => {
+            error!("☠️ An error occurred while sending the animation.");
+        });
+
+        let message = keep_in_outbox(outbox, "sendAnimation", kept, result);
+        record(&bot_params, idempotency, message)
+    }
+}
@@ -1,15 +1,17 @@
 use std::{convert::TryInto, path::PathBuf};
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendaudio::SendAudioRequestModel,
+    http::{
+        request::models::sendaudio::SendAudioRequestModel, response::models::message::MessageModel,
+        transfer_client,
+    },
     operations::{bot::BotParams, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{
+    keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams, ThumbnailParams,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -31,6 +33,9 @@ pub struct AudioParams {
     pub message: Option<String>,
     pub title: Option<String>,
     pub performer: Option<String>,
+    /// Duration of the audio in seconds.
+    pub duration: Option<usize>,
+    pub thumbnail: Option<ThumbnailParams>,
 }
 
 impl AudioParams {
@@ -39,12 +44,16 @@ impl AudioParams {
         message: Option<String>,
         title: Option<String>,
         performer: Option<String>,
+        duration: Option<usize>,
+        thumbnail: Option<ThumbnailParams>,
     ) -> Self {
         Self {
             file,
             message,
             title,
             performer,
+            duration,
+            thumbnail,
         }
     }
 }
@@ -67,27 +76,43 @@ impl SendOperation for SendAudioOperation {
     fn send(self) -> Result<(), crate::operations::OperationError> {
         info!("🔊 Sending audio...");
 
-        let url = format!(
-            "{root_url}{token}/sendAudio",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token
-        );
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendAudioRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendAudio", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendAudio");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendAudioRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         debug!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent audio.");
         }, on_failure => {
             error!("☠️ An error occurred while sending the audio.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendAudio", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
@@ -0,0 +1,220 @@
+use std::{fs, path::Path};
+
+use crate::operations::{bot::BotParams, CommonExitCodes, OperationError, RootParams};
+
+use super::{
+    commonmark::MESSAGE_LIMIT,
+    document::{DocumentParams, SendDocumentOperation},
+    entities::utf16_len,
+    message::{MessageParams, SendMessageOperation},
+    MessageFormat, SendOperation, SendParams,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The languages guessed from the extension of a file.
+const LANGUAGES: &[(&str, &str)] = &[
+    ("c", "c"),
+    ("cc", "cpp"),
+    ("cpp", "cpp"),
+    ("cs", "csharp"),
+    ("css", "css"),
+    ("diff", "diff"),
+    ("go", "go"),
+    ("h", "c"),
+    ("hpp", "cpp"),
+    ("html", "html"),
+    ("java", "java"),
+    ("js", "javascript"),
+    ("json", "json"),
+    ("kt", "kotlin"),
+    ("md", "markdown"),
+    ("patch", "diff"),
+    ("php", "php"),
+    ("py", "python"),
+    ("rb", "ruby"),
+    ("rs", "rust"),
+    ("sh", "bash"),
+    ("sql", "sql"),
+    ("swift", "swift"),
+    ("toml", "toml"),
+    ("ts", "typescript"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+    ("yml", "yaml"),
+];
+
+/// The language of `file` guessed from its extension, if known.
+pub fn language_of(file: &Path) -> Option<&'static str> {
+    let extension = file.extension()?.to_str()?.to_ascii_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|(e, _)| *e == extension)
+        .map(|(_, l)| *l)
+}
+
+/// The last `lines` lines of `text`.
+pub fn tail(text: &str, lines: usize) -> &str {
+    let text = text.trim_end_matches('\n');
+    match lines.checked_sub(1) {
+        Some(n) => match text.rmatch_indices('\n').nth(n) {
+            Some((i, _)) => &text[i + 1..],
+            None => text,
+        },
+        None => "",
+    }
+}
+
+/// Wraps `code` in as many code blocks as needed to keep each under `limit`
+/// characters, splitting between lines if possible.
+pub fn to_html_parts(code: &str, language: Option<&str>, limit: usize) -> Vec<String> {
+    let mut chunks = vec![];
+    let mut chunk = String::new();
+    let mut len = 0;
+
+    for line in code.split_inclusive('\n') {
+        let line_len = utf16_len(line);
+        if len > 0 && len + line_len > limit {
+            chunks.push(std::mem::take(&mut chunk));
+            len = 0;
+        }
+
+        // a line longer than a message is split anywhere
+        for c in line.chars() {
+            if len + c.len_utf16() > limit {
+                chunks.push(std::mem::take(&mut chunk));
+                len = 0;
+            }
+            chunk.push(c);
+            len += c.len_utf16();
+        }
+    }
+    chunks.push(chunk);
+
+    let (open, close) = match language {
+        Some(l) => (
+            format!(
+                "<pre><code class=\"language-{}\">",
+                MessageFormat::HTML.escape(l)
+            ),
+            "</code></pre>",
+        ),
+        None => (String::from("<pre>"), "</pre>"),
+    };
+    chunks
+        .iter()
+        .map(|c| c.trim_end_matches('\n'))
+        .filter(|c| !c.trim().is_empty())
+        .map(|c| format!("{}{}{}", open, MessageFormat::HTML.escape(c), close))
+        .collect()
+}
+
+/// What is done with code over the limit of a message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Overflow {
+    /// The code is split across messages.
+    Split,
+    /// The code is sent as a document instead.
+    Document,
+}
+
+#[derive(Debug)]
+pub struct CodeParams {
+    pub code: String,
+    pub language: Option<String>,
+    /// The name of the document the code is sent as if it overflows.
+    pub name: String,
+    pub overflow: Overflow,
+}
+
+impl CodeParams {
+    pub fn new(code: String, language: Option<String>, name: String, overflow: Overflow) -> Self {
+        Self {
+            code,
+            language,
+            name,
+            overflow,
+        }
+    }
+}
+
+pub type SendCodeParams = (RootParams, BotParams, SendParams, CodeParams);
+
+#[derive(Debug)]
+pub struct SendCodeOperation {
+    params: SendCodeParams,
+}
+
+impl SendCodeOperation {
+    pub fn new(params: SendCodeParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for SendCodeOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("💻 Sending code...");
+
+        let (root_params, bot_params, mut send_params, code_params) = self.params;
+        let parts = to_html_parts(
+            &code_params.code,
+            code_params.language.as_deref(),
+            MESSAGE_LIMIT,
+        );
+        if parts.is_empty() {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                "There is no code to send.",
+                None::<&str>,
+            ));
+        }
+
+        if parts.len() == 1 || code_params.overflow == Overflow::Split {
+            send_params.format = MessageFormat::HTML;
+            let operation = SendMessageOperation::new((
+                root_params,
+                bot_params,
+                send_params,
+                MessageParams::new(String::new()),
+            ));
+            return operation.send_parts(parts);
+        }
+
+        info!("📎 The code is over the limit of a message, sending it as a document...");
+        let dir = std::env::temp_dir().join(format!("tgcli-code-{}", std::process::id()));
+        let file = dir.join(&code_params.name);
+        if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&file, &code_params.code)) {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsWriteError as i32,
+                format!(
+                    "Could not write the code to upload.\nPath: {}",
+                    file.display()
+                ),
+                Some(e),
+            ));
+        }
+
+        let operation = SendDocumentOperation::new((
+            root_params,
+            bot_params,
+            send_params,
+            DocumentParams::new(file, None, None),
+        ));
+        let result = operation.send();
+        let _ = fs::remove_dir_all(&dir);
+        result
+    }
+}
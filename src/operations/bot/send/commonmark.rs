@@ -0,0 +1,518 @@
+use std::mem;
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag};
+use url::Url;
+
+use super::MessageFormat;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The most characters Telegram takes in a message.
+pub const MESSAGE_LIMIT: usize = 4096;
+
+/// Converts CommonMark, with tables, strikethrough and task lists of GitHub,
+/// into the HTML Telegram takes.
+pub fn to_html(text: &str) -> String {
+    Renderer::render(text).join("\n\n")
+}
+
+/// Converts CommonMark into the HTML of as many messages as needed to keep
+/// each under `limit` characters, splitting between blocks if possible.
+pub fn to_html_parts(text: &str, limit: usize) -> Vec<String> {
+    let mut parts = vec![];
+    let mut part = String::new();
+    let mut len = 0;
+
+    for block in Renderer::render(text) {
+        let block_len = visible_len(&block);
+        if !part.is_empty() && len + 2 + block_len <= limit {
+            part.push_str("\n\n");
+            part.push_str(&block);
+            len += 2 + block_len;
+            continue;
+        }
+
+        if !part.is_empty() {
+            parts.push(mem::take(&mut part));
+        }
+
+        let mut pieces = match block_len <= limit {
+            true => vec![block],
+            false => split(&block, limit),
+        };
+        part = pieces.pop().unwrap_or_default();
+        len = visible_len(&part);
+        parts.extend(pieces);
+    }
+
+    if !part.is_empty() {
+        parts.push(part);
+    }
+    parts
+}
+
+/// Renders CommonMark into the HTML of each block at the top.
+#[derive(Default)]
+struct Renderer {
+    blocks: Vec<String>,
+    /// The block being rendered.
+    html: String,
+    /// How many blocks are open, including the one at the top.
+    depth: usize,
+    /// Whether nothing but the opening of a block has been rendered since it
+    /// was opened, so it needs no separator.
+    fresh: bool,
+    /// Whether a raw HTML block is being rendered at the top.
+    raw: bool,
+    /// The next number of each open list, or none if it is a bullet list.
+    lists: Vec<Option<u64>>,
+    /// Whether each open link is rendered as a link.
+    links: Vec<bool>,
+    /// Whether the open code block has a language.
+    code: Option<bool>,
+    /// The alignments and rows of the open table.
+    table: Option<(Vec<Alignment>, Vec<Vec<String>>)>,
+}
+
+impl Renderer {
+    fn render(text: &str) -> Vec<String> {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut renderer = Renderer::default();
+
+        for event in Parser::new_ext(text, options) {
+            match event {
+                Event::Start(tag) => renderer.start(tag),
+                Event::End(tag) => renderer.end(tag),
+                Event::Text(text) => renderer.text(&text),
+                Event::Code(code) => match renderer.table {
+                    Some(_) => renderer.text(&code),
+                    None => {
+                        renderer.tag("<code>");
+                        renderer.text(&code);
+                        renderer.tag("</code>");
+                    }
+                },
+                Event::Html(html) => {
+                    if renderer.depth == 0 && !renderer.raw {
+                        renderer.flush();
+                        renderer.raw = true;
+                    }
+                    // Telegram takes few tags, so only the text is kept
+                    renderer.text(&strip_tags(&html));
+                }
+                Event::FootnoteReference(label) => renderer.text(&format!("[{}]", label)),
+                Event::SoftBreak => renderer.text(" "),
+                Event::HardBreak => renderer.text("\n"),
+                Event::Rule => {
+                    renderer.open();
+                    renderer.text("——————————");
+                    renderer.depth -= 1;
+                    if renderer.depth == 0 {
+                        renderer.flush();
+                    }
+                }
+                Event::TaskListMarker(checked) => match checked {
+                    true => renderer.text("☑ "),
+                    false => renderer.text("☐ "),
+                },
+            }
+        }
+
+        renderer.flush();
+        renderer.blocks
+    }
+
+    /// Opens a block, separating it from the one before.
+    fn open(&mut self) {
+        if self.depth == 0 && self.raw {
+            self.flush();
+        }
+        if !self.fresh && !self.html.is_empty() {
+            match self.lists.is_empty() {
+                true => self.html.push_str("\n\n"),
+                false => self.html.push('\n'),
+            }
+        }
+        self.depth += 1;
+        self.fresh = true;
+    }
+
+    /// Closes a block, keeping it if it is at the top.
+    fn close(&mut self) {
+        self.depth -= 1;
+        if self.depth == 0 {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        let block = self.html.trim_end();
+        if !block.is_empty() {
+            self.blocks.push(block.to_string());
+        }
+        self.html.clear();
+        self.fresh = false;
+        self.raw = false;
+    }
+
+    fn tag(&mut self, tag: &str) {
+        if self.table.is_none() {
+            self.html.push_str(tag);
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        match &mut self.table {
+            Some((_, rows)) => {
+                if let Some(cell) = rows.last_mut().and_then(|r| r.last_mut()) {
+                    cell.push_str(text);
+                }
+            }
+            None => {
+                self.html.push_str(&MessageFormat::HTML.escape(text));
+                self.fresh = false;
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.open(),
+            Tag::Heading(..) => {
+                self.open();
+                self.tag("<b>");
+            }
+            Tag::BlockQuote => {
+                self.open();
+                self.tag("<blockquote>");
+                self.fresh = true;
+            }
+            Tag::CodeBlock(kind) => {
+                self.open();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .map(|l| MessageFormat::HTML.escape(l)),
+                    CodeBlockKind::Indented => None,
+                };
+                match &language {
+                    Some(l) => self.tag(&format!("<pre><code class=\"language-{}\">", l)),
+                    None => self.tag("<pre>"),
+                }
+                self.code = Some(language.is_some());
+            }
+            Tag::List(start) => {
+                self.open();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.open();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}{}. ", indent, *n - 1)
+                    }
+                    _ => format!("{}• ", indent),
+                };
+                self.text(&bullet);
+                self.fresh = true;
+            }
+            Tag::FootnoteDefinition(label) => {
+                self.open();
+                self.text(&format!("[{}] ", label));
+                self.fresh = true;
+            }
+            Tag::Table(alignments) => {
+                self.open();
+                self.table = Some((alignments, vec![]));
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some((_, rows)) = &mut self.table {
+                    rows.push(vec![]);
+                }
+            }
+            Tag::TableCell => {
+                if let Some((_, rows)) = &mut self.table {
+                    if let Some(row) = rows.last_mut() {
+                        row.push(String::new());
+                    }
+                }
+            }
+            Tag::Emphasis => self.tag("<i>"),
+            Tag::Strong => self.tag("<b>"),
+            Tag::Strikethrough => self.tag("<s>"),
+            Tag::Link(_, url, _) | Tag::Image(_, url, _) => {
+                // Telegram refuses relative links, so only their text is kept
+                let absolute = Url::parse(&url).is_ok();
+                if absolute {
+                    self.tag(&format!(
+                        "<a href=\"{}\">",
+                        MessageFormat::HTML.escape(&url)
+                    ));
+                }
+                self.links.push(absolute);
+            }
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::List(_) | Tag::Item | Tag::FootnoteDefinition(_) => {
+                if let Tag::List(_) = tag {
+                    self.lists.pop();
+                }
+                self.close();
+            }
+            Tag::Heading(..) => {
+                self.tag("</b>");
+                self.close();
+            }
+            Tag::BlockQuote => {
+                let quoted = self.html.trim_end().len();
+                self.html.truncate(quoted);
+                self.tag("</blockquote>");
+                self.close();
+            }
+            Tag::CodeBlock(_) => {
+                if self.html.ends_with('\n') {
+                    self.html.pop();
+                }
+                match self.code.take() {
+                    Some(true) => self.tag("</code></pre>"),
+                    _ => self.tag("</pre>"),
+                }
+                self.close();
+            }
+            Tag::Table(_) => {
+                if let Some((alignments, rows)) = self.table.take() {
+                    let table = render_table(&alignments, &rows);
+                    self.tag("<pre>");
+                    self.text(&table);
+                    self.tag("</pre>");
+                }
+                self.close();
+            }
+            Tag::TableHead | Tag::TableRow | Tag::TableCell => (),
+            Tag::Emphasis => self.tag("</i>"),
+            Tag::Strong => self.tag("</b>"),
+            Tag::Strikethrough => self.tag("</s>"),
+            Tag::Link(..) | Tag::Image(..) => {
+                if let Some(true) = self.links.pop() {
+                    self.tag("</a>");
+                }
+            }
+        }
+    }
+}
+
+/// Lays out `rows` as plain text in aligned columns, the first being the head.
+fn render_table(alignments: &[Alignment], rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .filter_map(|r| r.get(c))
+                .map(|v| v.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut lines = vec![];
+    for (i, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(c, &width)| {
+                let value = row.get(c).map(|v| v.as_str()).unwrap_or("");
+                let pad = width - value.chars().count();
+                match alignments.get(c) {
+                    Some(Alignment::Right) => format!("{}{}", " ".repeat(pad), value),
+                    Some(Alignment::Center) => {
+                        format!(
+                            "{}{}{}",
+                            " ".repeat(pad / 2),
+                            value,
+                            " ".repeat(pad - pad / 2)
+                        )
+                    }
+                    _ => format!("{}{}", value, " ".repeat(pad)),
+                }
+            })
+            .collect();
+        lines.push(cells.join(" | ").trim_end().to_string());
+
+        if i == 0 {
+            let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+            lines.push(rule.join("-+-"));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Removes the tags of raw HTML, keeping its text.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+/// A piece of HTML, which is either a tag or a character shown in a message.
+enum Token<'a> {
+    Tag(&'a str),
+    /// A character, or an entity such as `&amp;`, with its length.
+    Unit(&'a str, usize),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while let Some(c) = rest.chars().next() {
+        let end = match c {
+            '<' => rest.find('>').map(|i| i + 1),
+            '&' => rest.find(';').map(|i| i + 1),
+            _ => None,
+        }
+        .unwrap_or(c.len_utf8());
+        let (token, tail) = rest.split_at(end);
+        tokens.push(match c {
+            '<' => Token::Tag(token),
+            '&' => Token::Unit(token, 1),
+            _ => Token::Unit(token, c.len_utf16()),
+        });
+        rest = tail;
+    }
+    tokens
+}
+
+/// The length of `html` as shown in a message, in UTF-16 code units.
+fn visible_len(html: &str) -> usize {
+    tokenize(html)
+        .iter()
+        .map(|t| match t {
+            Token::Tag(_) => 0,
+            Token::Unit(_, len) => *len,
+        })
+        .sum()
+}
+
+/// Splits a block of HTML into pieces under `limit` characters between
+/// words if possible, closing the tags open at the end of a piece and opening
+/// them again in the next.
+fn split(html: &str, limit: usize) -> Vec<String> {
+    let tokens = tokenize(html);
+    // the words with the tags before them, each ending with a space if any
+    let mut words: Vec<&[Token]> = vec![];
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if let Token::Unit(u, _) = token {
+            if u.chars().all(char::is_whitespace) {
+                words.push(&tokens[start..=i]);
+                start = i + 1;
+            }
+        }
+    }
+    words.push(&tokens[start..]);
+
+    let mut pieces = vec![];
+    let mut piece = String::new();
+    let mut len = 0;
+    // the opening tags and the names of the open elements
+    let mut open: Vec<(&str, &str)> = vec![];
+
+    for word in words {
+        let word_len: usize = word
+            .iter()
+            .map(|t| match t {
+                Token::Tag(_) => 0,
+                Token::Unit(_, l) => *l,
+            })
+            .sum();
+        if len > 0 && len + word_len > limit {
+            pieces.push(close_piece(&mut piece, &open));
+            len = 0;
+        }
+
+        for token in word {
+            match token {
+                Token::Tag(tag) => {
+                    let name = tag
+                        .trim_start_matches("</")
+                        .trim_start_matches('<')
+                        .split(|c: char| c.is_whitespace() || c == '>')
+                        .next()
+                        .unwrap_or("");
+                    match tag.starts_with("</") {
+                        true => {
+                            if let Some(i) = open.iter().rposition(|(_, n)| *n == name) {
+                                let (opening, _) = open.remove(i);
+                                // an element opened again right before it is closed is dropped
+                                match piece.ends_with(opening) {
+                                    true => piece.truncate(piece.len() - opening.len()),
+                                    false => piece.push_str(tag),
+                                }
+                            } else {
+                                piece.push_str(tag);
+                            }
+                        }
+                        false => {
+                            open.push((tag, name));
+                            piece.push_str(tag);
+                        }
+                    }
+                }
+                Token::Unit(unit, unit_len) => {
+                    // a word longer than a piece is split anywhere
+                    if len > 0 && len + unit_len > limit {
+                        pieces.push(close_piece(&mut piece, &open));
+                        len = 0;
+                    }
+                    piece.push_str(unit);
+                    len += unit_len;
+                }
+            }
+        }
+    }
+
+    if len > 0 {
+        pieces.push(piece.trim_end().to_string());
+    }
+    pieces
+}
+
+/// Closes the elements open at the end of `piece` and takes it, leaving them
+/// opened again in its place.
+fn close_piece(piece: &mut String, open: &[(&str, &str)]) -> String {
+    let mut closed = mem::take(piece);
+    closed.truncate(closed.trim_end().len());
+    for (_, name) in open.iter().rev() {
+        closed.push_str(&format!("</{}>", name));
+    }
+    for (opening, _) in open {
+        piece.push_str(opening);
+    }
+    closed
+}
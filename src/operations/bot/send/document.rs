@@ -1,15 +1,17 @@
 use std::{convert::TryInto, path::PathBuf};
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::senddocument::SendDocumentRequestModel,
+    http::{
+        request::models::senddocument::SendDocumentRequestModel,
+        response::models::message::MessageModel, transfer_client,
+    },
     operations::{bot::BotParams, OperationError, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{
+    keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams, ThumbnailParams,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -28,12 +30,12 @@ use super::{SendOperation, SendParams};
 #[derive(Debug)]
 pub struct DocumentParams {
     pub file: PathBuf,
-    pub thumbnail: Option<PathBuf>,
+    pub thumbnail: Option<ThumbnailParams>,
     pub message: Option<String>,
 }
 
 impl DocumentParams {
-    pub fn new(file: PathBuf, thumbnail: Option<PathBuf>, message: Option<String>) -> Self {
+    pub fn new(file: PathBuf, thumbnail: Option<ThumbnailParams>, message: Option<String>) -> Self {
         Self {
             file,
             thumbnail,
@@ -60,27 +62,43 @@ impl SendOperation for SendDocumentOperation {
     fn send(self) -> Result<(), OperationError> {
         info!("📎 Sending document...");
 
-        let url = format!(
-            "{root_url}{token}/sendDocument",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token
-        );
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendDocumentRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendDocument", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendDocument");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendDocumentRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         debug!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent document.");
         }, on_failure => {
             error!("💀 An error occurred while sending the document.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendDocument", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
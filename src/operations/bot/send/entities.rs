@@ -0,0 +1,198 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::operations::{CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The length of `text` as Telegram counts it, in UTF-16 code units.
+pub fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// A range of a text with a style such as `bold`, or a link.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageEntity {
+    /// One of `bold`, `italic`, `underline`, `strikethrough`, `spoiler`,
+    /// `code`, `pre`, `text_link`, `mention` and the rest Telegram supports.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Where the entity starts in UTF-16 code units.
+    pub offset: usize,
+    /// The length of the entity in UTF-16 code units.
+    pub length: usize,
+    /// The URL of a `text_link`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The programming language of a `pre`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The rest of the entity, e.g. `user` of a `text_mention`.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl MessageEntity {
+    pub fn new(kind: &str, offset: usize, length: usize) -> Self {
+        Self {
+            kind: String::from(kind),
+            offset,
+            length,
+            url: None,
+            language: None,
+            other: Map::new(),
+        }
+    }
+}
+
+/// Fails unless every entity is within `text`.
+pub fn check_entities(text: &str, entities: &[MessageEntity]) -> Result<(), OperationError> {
+    let len = utf16_len(text);
+    match entities
+        .iter()
+        .position(|e| e.length == 0 || e.offset.checked_add(e.length).is_none_or(|end| end > len))
+    {
+        Some(i) => Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!(
+                "The entity at {} is empty or out of the text, which is {} UTF-16 code units long.",
+                i, len
+            ),
+            None::<&str>,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// A text with entities, which needs no escaping unlike MarkdownV2 or HTML.
+///
+/// ```no_run
+/// use tgcli::bot::RichText;
+///
+/// let text = RichText::new()
+///     .bold("Build #42")
+///     .text(" failed on ")
+///     .code("main")
+///     .text(".");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichText {
+    text: String,
+    entities: Vec<MessageEntity>,
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `text` as is.
+    pub fn text(mut self, text: impl fmt::Display) -> Self {
+        self.text.push_str(&text.to_string());
+        self
+    }
+
+    /// Appends `text` as `entity`, whose range is set to that of the text.
+    fn push(mut self, text: impl fmt::Display, entity: MessageEntity) -> Self {
+        let text = text.to_string();
+        let length = utf16_len(&text);
+        // Telegram refuses empty entities
+        if length > 0 {
+            self.entities.push(MessageEntity {
+                offset: utf16_len(&self.text),
+                length,
+                ..entity
+            });
+        }
+        self.text.push_str(&text);
+        self
+    }
+
+    fn styled(self, kind: &str, text: impl fmt::Display) -> Self {
+        self.push(text, MessageEntity::new(kind, 0, 0))
+    }
+
+    pub fn bold(self, text: impl fmt::Display) -> Self {
+        self.styled("bold", text)
+    }
+
+    pub fn italic(self, text: impl fmt::Display) -> Self {
+        self.styled("italic", text)
+    }
+
+    pub fn underline(self, text: impl fmt::Display) -> Self {
+        self.styled("underline", text)
+    }
+
+    pub fn strikethrough(self, text: impl fmt::Display) -> Self {
+        self.styled("strikethrough", text)
+    }
+
+    pub fn spoiler(self, text: impl fmt::Display) -> Self {
+        self.styled("spoiler", text)
+    }
+
+    /// Inline monospace text.
+    pub fn code(self, text: impl fmt::Display) -> Self {
+        self.styled("code", text)
+    }
+
+    /// A monospace block, highlighted for `language` if present.
+    pub fn pre(self, text: impl fmt::Display, language: Option<&str>) -> Self {
+        let entity = MessageEntity {
+            language: language.map(String::from),
+            ..MessageEntity::new("pre", 0, 0)
+        };
+        self.push(text, entity)
+    }
+
+    /// `text` linking to `url`.
+    pub fn text_link(self, text: impl fmt::Display, url: &str) -> Self {
+        let entity = MessageEntity {
+            url: Some(String::from(url)),
+            ..MessageEntity::new("text_link", 0, 0)
+        };
+        self.push(text, entity)
+    }
+
+    /// A mention of a user by username, e.g. `@erayerdin`.
+    pub fn mention(self, username: &str) -> Self {
+        let username = match username.starts_with('@') {
+            true => String::from(username),
+            false => format!("@{}", username),
+        };
+        self.styled("mention", username)
+    }
+
+    /// The plain text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn entities(&self) -> &[MessageEntity] {
+        &self.entities
+    }
+
+    pub fn into_parts(self) -> (String, Vec<MessageEntity>) {
+        (self.text, self.entities)
+    }
+}
+
+impl fmt::Display for RichText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
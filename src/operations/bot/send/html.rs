@@ -0,0 +1,465 @@
+use crate::operations::{CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The tags Telegram takes in HTML.
+const SUPPORTED_TAGS: &[&str] = &[
+    "b",
+    "strong",
+    "i",
+    "em",
+    "u",
+    "ins",
+    "s",
+    "strike",
+    "del",
+    "span",
+    "tg-spoiler",
+    "a",
+    "tg-emoji",
+    "code",
+    "pre",
+    "blockquote",
+];
+
+/// The tags rewritten into a supported one by `sanitize`.
+const RENAMED_TAGS: &[(&str, &str)] = &[
+    ("h1", "b"),
+    ("h2", "b"),
+    ("h3", "b"),
+    ("h4", "b"),
+    ("h5", "b"),
+    ("h6", "b"),
+    ("kbd", "code"),
+    ("samp", "code"),
+    ("tt", "code"),
+    ("cite", "i"),
+    ("var", "i"),
+];
+
+/// The named entities rewritten into a numeric one by `sanitize`.
+const RENAMED_ENTITIES: &[(&str, &str)] = &[
+    ("&nbsp;", "&#160;"),
+    ("&ndash;", "&#8211;"),
+    ("&mdash;", "&#8212;"),
+    ("&hellip;", "&#8230;"),
+    ("&apos;", "&#39;"),
+    ("&copy;", "&#169;"),
+];
+
+struct Tag<'a> {
+    /// The name in lowercase.
+    name: String,
+    closing: bool,
+    attributes: Vec<(String, Option<&'a str>)>,
+}
+
+impl Tag<'_> {
+    fn attribute(&self, name: &str) -> Option<Option<&str>> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| *v)
+    }
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Tag(Tag<'a>),
+    /// An entity such as `&amp;`.
+    Entity(&'a str),
+    /// A comment or a declaration such as `<!DOCTYPE html>`.
+    Comment,
+    /// A `<` which does not start a tag.
+    Lt,
+    /// A `&` which does not start an entity.
+    Amp,
+}
+
+/// Splits `html` into pieces with their byte offsets.
+fn parse(html: &str) -> Vec<(usize, Piece<'_>)> {
+    let mut pieces = vec![];
+    let mut offset = 0;
+
+    while offset < html.len() {
+        let rest = &html[offset..];
+        let (piece, len) = match rest.as_bytes()[0] {
+            b'<' => parse_tag(rest),
+            b'&' => parse_entity(rest),
+            _ => {
+                let len = rest.find(['<', '&']).unwrap_or(rest.len());
+                (Piece::Text(&rest[..len]), len)
+            }
+        };
+        pieces.push((offset, piece));
+        offset += len;
+    }
+    pieces
+}
+
+fn parse_tag(rest: &str) -> (Piece<'_>, usize) {
+    if rest.starts_with("<!--") {
+        return match rest.find("-->") {
+            Some(end) => (Piece::Comment, end + 3),
+            None => (Piece::Lt, 1),
+        };
+    }
+    if rest.starts_with("<!") || rest.starts_with("<?") {
+        return match rest.find('>') {
+            Some(end) => (Piece::Comment, end + 1),
+            None => (Piece::Lt, 1),
+        };
+    }
+
+    let closing = rest.starts_with("</");
+    let mut i = if closing { 2 } else { 1 };
+    let name_len = rest[i..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(rest.len() - i);
+    if name_len == 0 || !rest.as_bytes()[i].is_ascii_alphabetic() {
+        return (Piece::Lt, 1);
+    }
+    let name = rest[i..i + name_len].to_ascii_lowercase();
+    i += name_len;
+
+    let mut attributes = vec![];
+    loop {
+        i += rest[i..].len() - rest[i..].trim_start().len();
+        let tail = &rest[i..];
+        if tail.starts_with('>') {
+            i += 1;
+            break;
+        }
+        if tail.starts_with("/>") {
+            i += 2;
+            break;
+        }
+
+        let key_len = tail
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(tail.len());
+        if key_len == 0 {
+            // an unterminated tag or a stray character such as / is not a tag
+            return (Piece::Lt, 1);
+        }
+        let key = tail[..key_len].to_ascii_lowercase();
+        i += key_len;
+
+        let after = rest[i..].trim_start();
+        if !after.starts_with('=') {
+            attributes.push((key, None));
+            continue;
+        }
+        i = rest.len() - after.len() + 1;
+        i += rest[i..].len() - rest[i..].trim_start().len();
+        let tail = &rest[i..];
+        let (value, len) = match tail.chars().next() {
+            Some(q) if q == '"' || q == '\'' => match tail[1..].find(q) {
+                Some(end) => (&tail[1..end + 1], end + 2),
+                None => return (Piece::Lt, 1),
+            },
+            _ => {
+                let end = tail
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(tail.len());
+                (&tail[..end], end)
+            }
+        };
+        attributes.push((key, Some(value)));
+        i += len;
+    }
+
+    let tag = Tag {
+        name,
+        closing,
+        attributes,
+    };
+    (Piece::Tag(tag), i)
+}
+
+fn parse_entity(rest: &str) -> (Piece<'_>, usize) {
+    let end = rest
+        .char_indices()
+        .skip(1)
+        .take(32)
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '#'));
+    match end {
+        Some((i, ';')) if i > 1 => (Piece::Entity(&rest[..i + 1]), i + 1),
+        _ => (Piece::Amp, 1),
+    }
+}
+
+/// Whether Telegram takes `entity`, which is either numeric or one of
+/// `&lt;`, `&gt;`, `&amp;` and `&quot;`.
+fn is_supported_entity(entity: &str) -> bool {
+    let name = &entity[1..entity.len() - 1];
+    match name.strip_prefix('#') {
+        Some(n) => match n.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).is_ok(),
+            None => n.parse::<u32>().is_ok(),
+        },
+        None => matches!(name, "lt" | "gt" | "amp" | "quot"),
+    }
+}
+
+fn invalid(message: String) -> OperationError {
+    OperationError::new(
+        CommonExitCodes::StdInvalidValue as i32,
+        message,
+        None::<&str>,
+    )
+}
+
+/// Checks that `html` is in the subset of HTML Telegram takes, so that it is
+/// not refused after an upload. Offsets are in bytes, as Telegram reports
+/// them.
+pub fn validate(html: &str) -> Result<(), OperationError> {
+    let mut open: Vec<(String, usize)> = vec![];
+
+    for (offset, piece) in parse(html) {
+        match piece {
+            Piece::Text(_) => (),
+            Piece::Entity(e) => {
+                if !is_supported_entity(e) {
+                    return Err(invalid(format!(
+                        "Unsupported entity {} at byte offset {}.",
+                        e, offset
+                    )));
+                }
+            }
+            Piece::Comment => {
+                return Err(invalid(format!(
+                    "Unsupported comment or declaration at byte offset {}.",
+                    offset
+                )))
+            }
+            Piece::Lt => {
+                return Err(invalid(format!(
+                    "Unescaped < at byte offset {}, which must be written as &lt;.",
+                    offset
+                )))
+            }
+            Piece::Amp => {
+                return Err(invalid(format!(
+                    "Unescaped & at byte offset {}, which must be written as &amp;.",
+                    offset
+                )))
+            }
+            Piece::Tag(tag) => {
+                if !SUPPORTED_TAGS.contains(&tag.name.as_str()) {
+                    let slash = if tag.closing { "/" } else { "" };
+                    return Err(invalid(format!(
+                        "Unsupported tag <{}{}> at byte offset {}.",
+                        slash, tag.name, offset
+                    )));
+                }
+
+                if tag.closing {
+                    match open.pop() {
+                        Some((name, _)) if name == tag.name => (),
+                        _ => {
+                            return Err(invalid(format!(
+                                "Unexpected end tag </{}> at byte offset {}.",
+                                tag.name, offset
+                            )))
+                        }
+                    }
+                    continue;
+                }
+
+                if tag.name == "span" && !is_spoiler(&tag) {
+                    return Err(invalid(format!(
+                        "Tag <span> at byte offset {} must have class \"tg-spoiler\".",
+                        offset
+                    )));
+                }
+                open.push((tag.name, offset));
+            }
+        }
+    }
+
+    match open.pop() {
+        Some((name, offset)) => Err(invalid(format!(
+            "Unclosed tag <{}> at byte offset {}.",
+            name, offset
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn is_spoiler(tag: &Tag) -> bool {
+    match tag.attribute("class") {
+        Some(Some(class)) => class.split_whitespace().any(|c| c == "tg-spoiler"),
+        _ => false,
+    }
+}
+
+/// The opening tag `tag` is rewritten into, keeping the attributes Telegram
+/// takes, or none if it is dropped.
+fn opening(name: &str, tag: &Tag) -> Option<String> {
+    let attribute = |key: &str| match tag.attribute(key) {
+        Some(Some(v)) => Some(v.replace('"', "&quot;")),
+        _ => None,
+    };
+
+    match name {
+        "a" => match attribute("href") {
+            Some(href) => Some(format!("<a href=\"{}\">", href)),
+            None => Some(String::from("<a>")),
+        },
+        "code" => match attribute("class").filter(|c| c.starts_with("language-")) {
+            Some(class) => Some(format!("<code class=\"{}\">", class)),
+            None => Some(String::from("<code>")),
+        },
+        "span" => match is_spoiler(tag) {
+            true => Some(String::from("<span class=\"tg-spoiler\">")),
+            false => None,
+        },
+        "tg-emoji" => attribute("emoji-id").map(|id| format!("<tg-emoji emoji-id=\"{}\">", id)),
+        "blockquote" => match tag.attribute("expandable") {
+            Some(_) => Some(String::from("<blockquote expandable>")),
+            None => Some(String::from("<blockquote>")),
+        },
+        _ => Some(format!("<{}>", name)),
+    }
+}
+
+/// Ends the line at the end of `html` so that it ends with `count` line
+/// breaks, unless it is empty.
+fn line_break(html: &mut String, count: usize) {
+    html.truncate(html.trim_end_matches([' ', '\t']).len());
+    if html.is_empty() {
+        return;
+    }
+    let breaks = html.len() - html.trim_end_matches('\n').len();
+    for _ in breaks..count {
+        html.push('\n');
+    }
+}
+
+/// Rewrites `html` into the subset Telegram takes. Headings become bold,
+/// paragraphs, line breaks and list items become lines, other unsupported
+/// tags are stripped and unbalanced tags are closed.
+pub fn sanitize(html: &str) -> String {
+    let mut sanitized = String::with_capacity(html.len());
+    // the names of the open elements with their opening tags, or none if
+    // they are stripped
+    let mut open: Vec<(String, Option<String>)> = vec![];
+    // whether a line was just broken, so that the whitespace after is dropped
+    let mut broken = false;
+
+    for (_, piece) in parse(html) {
+        let in_pre = open.iter().any(|(n, _)| n == "pre");
+        match piece {
+            Piece::Text(text) => {
+                let text = match broken && !in_pre {
+                    true => text.trim_start(),
+                    false => text,
+                };
+                if !text.is_empty() {
+                    sanitized.push_str(text);
+                    broken = false;
+                }
+            }
+            Piece::Entity(e) => {
+                match RENAMED_ENTITIES.iter().find(|(n, _)| *n == e) {
+                    Some((_, numeric)) => sanitized.push_str(numeric),
+                    None if is_supported_entity(e) => sanitized.push_str(e),
+                    None => {
+                        sanitized.push_str("&amp;");
+                        sanitized.push_str(&e[1..]);
+                    }
+                }
+                broken = false;
+            }
+            Piece::Comment => (),
+            Piece::Lt => {
+                sanitized.push_str("&lt;");
+                broken = false;
+            }
+            Piece::Amp => {
+                sanitized.push_str("&amp;");
+                broken = false;
+            }
+            Piece::Tag(tag) => {
+                let name = RENAMED_TAGS
+                    .iter()
+                    .find(|(n, _)| *n == tag.name)
+                    .map(|(_, r)| *r)
+                    .unwrap_or(&tag.name)
+                    .to_string();
+
+                if tag.closing {
+                    if let Some(i) = open.iter().rposition(|(n, _)| *n == name) {
+                        close(&mut sanitized, &mut open, i);
+                    }
+                } else if tag.name == "li" {
+                    line_break(&mut sanitized, 1);
+                    sanitized.push_str("• ");
+                } else if SUPPORTED_TAGS.contains(&name.as_str()) {
+                    let opening = opening(&name, &tag);
+                    if let Some(o) = &opening {
+                        sanitized.push_str(o);
+                    }
+                    open.push((name, opening));
+                }
+
+                let breaks = match tag.name.as_str() {
+                    "p" | "ul" | "ol" | "table" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6"
+                        if tag.closing =>
+                    {
+                        2
+                    }
+                    "br" | "hr" => 1,
+                    "div" | "tr" | "li" if tag.closing => 1,
+                    _ => 0,
+                };
+                if breaks > 0 {
+                    line_break(&mut sanitized, breaks);
+                    broken = true;
+                }
+            }
+        }
+    }
+
+    for (name, opening) in open.iter().rev() {
+        if opening.is_some() {
+            sanitized.push_str(&format!("</{}>", name));
+        }
+    }
+    sanitized.trim().to_string()
+}
+
+/// Closes the element at `index` of `open`, closing the elements in it first
+/// and opening them again after.
+fn close(html: &mut String, open: &mut Vec<(String, Option<String>)>, index: usize) {
+    let inner = open.split_off(index + 1);
+    for (name, opening) in inner.iter().rev() {
+        if opening.is_some() {
+            html.push_str(&format!("</{}>", name));
+        }
+    }
+
+    if let Some((name, Some(_))) = open.pop() {
+        html.push_str(&format!("</{}>", name));
+    }
+
+    for (name, opening) in inner {
+        if let Some(o) = &opening {
+            html.push_str(o);
+        }
+        open.push((name, opening));
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::BTreeMap,
+    convert::TryInto,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Duration, FixedOffset, Local};
+
+use crate::{
+    handle_response,
+    http::{
+        request::models::editmessage::{EditContent, EditMessageRequestModel},
+        response::models::message::MessageModel,
+        transfer_client,
+    },
+    operations::{bot::BotParams, CommonExitCodes, OperationError, TelegramErrorKind},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The file in the state directory with the messages sent by idempotency key.
+const IDEMPOTENCY_FILE: &str = "idempotency.json";
+/// The file in the state directory with the sticky messages by name.
+const STICKY_FILE: &str = "sticky.json";
+
+/// A message sent with an idempotency key.
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    /// The id of the bot that sent the message.
+    bot: String,
+    /// When the key can be used to send another message. Never if absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires: Option<DateTime<FixedOffset>>,
+    message: MessageModel,
+}
+
+impl IdempotencyRecord {
+    fn is_expired(&self) -> bool {
+        match self.expires {
+            Some(e) => e <= Local::now(),
+            None => false,
+        }
+    }
+}
+
+/// The messages sent by their keys.
+type IdempotencyStore = BTreeMap<String, IdempotencyRecord>;
+
+#[derive(Debug, Clone)]
+pub struct IdempotencyParams {
+    pub key: String,
+    /// How long a repeat with the same key is not sent again. Forever if
+    /// absent.
+    pub ttl: Option<Duration>,
+    /// Whether a repeat edits the message sent before.
+    pub upsert: bool,
+    /// The directory the store is kept in.
+    pub state: PathBuf,
+    /// The file of the store in the state directory.
+    store: &'static str,
+}
+
+impl IdempotencyParams {
+    pub fn new(key: &str, ttl: Duration, upsert: bool, state: PathBuf) -> Self {
+        Self {
+            key: String::from(key),
+            ttl: Some(ttl),
+            upsert,
+            state,
+            store: IDEMPOTENCY_FILE,
+        }
+    }
+
+    /// A message named `name` that is sent once to `chat` and edited
+    /// afterwards. The same name is another message in another chat.
+    pub fn sticky(chat: &str, name: &str, state: PathBuf) -> Self {
+        Self {
+            key: format!("{}:{}", chat, name),
+            ttl: None,
+            upsert: true,
+            state,
+            store: STICKY_FILE,
+        }
+    }
+
+    fn store_path(&self) -> PathBuf {
+        self.state.join(self.store)
+    }
+
+    /// Prints the message sent before with the same key, or edits it to have
+    /// `content` if upserting, in which case it returns true. A message that
+    /// was deleted is sent again.
+    pub fn replay(
+        &self,
+        bot_params: &BotParams,
+        content: Option<EditContent>,
+    ) -> Result<bool, OperationError> {
+        let content = match (self.upsert, content) {
+            (true, None) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdInvalidValue as i32,
+                    "`--upsert` can only edit a message or the caption of a file.",
+                    None::<&str>,
+                ))
+            }
+            (_, c) => c,
+        };
+
+        let mut store = read_store(&self.store_path())?;
+        let record = match store.get_mut(&self.key) {
+            Some(r) if r.bot == bot_params.id() && !r.is_expired() => r,
+            _ => return Ok(false),
+        };
+
+        let content = match content {
+            Some(c) => c,
+            None => {
+                info!("📦 The message was already sent with key {}.", self.key);
+                println!("{}", serde_json::to_string(&record.message).unwrap());
+                return Ok(true);
+            }
+        };
+
+        info!("✏️ Editing the message sent with key {}...", self.key);
+        let sent = match edit_message(bot_params, &record.message, content) {
+            Ok(m) => {
+                println!("{}", serde_json::to_string(&m).unwrap());
+                record.message = m;
+                true
+            }
+            Err(e) if e.telegram_kind() == Some(TelegramErrorKind::MessageNotFound) => {
+                warn!("The message was deleted. Sending a new one...");
+                store.remove(&self.key);
+                false
+            }
+            Err(e) => return Err(e),
+        };
+
+        match write_store(&self.store_path(), &store) {
+            Ok(_) => Ok(sent),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Keeps the message sent with the key until the key expires and prints it.
+    /// Nothing is kept for a message that was not sent, e.g. kept in the
+    /// outbox.
+    pub fn record(
+        &self,
+        bot_params: &BotParams,
+        message: Option<MessageModel>,
+    ) -> Result<(), OperationError> {
+        let message = match message {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+        println!("{}", serde_json::to_string(&message).unwrap());
+
+        let mut store = read_store(&self.store_path())?;
+        store.retain(|_, r| !r.is_expired());
+        store.insert(
+            self.key.clone(),
+            IdempotencyRecord {
+                bot: String::from(bot_params.id()),
+                expires: self.ttl.map(|t| (Local::now() + t).into()),
+                message,
+            },
+        );
+
+        write_store(&self.store_path(), &store)
+    }
+}
+
+/// Edits `message` to have `content` and returns the edited message.
+fn edit_message(
+    bot_params: &BotParams,
+    message: &MessageModel,
+    content: EditContent,
+) -> Result<MessageModel, OperationError> {
+    let req_instance = EditMessageRequestModel::new(message.chat.id, message.message_id, content);
+    let url = bot_params.method_url(req_instance.method());
+    trace!("url: {}", url);
+
+    let req_body = req_instance.try_into()?;
+    trace!("request body: {:?}", req_body);
+
+    let client = transfer_client()?;
+    let response = client.post(url).multipart(req_body).send();
+
+    let result = handle_response!(response => MessageModel, on_success => {
+        info!("📦 Successfully edited the message.");
+    }, on_failure => {
+        error!("☠️ An error occured while editing the message.");
+    });
+
+    match result {
+        // Telegram refuses to edit a message to what it already has
+        Err(e)
+            if e.telegram()
+                .is_some_and(|t| t.description.contains("message is not modified")) =>
+        {
+            Ok(message.clone())
+        }
+        r => r,
+    }
+}
+
+fn read_store(path: &Path) -> Result<IdempotencyStore, OperationError> {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        // nothing has been sent with a key yet
+        Err(_) if !path.exists() => return Ok(IdempotencyStore::new()),
+        Err(e) => {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!(
+                    "Could not read the idempotency store.\nPath: {}",
+                    path.display()
+                ),
+                Some(e),
+            ))
+        }
+    };
+
+    match serde_json::from_str(&content) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::SerdeDeserializationError as i32,
+            format!(
+                "The idempotency store is not valid.\nPath: {}",
+                path.display()
+            ),
+            Some(e),
+        )),
+    }
+}
+
+fn write_store(path: &Path, store: &IdempotencyStore) -> Result<(), OperationError> {
+    let temp_path = path.with_extension("json.tmp");
+    let content = serde_json::to_string_pretty(store).unwrap();
+    let result = fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(&temp_path, content))
+        .and_then(|_| fs::rename(&temp_path, path));
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdFsWriteError as i32,
+            format!(
+                "Could not write the idempotency store.\nPath: {}",
+                path.display()
+            ),
+            Some(e),
+        )),
+    }
+}
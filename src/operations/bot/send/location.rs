@@ -1,15 +1,15 @@
 use std::convert::TryInto;
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendlocation::SendLocationRequestModel,
+    http::{
+        request::models::sendlocation::SendLocationRequestModel,
+        response::models::message::MessageModel, transfer_client,
+    },
     operations::{bot::BotParams, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams};
 
 // Copyright 2021 Eray Erdin
 //
@@ -29,13 +29,34 @@ use super::{SendOperation, SendParams};
 pub struct LocationParams {
     pub latitude: f32,
     pub longitude: f32,
+    /// Period in seconds for which the location will be updated.
+    /// The location is static if absent.
+    pub live_period: Option<usize>,
+    /// The direction in which the user is moving, in degrees.
+    pub heading: Option<usize>,
+    /// The radius of uncertainty for the location, in meters.
+    pub horizontal_accuracy: Option<f32>,
+    /// The maximum distance in meters for proximity alerts about
+    /// approaching another chat member.
+    pub proximity_alert_radius: Option<usize>,
 }
 
 impl LocationParams {
-    pub fn new(latitude: f32, longitude: f32) -> Self {
+    pub fn new(
+        latitude: f32,
+        longitude: f32,
+        live_period: Option<usize>,
+        heading: Option<usize>,
+        horizontal_accuracy: Option<f32>,
+        proximity_alert_radius: Option<usize>,
+    ) -> Self {
         Self {
             latitude,
             longitude,
+            live_period,
+            heading,
+            horizontal_accuracy,
+            proximity_alert_radius,
         }
     }
 }
@@ -58,27 +79,41 @@ impl SendOperation for SendLocationOperation {
     fn send(self) -> Result<(), crate::operations::OperationError> {
         info!("🌍 Sending location...");
 
-        let url = format!(
-            "{root_url}{token}/sendLocation",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token,
-        );
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendLocationRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendLocation", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendLocation");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendLocationRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, None) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         trace!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent location.");
         }, on_failure => {
             error!("☠️ An error occured while sending the location.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendLocation", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
@@ -1,15 +1,18 @@
 use std::convert::TryInto;
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendmessage::SendMessageRequestModel,
-    operations::{bot::BotParams, OperationError, RootParams},
-    API_ROOT_URL,
+    http::{
+        request::models::sendmessage::SendMessageRequestModel,
+        response::models::message::MessageModel, transfer_client,
+    },
+    operations::{bot::BotParams, CommonExitCodes, OperationError, RootParams},
 };
 
-use super::{SendOperation, SendParams};
+use super::{
+    commonmark, entities::MessageEntity, keep_in_outbox, print_dry_run, record, replay,
+    MessageFormat, SendOperation, SendParams,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -28,12 +31,16 @@ use super::{SendOperation, SendParams};
 #[derive(Debug)]
 pub struct MessageParams {
     pub message: String,
+    /// The styles and links of the message, which is sent without a format if
+    /// present.
+    pub entities: Option<Vec<MessageEntity>>,
 }
 
 impl MessageParams {
     pub fn new(message: String) -> Self {
         Self {
             message: message.to_string(),
+            entities: None,
         }
     }
 }
@@ -49,35 +56,96 @@ impl SendMessageOperation {
     pub fn new(params: SendMessageParams) -> Self {
         Self { params }
     }
+
+    /// Sends each part of a message split over the limit as a message of its
+    /// own in the format of the operation, replying with the first.
+    pub(super) fn send_parts(mut self, mut parts: Vec<String>) -> Result<(), OperationError> {
+        if parts.len() <= 1 {
+            self.params.3.message = parts.pop().unwrap_or_default();
+            return self.send();
+        }
+
+        if self.params.2.idempotency.is_some() {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                format!(
+                    "The message is split into {} parts, which cannot be sent with an idempotency key or as a sticky message.",
+                    parts.len()
+                ),
+                None::<&str>,
+            ));
+        }
+
+        let (root_params, bot_params, send_params, _) = self.params;
+        let mut reply_to = send_params.reply_to;
+        for part in parts {
+            let mut part_params = SendParams::new(
+                &send_params.receiver,
+                send_params.format,
+                send_params.silent,
+            );
+            part_params.reply_to = reply_to.take();
+            part_params.outbox = send_params.outbox.clone();
+
+            let operation = SendMessageOperation::new((
+                root_params.clone(),
+                bot_params.clone(),
+                part_params,
+                MessageParams::new(part),
+            ));
+            operation.send()?
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl SendOperation for SendMessageOperation {
-    fn send(self) -> Result<(), OperationError> {
+    fn send(mut self) -> Result<(), OperationError> {
         info!("✏️ Sending message...");
 
-        let url = format!(
-            "{root_url}{token}/sendMessage",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token,
-        );
+        if let (MessageFormat::CommonMark, None) = (self.params.2.format, &self.params.3.entities) {
+            let parts =
+                commonmark::to_html_parts(&self.params.3.message, commonmark::MESSAGE_LIMIT);
+            self.params.2.format = MessageFormat::HTML;
+            return self.send_parts(parts);
+        }
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendMessageRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendMessage", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendMessage");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendMessageRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         trace!("request body: {:?}", req_body);
 
-        // TODO set up client earlier on bot params
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent message.");
         }, on_failure => {
             error!("☠️ An error occured while sending the message.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendMessage", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
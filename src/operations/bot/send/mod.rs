@@ -1,4 +1,19 @@
-use crate::operations::OperationError;
+use std::{convert::TryFrom, path::PathBuf};
+
+use serde_json::json;
+
+use self::idempotency::IdempotencyParams;
+use crate::{
+    http::{
+        request::{
+            dryrun::{DryRunForm, DryRunRequest},
+            models::editmessage::EditContent,
+            outbox::{OutboxEntry, OutboxForm},
+        },
+        response::models::message::MessageModel,
+    },
+    operations::{bot::BotParams, DryRunParams, EmitFormat, OperationError},
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -14,18 +29,68 @@ use crate::operations::OperationError;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod animation;
 pub mod audio;
+pub mod code;
+pub mod commonmark;
 pub mod document;
+pub mod entities;
+pub mod html;
+pub mod idempotency;
 pub mod location;
 pub mod message;
 pub mod photo;
 pub mod poll;
+pub mod template;
 pub mod video;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum MessageFormat {
     Markdown,
     HTML,
+    /// CommonMark, which is converted into HTML.
+    CommonMark,
+}
+
+impl MessageFormat {
+    /// Escapes `text` so that it is shown as is in a message of this format.
+    pub fn escape(&self, text: &str) -> String {
+        match self {
+            MessageFormat::Markdown => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if "_*[]()~`>#+-=|{}.!\\".contains(c) {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+            MessageFormat::HTML => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;"),
+            MessageFormat::CommonMark => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if c.is_ascii_punctuation() {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+        }
+    }
+
+    /// Converts `text` of this format into what Telegram takes.
+    pub fn convert(&self, text: String) -> String {
+        match self {
+            MessageFormat::CommonMark => commonmark::to_html(&text),
+            _ => text,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -33,6 +98,13 @@ pub struct SendParams {
     pub receiver: String,
     pub format: MessageFormat,
     pub silent: bool,
+    /// The id of the message to reply to.
+    pub reply_to: Option<usize>,
+    /// The outbox a request that fails for a reason that may go away is
+    /// kept in, if any.
+    pub outbox: Option<PathBuf>,
+    /// The key a message is sent once with, if any.
+    pub idempotency: Option<IdempotencyParams>,
 }
 
 impl SendParams {
@@ -41,11 +113,105 @@ impl SendParams {
             receiver: String::from(receiver),
             format,
             silent,
+            reply_to: None,
+            outbox: None,
+            idempotency: None,
         }
     }
 }
 
+#[derive(Debug)]
+pub struct ThumbnailParams {
+    pub file: PathBuf,
+    /// Whether the thumbnail should be fitted into the limits of Telegram.
+    pub fit: bool,
+}
+
+impl ThumbnailParams {
+    pub fn new(file: PathBuf, fit: bool) -> Self {
+        Self { file, fit }
+    }
+}
+
 #[async_trait]
 pub trait SendOperation {
     fn send(self) -> Result<(), OperationError>;
 }
+
+/// Prints the request `method` would be sent with instead of sending it.
+fn print_dry_run<M>(
+    dry_run: DryRunParams,
+    bot_params: &BotParams,
+    method: &str,
+    model: M,
+) -> Result<(), OperationError>
+where
+    DryRunForm: TryFrom<M, Error = OperationError>,
+{
+    let form = DryRunForm::try_from(model)?;
+
+    let url = match dry_run.show_token {
+        true => bot_params.method_url(method),
+        false => bot_params.redacted_method_url(method),
+    };
+    let request = DryRunRequest::new(url, form);
+
+    match dry_run.emit {
+        EmitFormat::Json => println!("{}", serde_json::to_string_pretty(&request).unwrap()),
+        EmitFormat::Curl => println!("{}", request.to_curl()),
+        EmitFormat::Http => print!("{}", request.to_http()),
+    }
+    Ok(())
+}
+
+/// Keeps the request of a send that failed for a reason that may go away,
+/// such as a connection error, in `outbox` to be flushed later. `model` is
+/// only present with an outbox. The result is absent if the request was kept.
+fn keep_in_outbox<M, T>(
+    outbox: Option<PathBuf>,
+    method: &str,
+    model: Option<M>,
+    result: Result<T, OperationError>,
+) -> Result<Option<T>, OperationError>
+where
+    OutboxForm: TryFrom<M, Error = OperationError>,
+{
+    let (outbox, model, error) = match (outbox, model, result) {
+        (_, _, Ok(v)) => return Ok(Some(v)),
+        (Some(o), Some(m), Err(e)) if e.is_transient() => (o, m, e),
+        (_, _, Err(e)) => return Err(e),
+    };
+
+    let form = OutboxForm::try_from(model)?;
+    let id = OutboxEntry::keep(&outbox, method, form, &error)?;
+
+    warn!("📮 Kept in the outbox as {} to be sent later.", id);
+    println!("{}", json!({ "outbox": id, "error": error.to_string() }));
+    Ok(None)
+}
+
+/// Prints the message sent before with the key of `idempotency` or edits it to
+/// have `content`, in which case it returns true.
+fn replay(
+    bot_params: &BotParams,
+    idempotency: &Option<IdempotencyParams>,
+    content: Option<EditContent>,
+) -> Result<bool, OperationError> {
+    match idempotency {
+        Some(i) => i.replay(bot_params, content),
+        None => Ok(false),
+    }
+}
+
+/// Keeps the message that was sent with the key of `idempotency`, if any.
+fn record(
+    bot_params: &BotParams,
+    idempotency: Option<IdempotencyParams>,
+    message: Result<Option<MessageModel>, OperationError>,
+) -> Result<(), OperationError> {
+    match (idempotency, message) {
+        (Some(i), Ok(m)) => i.record(bot_params, m),
+        (_, Ok(_)) => Ok(()),
+        (_, Err(e)) => Err(e),
+    }
+}
@@ -1,15 +1,15 @@
 use std::{convert::TryInto, path::PathBuf};
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendphoto::SendPhotoRequestModel,
+    http::{
+        request::models::sendphoto::SendPhotoRequestModel, response::models::message::MessageModel,
+        transfer_client,
+    },
     operations::{bot::BotParams, OperationError, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams};
 
 // Copyright 2021 Eray Erdin
 //
@@ -55,27 +55,43 @@ impl SendOperation for SendPhotoOperation {
     fn send(self) -> Result<(), OperationError> {
         info!("📷 Sending photo...");
 
-        let url = format!(
-            "{root_url}{token}/sendPhoto",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token
-        );
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendPhotoRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendPhoto", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendPhoto");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendPhotoRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         debug!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent photo.");
         }, on_failure => {
             error!("☠️ An error occurred while sending the photo.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendPhoto", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
@@ -1,15 +1,15 @@
 use std::convert::TryInto;
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendpoll::SendPollRequestModel,
+    http::{
+        request::models::sendpoll::SendPollRequestModel, response::models::message::MessageModel,
+        transfer_client,
+    },
     operations::{bot::BotParams, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams};
 
 // Copyright 2021 Eray Erdin
 //
@@ -55,27 +55,41 @@ impl SendOperation for SendPollOperation {
     fn send(self) -> Result<(), crate::operations::OperationError> {
         info!("🗯️ Sending poll...");
 
-        let url = format!(
-            "{root_url}{token}/sendPoll",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token,
-        );
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendPollRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendPoll", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendPoll");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendPollRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, None) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         trace!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent poll.");
         }, on_failure => {
             error!("☠️ An error occured while sending the poll.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendPoll", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
@@ -0,0 +1,89 @@
+use std::{fs, path::PathBuf};
+
+use minijinja::{Environment, UndefinedBehavior};
+use serde_json::{Map, Value};
+
+use crate::operations::{CommonExitCodes, OperationError};
+
+use super::MessageFormat;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A template rendered into the text of a message or the caption of a file.
+#[derive(Debug)]
+pub struct TemplateParams {
+    pub file: PathBuf,
+    /// The variables of the template. Environment variables are under `env`.
+    pub vars: Map<String, Value>,
+}
+
+impl TemplateParams {
+    pub fn new(file: PathBuf, vars: Map<String, Value>) -> Self {
+        Self { file, vars }
+    }
+
+    /// Renders the template, escaping interpolated values for `format` unless
+    /// they are marked with `safe` filter.
+    pub fn render(&self, format: MessageFormat) -> Result<String, OperationError> {
+        debug!("Rendering template {}...", self.file.to_string_lossy());
+
+        let source = match fs::read_to_string(&self.file) {
+            Ok(s) => s,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!(
+                        "Could not read the template.\nPath: {}",
+                        self.file.to_string_lossy()
+                    ),
+                    Some(e),
+                ))
+            }
+        };
+
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        env.set_formatter(move |out, _state, value| {
+            let text = value.to_string();
+            let result = match value.is_safe() {
+                true => out.write_str(&text),
+                false => out.write_str(&format.escape(&text)),
+            };
+            result.map_err(minijinja::Error::from)
+        });
+
+        let mut context = self.vars.clone();
+        context.entry("env").or_insert_with(|| {
+            Value::Object(
+                std::env::vars()
+                    .map(|(k, v)| (k, Value::String(v)))
+                    .collect(),
+            )
+        });
+
+        let name = self.file.to_string_lossy();
+        match env.render_named_str(&name, &source, &context) {
+            Ok(text) => {
+                trace!("rendered template: {}", text);
+                Ok(text)
+            }
+            Err(e) => Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                format!("Could not render the template: {}", e),
+                Some(e),
+            )),
+        }
+    }
+}
@@ -1,15 +1,17 @@
 use std::{convert::TryInto, path::PathBuf};
 
-use reqwest::blocking::Client;
-
 use crate::{
     handle_response,
-    http::request::models::sendvideo::SendVideoRequestModel,
+    http::{
+        request::models::sendvideo::SendVideoRequestModel, response::models::message::MessageModel,
+        transfer_client,
+    },
     operations::{bot::BotParams, RootParams},
-    API_ROOT_URL,
 };
 
-use super::{SendOperation, SendParams};
+use super::{
+    keep_in_outbox, print_dry_run, record, replay, SendOperation, SendParams, ThumbnailParams,
+};
 
 // Copyright 2021 Eray Erdin
 //
@@ -29,22 +31,32 @@ use super::{SendOperation, SendParams};
 pub struct VideoParams {
     pub file: PathBuf,
     pub message: Option<String>,
-    pub horizontal: Option<usize>,
-    pub vertical: Option<usize>,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+    /// Duration of the video in seconds.
+    pub duration: Option<usize>,
+    pub supports_streaming: bool,
+    pub thumbnail: Option<ThumbnailParams>,
 }
 
 impl VideoParams {
     pub fn new(
         file: PathBuf,
         message: Option<String>,
-        horizontal: Option<usize>,
-        vertical: Option<usize>,
+        width: Option<usize>,
+        height: Option<usize>,
+        duration: Option<usize>,
+        supports_streaming: bool,
+        thumbnail: Option<ThumbnailParams>,
     ) -> Self {
         Self {
             file,
             message,
-            horizontal,
-            vertical,
+            width,
+            height,
+            duration,
+            supports_streaming,
+            thumbnail,
         }
     }
 }
@@ -67,27 +79,43 @@ impl SendOperation for SendVideoOperation {
     fn send(self) -> Result<(), crate::operations::OperationError> {
         info!("🎥 Sending video...");
 
-        let url = format!(
-            "{root_url}{token}/sendVideo",
-            root_url = API_ROOT_URL,
-            token = self.params.1.token
-        );
+        self.params.1.check_upload(&self.params.3.file)?;
+
+        if let Some(dry_run) = self.params.0.dry_run {
+            let bot_params = self.params.1.clone();
+            let req_instance: SendVideoRequestModel = self.params.into();
+            return print_dry_run(dry_run, &bot_params, "sendVideo", req_instance);
+        }
+
+        let url = self.params.1.method_url("sendVideo");
         trace!("url: {}", url);
 
+        let outbox = self.params.2.outbox.clone();
+        let idempotency = self.params.2.idempotency.clone();
+        let bot_params = self.params.1.clone();
         let req_instance: SendVideoRequestModel = self.params.into();
+        match replay(&bot_params, &idempotency, Some(req_instance.edit_content())) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+        let kept = outbox.as_ref().map(|_| req_instance.clone());
         let req_body = match req_instance.try_into() {
             Ok(f) => f,
             Err(e) => return Err(e),
         };
         debug!("request body: {:?}", req_body);
 
-        let client = Client::new();
+        let client = transfer_client()?;
         let response = client.post(url).multipart(req_body).send();
 
-        handle_response!(response, on_success => {
+        let result = handle_response!(response => MessageModel, on_success => {
             info!("📦 Successfully sent video.");
         }, on_failure => {
             error!("☠️ An error occurred while sending the video.");
-        })
+        });
+
+        let message = keep_in_outbox(outbox, "sendVideo", kept, result);
+        record(&bot_params, idempotency, message)
     }
 }
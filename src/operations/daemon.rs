@@ -0,0 +1,199 @@
+use std::{env, fs, path::PathBuf, thread, time::Duration};
+
+use chrono::Local;
+use serde_json::json;
+
+use crate::operations::{
+    bot::send::SendOperation,
+    schedule::{next_occurrence, SpoolEntry},
+    CommonExitCodes, OperationError,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Parses and runs the command line arguments of an operation.
+pub type RunArgs = fn(&[String]) -> Result<(), OperationError>;
+
+/// How often the spool directory is read for new entries at most.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub struct DaemonParams {
+    pub spool: PathBuf,
+    /// Whether the daemon exits after running the entries that are due.
+    pub once: bool,
+    /// The arguments added to every entry, e.g. the token.
+    pub extra_args: Vec<String>,
+    pub run: RunArgs,
+}
+
+impl DaemonParams {
+    pub fn new(spool: PathBuf, once: bool, extra_args: Vec<String>, run: RunArgs) -> Self {
+        Self {
+            spool,
+            once,
+            extra_args,
+            run,
+        }
+    }
+
+    /// The entries in the spool directory by their ids, in the order they
+    /// were queued.
+    fn entries(&self) -> Result<Vec<(String, SpoolEntry)>, OperationError> {
+        let dir = match fs::read_dir(&self.spool) {
+            Ok(d) => d,
+            // nothing has been queued yet
+            Err(_) if !self.spool.exists() => return Ok(vec![]),
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!(
+                        "Could not read the spool directory.\nPath: {}",
+                        self.spool.to_string_lossy()
+                    ),
+                    Some(e),
+                ))
+            }
+        };
+
+        let mut paths: Vec<PathBuf> = dir
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "json"))
+            .collect();
+        paths.sort();
+
+        let mut entries = Vec::new();
+        for path in paths {
+            let id = path.file_stem().unwrap().to_string_lossy().to_string();
+            match SpoolEntry::read(&path) {
+                Ok(e) => entries.push((id, e)),
+                Err(e) => warn!("Skipping {}: {}", id, e),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// The arguments of `entry` with `extra_args`, which come before positional
+    /// arguments after `--`.
+    fn args(&self, entry: &SpoolEntry) -> Vec<String> {
+        let mut args = entry.args.clone();
+        let index = args.iter().position(|a| a == "--").unwrap_or(args.len());
+        args.splice(index..index, self.extra_args.iter().cloned());
+        args
+    }
+
+    /// Runs a due entry, rescheduling it if it recurs and removing it otherwise.
+    fn run_entry(&self, id: &str, entry: SpoolEntry) -> Result<(), OperationError> {
+        let path = self.spool.join(format!("{}.json", id));
+        match &entry.cron {
+            Some(cron) => {
+                let at = next_occurrence(cron, &Local::now())?;
+                let next = SpoolEntry::new(
+                    entry.args.clone(),
+                    entry.dir.clone(),
+                    at.into(),
+                    entry.cron.clone(),
+                );
+                next.write(&path)?;
+                debug!("{} runs next at {}.", id, at.to_rfc3339());
+            }
+            // another daemon has already claimed it if it is gone
+            None => {
+                if fs::remove_file(&path).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        info!("⏰ Running {}...", id);
+        if let Err(e) = env::set_current_dir(&entry.dir) {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsReadError as i32,
+                format!(
+                    "Could not change to the working directory of the operation.\nPath: {}",
+                    entry.dir.to_string_lossy()
+                ),
+                Some(e),
+            ));
+        }
+        (self.run)(&self.args(&entry))
+    }
+}
+
+pub type DaemonOperationParams = DaemonParams;
+
+#[derive(Debug)]
+pub struct DaemonOperation {
+    params: DaemonOperationParams,
+}
+
+impl DaemonOperation {
+    pub fn new(params: DaemonOperationParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for DaemonOperation {
+    fn send(self) -> Result<(), OperationError> {
+        let params = self.params;
+        info!("👂 Reading {}...", params.spool.to_string_lossy());
+        let mut last_error = None;
+
+        loop {
+            let entries = params.entries()?;
+
+            let now = Local::now();
+            let mut next = None;
+            for (id, entry) in entries {
+                if entry.at > now {
+                    next = Some(next.map_or(entry.at, |n| entry.at.min(n)));
+                    continue;
+                }
+
+                match params.run_entry(&id, entry) {
+                    Ok(_) => println!("{}", json!({ "id": id, "ok": true })),
+                    Err(e) => {
+                        error!("☠️ {}: {}", id, e);
+                        println!(
+                            "{}",
+                            json!({
+                                "id": id,
+                                "ok": false,
+                                "exit_code": e.exit_code(),
+                                "error": e.to_string(),
+                            })
+                        );
+                        last_error = Some(e);
+                    }
+                }
+            }
+
+            if params.once {
+                return match last_error {
+                    Some(e) => Err(e),
+                    None => Ok(()),
+                };
+            }
+
+            // a recurring entry may have been rescheduled, so it is read again anyway
+            let wait = next
+                .and_then(|n| (n.with_timezone(&Local) - Local::now()).to_std().ok())
+                .map_or(POLL_INTERVAL, |d| d.min(POLL_INTERVAL));
+            thread::sleep(wait);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{error::Error, fmt};
+
+use super::CommonExitCodes;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The reason an operation failed.
+///
+/// Failures reported by Telegram are kept structured in `Telegram` so that
+/// they can be matched on, everything else is described by `Other`. Both can
+/// be mapped to an exit code of `CommonExitCodes` for the CLI.
+#[derive(Debug)]
+pub enum OperationError {
+    /// Telegram refused the request.
+    Telegram(TelegramError),
+    /// Any other failure, e.g. an invalid value, an unreadable file or a
+    /// connection error.
+    Other {
+        exit_code: i32,
+        /// The simplest message for non-verbose output.
+        message: String,
+        /// The underlying error, which is detailed in verbose output.
+        source: Option<Box<dyn Error + Send + Sync>>,
+    },
+}
+
+impl OperationError {
+    pub fn new(
+        exit_code: i32,
+        message: impl fmt::Display,
+        source: Option<impl Into<Box<dyn Error + Send + Sync>>>,
+    ) -> Self {
+        Self::Other {
+            exit_code,
+            message: message.to_string(),
+            source: source.map(|s| s.into()),
+        }
+    }
+
+    /// The exit code of the CLI for this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Telegram(e) => e.kind().exit_code(),
+            Self::Other { exit_code, .. } => *exit_code,
+        }
+    }
+
+    /// Whether the same request may succeed later, e.g. after a connection
+    /// error or flood control.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Telegram(e) => matches!(
+                e.kind(),
+                TelegramErrorKind::TooManyRequests | TelegramErrorKind::ServerError
+            ),
+            Self::Other { exit_code, .. } => {
+                *exit_code == CommonExitCodes::ReqwestConnectionError as i32
+            }
+        }
+    }
+
+    /// The error reported by Telegram, if any.
+    pub fn telegram(&self) -> Option<&TelegramError> {
+        match self {
+            Self::Telegram(e) => Some(e),
+            Self::Other { .. } => None,
+        }
+    }
+
+    /// The kind of the error reported by Telegram, if any.
+    pub fn telegram_kind(&self) -> Option<TelegramErrorKind> {
+        self.telegram().map(|e| e.kind())
+    }
+
+    pub fn exit(self) {
+        error!("{}", self);
+        if let Some(s) = self.source() {
+            debug!("{}", s);
+        };
+        std::process::exit(self.exit_code());
+    }
+}
+
+impl fmt::Display for OperationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Telegram(e) => write!(f, "{}", e),
+            Self::Other { message, .. } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for OperationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Telegram(_) => None,
+            Self::Other { source, .. } => source.as_deref().map(|s| s as &(dyn Error + 'static)),
+        }
+    }
+}
+
+impl From<TelegramError> for OperationError {
+    fn from(e: TelegramError) -> Self {
+        Self::Telegram(e)
+    }
+}
+
+/// Additional information Telegram provides for some errors.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ResponseParameters {
+    /// The group has been migrated to a supergroup with this id.
+    pub migrate_to_chat_id: Option<i64>,
+    /// The number of seconds to wait before the request can be repeated.
+    pub retry_after: Option<u64>,
+}
+
+/// An error reported by Telegram in an unsuccessful response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelegramError {
+    /// The error code, which mostly follows HTTP status codes.
+    pub error_code: u16,
+    /// The human-readable description, such as "Bad Request: chat not found".
+    pub description: String,
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl TelegramError {
+    pub fn new(
+        error_code: u16,
+        description: impl fmt::Display,
+        parameters: Option<ResponseParameters>,
+    ) -> Self {
+        Self {
+            error_code,
+            description: description.to_string(),
+            parameters,
+        }
+    }
+
+    /// Categorizes the error by its code and description.
+    ///
+    /// This is meant for the responses of Bot API methods. Downloading a file
+    /// fails with a plain HTTP error instead.
+    pub fn kind(&self) -> TelegramErrorKind {
+        let description = self.description.to_lowercase();
+        match self.error_code {
+            // a method called with a malformed token is responded with 404
+            401 => TelegramErrorKind::Unauthorized,
+            404 if description == "not found" => TelegramErrorKind::Unauthorized,
+            403 if description.contains("bot was blocked by the user") => {
+                TelegramErrorKind::BotBlocked
+            }
+            403 => TelegramErrorKind::Forbidden,
+            429 => TelegramErrorKind::TooManyRequests,
+            400 if description.contains("chat not found") => TelegramErrorKind::ChatNotFound,
+            400 if description.contains("message to edit not found")
+                || description.contains("message to delete not found")
+                || description.contains("message to reply not found")
+                || description.contains("message not found") =>
+            {
+                TelegramErrorKind::MessageNotFound
+            }
+            400 if description.contains("can't parse entities") => {
+                TelegramErrorKind::EntityParseError
+            }
+            400 if description.contains("too big") || description.contains("too large") => {
+                TelegramErrorKind::FileTooLarge
+            }
+            413 => TelegramErrorKind::FileTooLarge,
+            c if c >= 500 => TelegramErrorKind::ServerError,
+            _ => TelegramErrorKind::Other,
+        }
+    }
+
+    /// The number of seconds to wait before repeating a rate-limited request.
+    pub fn retry_after(&self) -> Option<u64> {
+        self.parameters.as_ref().and_then(|p| p.retry_after)
+    }
+
+    /// The id of the supergroup a group has been migrated to.
+    pub fn migrate_to_chat_id(&self) -> Option<i64> {
+        self.parameters.as_ref().and_then(|p| p.migrate_to_chat_id)
+    }
+}
+
+impl fmt::Display for TelegramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl Error for TelegramError {}
+
+/// The well-known errors reported by Telegram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelegramErrorKind {
+    /// The bot token is invalid or has been revoked.
+    Unauthorized,
+    /// The user has blocked the bot.
+    BotBlocked,
+    /// The bot is not allowed to do this, e.g. it was kicked from the chat.
+    Forbidden,
+    /// The chat does not exist or the bot is not a member of it.
+    ChatNotFound,
+    /// The message does not exist or was deleted.
+    MessageNotFound,
+    /// Flood control is exceeded, see `TelegramError::retry_after`.
+    TooManyRequests,
+    /// The text could not be parsed with the given format.
+    EntityParseError,
+    /// The file is too large for Telegram.
+    FileTooLarge,
+    /// Telegram failed on its own side.
+    ServerError,
+    Other,
+}
+
+impl TelegramErrorKind {
+    /// The exit code of the CLI for this kind of error.
+    pub fn exit_code(&self) -> i32 {
+        let code = match self {
+            Self::Unauthorized => CommonExitCodes::TelegramAPIUnauthorized,
+            Self::BotBlocked | Self::Forbidden => CommonExitCodes::TelegramAPIForbidden,
+            Self::ChatNotFound => CommonExitCodes::TelegramAPIChatNotFound,
+            Self::MessageNotFound => CommonExitCodes::TelegramAPIMessageNotFound,
+            Self::TooManyRequests => CommonExitCodes::TelegramAPITooManyRequests,
+            Self::EntityParseError => CommonExitCodes::TelegramAPIEntityParseError,
+            Self::FileTooLarge => CommonExitCodes::TelegramAPIFileTooLarge,
+            Self::ServerError => CommonExitCodes::TelegramAPIServerError,
+            Self::Other => CommonExitCodes::TelegramAPIBadRequest,
+        };
+        code as i32
+    }
+}
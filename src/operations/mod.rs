@@ -1,5 +1,3 @@
-use std::fmt;
-
 // Copyright 2021 Eray Erdin
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
@@ -14,49 +12,60 @@ use std::fmt;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod batch;
 pub mod bot;
+pub mod daemon;
+pub mod error;
+pub mod outbox;
+pub mod schedule;
 
-#[derive(Debug)]
-pub struct RootParams;
+pub use error::{OperationError, ResponseParameters, TelegramError, TelegramErrorKind};
 
-impl RootParams {
-    pub fn new() -> Self {
-        Self
-    }
+/// How the progress of file transfers is reported.
+#[derive(Debug, Clone, Copy)]
+pub enum ProgressMode {
+    /// A progress bar on stderr.
+    Bar,
+    /// JSON events on stderr, one per line.
+    Json,
+    /// No progress is reported.
+    Hidden,
 }
 
-#[derive(Debug)]
-pub struct OperationError {
-    pub exit_code: i32,
-    /// The simplest message for non-verbose output.
-    pub message_eu: String,
-    /// The error message for verbose output. It is detailed.
-    pub message_origin: Option<String>,
+/// How a request is printed instead of sent.
+#[derive(Debug, Clone, Copy)]
+pub enum EmitFormat {
+    /// A JSON object describing the request.
+    Json,
+    /// A curl command reproducing the request.
+    Curl,
+    /// A raw HTTP/1.1 transcript of the request.
+    Http,
 }
 
-impl OperationError {
-    pub fn new(
-        exit_code: i32,
-        message_eu: impl fmt::Display,
-        message_origin: Option<impl fmt::Display>,
-    ) -> Self {
-        Self {
-            exit_code,
-            message_eu: message_eu.to_string(),
-            message_origin: message_origin.map(|m| m.to_string()),
-        }
+#[derive(Debug, Clone, Copy)]
+pub struct DryRunParams {
+    pub emit: EmitFormat,
+    /// Whether the bot token is printed as is rather than redacted.
+    pub show_token: bool,
+}
+
+impl DryRunParams {
+    pub fn new(emit: EmitFormat, show_token: bool) -> Self {
+        Self { emit, show_token }
     }
+}
 
-    pub fn exit(self) {
-        error!("{}", self.message_eu);
-        if self.message_origin.is_some() {
-            debug!(
-                "{}",
-                self.message_origin
-                    .expect("The program should not have failed with this expected unwrap.")
-            );
-        };
-        std::process::exit(self.exit_code);
+#[derive(Debug, Clone)]
+pub struct RootParams {
+    pub progress: ProgressMode,
+    /// If present, the request is printed instead of sent.
+    pub dry_run: Option<DryRunParams>,
+}
+
+impl RootParams {
+    pub fn new(progress: ProgressMode, dry_run: Option<DryRunParams>) -> Self {
+        Self { progress, dry_run }
     }
 }
 
@@ -77,6 +86,12 @@ pub enum CommonExitCodes {
     /// is provided by the user.
     StdInvalidValue = 2,
     StdFsInvalidFilename = 3,
+    /// A file could not be read.
+    StdFsReadError = 4,
+    /// A file could not be written.
+    StdFsWriteError = 5,
+    /// Standard input could not be read.
+    StdStdinReadError = 6,
 
     // /////////// //
     // Clap Errors //
@@ -102,6 +117,25 @@ pub enum CommonExitCodes {
     // between 60-79
     TelegramAPIMissingDescription = 60,
     TelegramAPIBadRequest = 61,
+    /// Telegram reported success but did not provide a result.
+    TelegramAPIMissingResult = 62,
+    /// The file exceeds the upload limit of the API server.
+    TelegramAPIFileTooLarge = 63,
+    /// The bot token is invalid or has been revoked.
+    TelegramAPIUnauthorized = 64,
+    /// The bot is not allowed to do this, e.g. the user blocked the bot
+    /// or the bot was kicked from the chat.
+    TelegramAPIForbidden = 65,
+    /// The chat does not exist or the bot is not a member of it.
+    TelegramAPIChatNotFound = 66,
+    /// The message does not exist or was deleted.
+    TelegramAPIMessageNotFound = 67,
+    /// Flood control is exceeded and the request can be retried later.
+    TelegramAPITooManyRequests = 68,
+    /// The text could not be parsed with the given format.
+    TelegramAPIEntityParseError = 69,
+    /// Telegram failed on its own side.
+    TelegramAPIServerError = 70,
 
     // //////////// //
     // Serde Errors //
@@ -120,4 +154,13 @@ pub enum CommonExitCodes {
     // //////////// //
     // between 120-139
     TokioFsFileError = 120,
+
+    // //////////// //
+    // Image Errors //
+    // //////////// //
+    // between 140-159
+    /// An image could not be read.
+    ImageDecodeError = 140,
+    /// An image could not be written.
+    ImageEncodeError = 141,
 }
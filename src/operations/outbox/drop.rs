@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::{
+    http::request::outbox::OutboxEntry,
+    operations::{bot::send::SendOperation, CommonExitCodes, OperationError},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct DropParams {
+    pub outbox: PathBuf,
+    /// The ids of the entries to drop. All of them are dropped if absent.
+    pub ids: Option<Vec<String>>,
+}
+
+impl DropParams {
+    pub fn new(outbox: PathBuf, ids: Option<Vec<String>>) -> Self {
+        Self { outbox, ids }
+    }
+}
+
+#[derive(Debug)]
+pub struct DropOutboxOperation {
+    params: DropParams,
+}
+
+impl DropOutboxOperation {
+    pub fn new(params: DropParams) -> Self {
+        Self { params }
+    }
+}
+
+#[async_trait]
+impl SendOperation for DropOutboxOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("🗑️ Dropping from the outbox...");
+
+        let kept: Vec<String> = match OutboxEntry::list(&self.params.outbox) {
+            Ok(e) => e.into_iter().map(|(id, _)| id).collect(),
+            Err(e) => return Err(e),
+        };
+
+        let ids = self.params.ids.unwrap_or_else(|| kept.clone());
+        // nothing is dropped unless all of them are in the outbox
+        if let Some(id) = ids.iter().find(|id| !kept.contains(id)) {
+            return Err(OperationError::new(
+                CommonExitCodes::StdInvalidValue as i32,
+                format!("There is no entry with id {} in the outbox.", id),
+                None::<&str>,
+            ));
+        }
+
+        for id in ids {
+            match OutboxEntry::remove(&self.params.outbox, &id) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            };
+            println!("{}", json!({ "id": id, "dropped": true }));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::{path::PathBuf, thread, time::Duration};
+
+use reqwest::blocking::Client;
+use serde_json::json;
+
+use crate::{
+    handle_response,
+    http::{request::outbox::OutboxEntry, transfer_client},
+    operations::{bot::send::SendOperation, bot::BotParams, OperationError, RootParams},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct FlushParams {
+    pub outbox: PathBuf,
+    /// How many times a request that fails for a reason that may go away is
+    /// retried before the flush stops.
+    pub retries: u32,
+}
+
+impl FlushParams {
+    pub fn new(outbox: PathBuf, retries: u32) -> Self {
+        Self { outbox, retries }
+    }
+}
+
+pub type FlushOutboxParams = (RootParams, BotParams, FlushParams);
+
+#[derive(Debug)]
+pub struct FlushOutboxOperation {
+    params: FlushOutboxParams,
+}
+
+impl FlushOutboxOperation {
+    pub fn new(params: FlushOutboxParams) -> Self {
+        Self { params }
+    }
+
+    /// Sends the request of an entry once.
+    fn post(&self, client: &Client, id: &str, entry: &OutboxEntry) -> Result<(), OperationError> {
+        let url = self.params.1.method_url(&entry.method);
+        trace!("url: {}", url);
+
+        let dir = self.params.2.outbox.join(id);
+        let req_body = entry.form(&dir, self.params.0.progress)?;
+        trace!("request body: {:?}", req_body);
+
+        let response = client.post(url).multipart(req_body).send();
+
+        handle_response!(response, on_success => {
+            info!("📦 Successfully sent {}.", id);
+        }, on_failure => {
+            error!("☠️ An error occured while sending {}.", id);
+        })
+    }
+
+    /// Sends the request of an entry, retrying it as long as it fails for a
+    /// reason that may go away, and removes the entry once it is sent.
+    fn flush(
+        &self,
+        client: &Client,
+        id: &str,
+        mut entry: OutboxEntry,
+    ) -> Result<(), OperationError> {
+        let mut retries = 0;
+        loop {
+            let error = match self.post(client, id, &entry) {
+                Ok(_) => return OutboxEntry::remove(&self.params.2.outbox, id),
+                Err(e) => e,
+            };
+
+            entry.attempts += 1;
+            entry.last_error = error.to_string();
+            match entry.write(&self.params.2.outbox.join(id)) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            };
+
+            if !error.is_transient() || retries >= self.params.2.retries {
+                return Err(error);
+            }
+
+            let delay = match error.telegram().and_then(|e| e.retry_after()) {
+                Some(s) => Duration::from_secs(s),
+                None => Duration::from_secs(1 << retries.min(6)),
+            };
+            retries += 1;
+            warn!(
+                "Retrying {} in {} seconds ({}/{})...",
+                id,
+                delay.as_secs(),
+                retries,
+                self.params.2.retries
+            );
+            thread::sleep(delay);
+        }
+    }
+}
+
+#[async_trait]
+impl SendOperation for FlushOutboxOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📮 Flushing the outbox...");
+
+        let entries = OutboxEntry::list(&self.params.2.outbox)?;
+
+        let client = transfer_client()?;
+
+        let mut last_error = None;
+        for (id, entry) in entries {
+            match self.flush(&client, &id, entry) {
+                Ok(_) => println!("{}", json!({ "id": id, "ok": true })),
+                Err(e) => {
+                    println!(
+                        "{}",
+                        json!({ "id": id, "ok": false, "error": e.to_string() })
+                    );
+                    // the rest would fail the same way, and are kept in order
+                    if e.is_transient() {
+                        return Err(e);
+                    }
+                    last_error = Some(e);
+                }
+            };
+        }
+
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
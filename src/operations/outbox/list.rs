@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::{
+    http::request::outbox::OutboxEntry,
+    operations::{bot::send::SendOperation, OperationError},
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[derive(Debug)]
+pub struct ListOutboxOperation {
+    outbox: PathBuf,
+}
+
+impl ListOutboxOperation {
+    pub fn new(outbox: PathBuf) -> Self {
+        Self { outbox }
+    }
+}
+
+#[async_trait]
+impl SendOperation for ListOutboxOperation {
+    fn send(self) -> Result<(), OperationError> {
+        info!("📮 Listing the outbox...");
+
+        let entries = OutboxEntry::list(&self.outbox)?;
+
+        for (id, entry) in entries {
+            println!(
+                "{}",
+                json!({
+                    "id": id,
+                    "method": entry.method,
+                    "chat_id": entry.value("chat_id"),
+                    "created": entry.created.to_rfc3339(),
+                    "attempts": entry.attempts,
+                    "last_error": entry.last_error,
+                })
+            );
+        }
+        Ok(())
+    }
+}
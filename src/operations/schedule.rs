@@ -0,0 +1,181 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process, thread,
+};
+
+use chrono::{DateTime, FixedOffset, Local};
+use croner::Cron;
+
+use crate::operations::{CommonExitCodes, OperationError};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// When an operation runs instead of right away.
+#[derive(Debug)]
+pub struct ScheduleParams {
+    /// When the operation runs first.
+    pub at: DateTime<Local>,
+    /// A cron pattern the operation recurs on after `at`.
+    pub cron: Option<String>,
+    /// The spool directory the operation is written to if it is queued for
+    /// `daemon` rather than waited for.
+    pub queue: Option<PathBuf>,
+}
+
+impl ScheduleParams {
+    pub fn new(at: DateTime<Local>, cron: Option<String>, queue: Option<PathBuf>) -> Self {
+        Self { at, cron, queue }
+    }
+
+    /// Blocks until the operation is due.
+    pub fn wait(&self) {
+        match (self.at - Local::now()).to_std() {
+            Ok(d) => {
+                info!("⏰ Waiting until {}...", self.at.to_rfc3339());
+                thread::sleep(d);
+            }
+            Err(_) => warn!("{} has already passed, running now.", self.at.to_rfc3339()),
+        }
+    }
+}
+
+/// The next time `cron` matches after `time`.
+pub fn next_occurrence(
+    cron: &str,
+    time: &DateTime<Local>,
+) -> Result<DateTime<Local>, OperationError> {
+    let result = match Cron::new(cron).parse() {
+        Ok(c) => c.find_next_occurrence(time, false),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(t) => Ok(t),
+        Err(e) => Err(OperationError::new(
+            CommonExitCodes::StdInvalidValue as i32,
+            format!("Could not find the next time of `{}`.", cron),
+            Some(e.to_string()),
+        )),
+    }
+}
+
+/// A new id of an entry in a spool or an outbox, which sorts by the time it was
+/// created.
+pub fn entry_id() -> String {
+    format!(
+        "{}-{}",
+        Local::now().format("%Y%m%dT%H%M%S%.f"),
+        process::id()
+    )
+}
+
+/// An operation waiting in the spool directory for `daemon`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpoolEntry {
+    /// The command line arguments of the operation without the program name,
+    /// the token and the URL of Bot API server.
+    pub args: Vec<String>,
+    /// The working directory that relative paths in `args` are resolved against.
+    pub dir: PathBuf,
+    /// When the operation runs next.
+    pub at: DateTime<FixedOffset>,
+    /// A cron pattern the operation recurs on.
+    pub cron: Option<String>,
+}
+
+impl SpoolEntry {
+    pub fn new(
+        args: Vec<String>,
+        dir: PathBuf,
+        at: DateTime<FixedOffset>,
+        cron: Option<String>,
+    ) -> Self {
+        Self {
+            args,
+            dir,
+            at,
+            cron,
+        }
+    }
+
+    /// Reads an entry from `path`.
+    pub fn read(path: &Path) -> Result<Self, OperationError> {
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                return Err(OperationError::new(
+                    CommonExitCodes::StdFsReadError as i32,
+                    format!(
+                        "Could not read the spool entry.\nPath: {}",
+                        path.to_string_lossy()
+                    ),
+                    Some(e),
+                ))
+            }
+        };
+
+        match serde_json::from_str(&content) {
+            Ok(e) => Ok(e),
+            Err(e) => Err(OperationError::new(
+                CommonExitCodes::SerdeDeserializationError as i32,
+                format!(
+                    "The spool entry is not valid.\nPath: {}",
+                    path.to_string_lossy()
+                ),
+                Some(e),
+            )),
+        }
+    }
+
+    /// Writes the entry to `path` as a whole, so that `daemon` never reads a
+    /// partial entry.
+    pub fn write(&self, path: &Path) -> Result<(), OperationError> {
+        let temp_path = path.with_extension(format!("tmp{}", process::id()));
+        let content = serde_json::to_string_pretty(self).unwrap();
+
+        match fs::write(&temp_path, content).and_then(|_| fs::rename(&temp_path, path)) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(OperationError::new(
+                CommonExitCodes::StdFsWriteError as i32,
+                format!(
+                    "Could not write the spool entry.\nPath: {}",
+                    path.to_string_lossy()
+                ),
+                Some(e),
+            )),
+        }
+    }
+
+    /// Adds the entry to `spool` and returns its id.
+    pub fn queue(&self, spool: &Path) -> Result<String, OperationError> {
+        if let Err(e) = fs::create_dir_all(spool) {
+            return Err(OperationError::new(
+                CommonExitCodes::StdFsWriteError as i32,
+                format!(
+                    "Could not create the spool directory.\nPath: {}",
+                    spool.to_string_lossy()
+                ),
+                Some(e),
+            ));
+        }
+
+        let id = entry_id();
+        match self.write(&spool.join(format!("{}.json", id))) {
+            Ok(_) => Ok(id),
+            Err(e) => Err(e),
+        }
+    }
+}
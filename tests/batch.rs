@@ -0,0 +1,189 @@
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockResponse, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// What a finished run printed.
+fn stdout(assert: assert_cmd::assert::Assert) -> String {
+    String::from_utf8_lossy(&assert.get_output().stdout).to_string()
+}
+
+const MANIFEST: &str = r#"{"op": "send.message", "receiver": 1234, "message": "foo"}
+
+{"op": "send.photo", "receiver": 1234, "file": "resources/test/thumbnail512.png", "caption": "bar"}
+{"op": "send.location", "receiver": 1234, "latitude": 1.5, "longitude": 2.5, "silent": true}
+{"op": "send.poll", "receiver": 1234, "question": "baz?", "option": ["yes", "-no"]}
+"#;
+
+#[rstest]
+fn sequential(server: MockServer) {
+    let output = stdout(
+        binary(server.url())
+            .args(["batch", "-"])
+            .write_stdin(MANIFEST)
+            .assert()
+            .success(),
+    );
+    assert!(output.contains(r#"{"line":1,"ok":true,"op":"send.message"}"#));
+    assert!(output.contains(r#"{"line":3,"ok":true,"op":"send.photo"}"#));
+    assert!(output.contains(r#"{"line":4,"ok":true,"op":"send.location"}"#));
+    assert!(output.contains(r#"{"line":5,"ok":true,"op":"send.poll"}"#));
+
+    assert_eq!(server.requests_to("getMe").len(), 1);
+    let message = &server.requests_to("sendMessage")[0];
+    assert_eq!(message.field("chat_id").as_deref(), Some("1234"));
+    assert_eq!(message.field("text").as_deref(), Some("foo"));
+    let photo = &server.requests_to("sendPhoto")[0];
+    assert!(photo.part("photo").is_some());
+    assert_eq!(photo.field("caption").as_deref(), Some("bar"));
+    let location = &server.requests_to("sendLocation")[0];
+    assert_eq!(location.field("latitude").as_deref(), Some("1.5"));
+    assert_eq!(
+        location.field("disable_notification").as_deref(),
+        Some("true")
+    );
+    let poll = &server.requests_to("sendPoll")[0];
+    assert_eq!(poll.field("options").as_deref(), Some(r#"["yes","-no"]"#));
+}
+
+#[rstest]
+fn yaml(server: MockServer) {
+    let path = std::env::temp_dir().join(format!("tgcli-batch-{}.yaml", std::process::id()));
+    std::fs::write(
+        &path,
+        "- op: send.message\n  receiver: 1234\n  message: foo\n- op: send.message\n  receiver: 1234\n  message: bar\n",
+    )
+    .expect("Could not write the manifest.");
+
+    let assert = binary(server.url()).arg("batch").arg(&path).assert();
+    std::fs::remove_file(&path).expect("Could not remove the manifest.");
+    assert.success();
+
+    let texts: Vec<_> = server
+        .requests_to("sendMessage")
+        .iter()
+        .map(|r| r.field("text").unwrap_or_default())
+        .collect();
+    assert_eq!(texts, vec!["foo", "bar"]);
+}
+
+#[rstest]
+fn stop_on_error(server: MockServer) {
+    server.respond(
+        "sendMessage",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+
+    let output = stdout(
+        binary(server.url())
+            .args(["batch", "-"])
+            .write_stdin(MANIFEST)
+            .assert()
+            .failure()
+            .code(66),
+    );
+    assert!(output.contains(r#""exit_code":66"#));
+    assert!(!output.contains(r#""line":3"#));
+
+    assert!(server.requests_to("sendPhoto").is_empty());
+    assert!(server.requests_to("sendLocation").is_empty());
+}
+
+#[rstest]
+fn continue_on_error(server: MockServer) {
+    server.respond(
+        "sendMessage",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+
+    let output = stdout(
+        binary(server.url())
+            .args(["batch", "-", "--continue-on-error"])
+            .write_stdin(MANIFEST)
+            .assert()
+            .failure()
+            .code(66),
+    );
+    assert!(output.contains(r#"{"line":4,"ok":true,"op":"send.location"}"#));
+
+    assert_eq!(server.requests_to("sendPhoto").len(), 1);
+    assert_eq!(server.requests_to("sendLocation").len(), 1);
+}
+
+#[rstest]
+fn jobs(server: MockServer) {
+    let manifest: String = (0..8)
+        .map(|i| {
+            format!(
+                r#"{{"op": "send.message", "receiver": 1234, "message": "{}"}}"#,
+                i
+            ) + "\n"
+        })
+        .collect();
+
+    binary(server.url())
+        .args(["batch", "-", "--jobs", "4"])
+        .write_stdin(manifest)
+        .assert()
+        .success();
+
+    let mut texts: Vec<_> = server
+        .requests_to("sendMessage")
+        .iter()
+        .map(|r| r.field("text").unwrap_or_default())
+        .collect();
+    texts.sort();
+    assert_eq!(texts, (0..8).map(|i| i.to_string()).collect::<Vec<_>>());
+}
+
+#[rstest]
+fn dry_run(server: MockServer) {
+    let output = stdout(
+        binary(server.url())
+            .args(["--dry-run", "batch", "-"])
+            .write_stdin(MANIFEST)
+            .assert()
+            .success(),
+    );
+    assert!(output.contains("/sendPhoto\""));
+
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+#[case("{\"receiver\": 1234}\n")]
+#[case("[]\n")]
+#[case("not json\n")]
+#[case("{\"op\": \"send.nothing\"}\n")]
+#[case("{\"op\": \"send.message\", \"receiver\": 1234}\n")]
+fn invalid(server: MockServer, #[case] manifest: &str) {
+    binary(server.url())
+        .args(["batch", "-"])
+        .write_stdin(manifest)
+        .assert()
+        .failure()
+        .code(2);
+
+    assert!(server.requests_to("sendMessage").is_empty());
+}
@@ -0,0 +1,148 @@
+use std::env;
+
+use assert_cmd::Command;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn get_member_count(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "chat",
+            "get-member-count",
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn get_administrators(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "chat",
+            "get-administrators",
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn create_invite_link(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "chat",
+            "create-invite-link",
+            "--name",
+            "tgcli",
+            "--expire-in",
+            "1h",
+            "--member-limit",
+            "1",
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn unpin_all(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "chat",
+            "unpin-all",
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn chat_missing(mut binary: Command) {
+    let assertion = binary.args(["bot", "chat", "leave"]).assert();
+
+    assertion.failure().code(20);
+}
+
+#[rstest]
+fn set_title_invalid(mut binary: Command, #[values("", &"a".repeat(129))] title: &str) {
+    let assertion = binary
+        .args(["bot", "chat", "set-title", title, "--chat", "1"])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn set_description_too_long(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "chat",
+            "set-description",
+            &"a".repeat(256),
+            "--chat",
+            "1",
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn create_invite_link_invalid(
+    mut binary: Command,
+    #[values(
+        vec!["--expire-in", "2x"],
+        vec!["--expire-in", "0s"],
+        vec!["--member-limit", "0"],
+        vec!["--member-limit", "100000"],
+        vec!["--member-limit", "5", "--join-request"]
+    )]
+    args: Vec<&str>,
+) {
+    let assertion = binary
+        .args(["bot", "chat", "create-invite-link", "--chat", "1"])
+        .args(args)
+        .assert();
+
+    assertion.failure().code(1);
+}
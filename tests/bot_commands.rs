@@ -0,0 +1,106 @@
+use assert_cmd::Command;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn set_commands(
+    mut binary: Command,
+    #[values("resources/test/commands.toml", "resources/test/commands.json")] file: &str,
+) {
+    let assertion = binary
+        .args([
+            "bot",
+            "commands",
+            "set",
+            file,
+            "--scope",
+            "all-private-chats",
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn get_commands(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "commands", "get", "--language-code", "en"])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn delete_commands(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "commands", "delete", "--language-code", "tr"])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn set_commands_invalid_file(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "commands",
+            "set",
+            "resources/test/commands_invalid.json",
+        ])
+        .assert();
+
+    assertion.failure().code(2);
+}
+
+#[rstest]
+fn scope_missing_value(
+    mut binary: Command,
+    #[values(
+        vec!["--scope", "chat"],
+        vec!["--scope", "chat-administrators"],
+        vec!["--scope", "chat-member", "--chat", "1"]
+    )]
+    args: Vec<&str>,
+) {
+    let assertion = binary.args(["bot", "commands", "get"]).args(args).assert();
+
+    assertion.failure().code(20);
+}
+
+#[rstest]
+fn commands_invalid(
+    mut binary: Command,
+    #[values(
+        vec!["get", "--language-code", "EN"],
+        vec!["get", "--language-code", "eng"],
+        vec!["get", "--scope", "everyone"],
+        vec!["set", "resources/test/nonexistent.toml"]
+    )]
+    args: Vec<&str>,
+) {
+    let assertion = binary.args(["bot", "commands"]).args(args).assert();
+
+    assertion.failure().code(1);
+}
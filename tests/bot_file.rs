@@ -0,0 +1,99 @@
+use std::env;
+
+use assert_cmd::Command;
+use tgcli::operations::CommonExitCodes;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn get_file(mut binary: Command) {
+    let output = env::temp_dir().join("tgcli_get_file");
+    let assertion = binary
+        .args([
+            "bot",
+            "file",
+            "get",
+            &env::var("TELEGRAM_FILE_ID")
+                .expect("TELEGRAM_FILE_ID environment variable could not be found. Please create .env file and define it."),
+            "--output",
+            output.to_str().unwrap(),
+        ])
+        .assert();
+
+    assertion.success();
+    assert!(output.exists());
+}
+
+#[rstest]
+fn get_file_missing_id(mut binary: Command) {
+    let assertion = binary.args(["bot", "file", "get"]).assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn get_file_id_with_stdin(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "file", "get", "AgACAgIAAxkBAAIBB2", "--stdin"])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn get_file_stdin_without_attachments(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "file", "get", "--stdin"])
+        .write_stdin(r#"{"update_id": 1, "message": {"message_id": 2, "text": "hello"}}"#)
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn get_file_stdin_invalid_json(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "file", "get", "--stdin"])
+        .write_stdin("not a json")
+        .assert();
+
+    assertion.failure().code(80);
+}
+
+#[rstest]
+fn get_file_expired_path() {
+    let server = common::MockServer::start();
+    let output = env::temp_dir().join(format!("tgcli_expired_{}", std::process::id()));
+
+    // the file path is not served, so downloading it fails with 404
+    common::binary(server.url())
+        .args(["bot", "file", "get", "AgACAgIAAxkBAAIBB2", "-o"])
+        .arg(&output)
+        .assert()
+        .failure()
+        .code(CommonExitCodes::ReqwestHttpError as i32);
+    assert_eq!(server.requests_to("getFile").len(), 1);
+}
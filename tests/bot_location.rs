@@ -0,0 +1,114 @@
+use std::env;
+
+use assert_cmd::Command;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn follow_track(mut binary: Command, #[values("gpx", "nmea")] format: &str) {
+    let assertion = binary
+        .args([
+            "bot",
+            "location",
+            "follow",
+            &format!("--from-{}", format),
+            &format!("resources/test/track.{}", format),
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn follow_without_track(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "location",
+            "follow",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn follow_invalid_track(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "location",
+            "follow",
+            "--from-gpx",
+            "resources/test/doc.txt",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(2);
+}
+
+#[rstest]
+fn update_without_message_id(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "location",
+            "update",
+            "-x", "38.42",
+            "-y", "27.14",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn stop_invalid_message_id(mut binary: Command, #[values("0", "a")] message_id: &str) {
+    let assertion = binary
+        .args([
+            "bot",
+            "location",
+            "stop",
+            "--message-id",
+            message_id,
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
@@ -0,0 +1,40 @@
+use assert_cmd::Command;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn me(mut binary: Command) {
+    let assertion = binary.args(["bot", "me"]).assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn me_invalid_token(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "me", "--token", "123:invalid"])
+        .assert();
+
+    assertion.failure().code(64);
+}
@@ -0,0 +1,143 @@
+use std::env;
+
+use assert_cmd::Command;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn binary() -> Command {
+    let _ = dotenv::dotenv();
+    Command::cargo_bin("tgcli").expect("Could not find tgcli binary.")
+}
+
+#[rstest]
+fn info(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "member",
+            "info",
+            "--user",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it."),
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn info_users_from_stdin(mut binary: Command) {
+    let users = format!(
+        "# the receiver\n{}\n",
+        env::var("TELEGRAM_RECEIVER")
+            .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+    );
+    let assertion = binary
+        .args([
+            "bot",
+            "member",
+            "info",
+            "--users-from",
+            "-",
+            "--chat",
+            &env::var("TELEGRAM_GROUP")
+                .expect("TELEGRAM_GROUP environment variable could not be found. Please create .env file and define it."),
+        ])
+        .write_stdin(users)
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn chat_missing(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "member", "info", "--user", "1"])
+        .assert();
+
+    assertion.failure().code(20);
+}
+
+#[rstest]
+fn users_missing(
+    mut binary: Command,
+    #[values(vec![], vec!["--users-from", "-"])] args: Vec<&str>,
+) {
+    let assertion = binary
+        .args(["bot", "member", "ban", "--chat", "1"])
+        .args(args)
+        .write_stdin("# nobody\n")
+        .assert();
+
+    assertion.failure().code(20);
+}
+
+#[rstest]
+fn users_from_invalid(mut binary: Command) {
+    let assertion = binary
+        .args(["bot", "member", "ban", "--chat", "1", "--users-from", "-"])
+        .write_stdin("123\n@someone\n")
+        .assert();
+
+    assertion.failure().code(2);
+}
+
+#[rstest]
+fn users_from_missing_file(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "member",
+            "ban",
+            "--chat",
+            "1",
+            "--users-from",
+            "resources/test/nonexistent.txt",
+        ])
+        .assert();
+
+    assertion.failure().code(4);
+}
+
+#[rstest]
+fn member_invalid(
+    mut binary: Command,
+    #[values(
+        vec!["ban", "--user", "0"],
+        vec!["ban", "--user", "someone"],
+        vec!["ban", "--user", "1", "--until", "2x"],
+        vec!["restrict", "--user", "1", "--until", "0s"],
+        vec!["ban", "--user", "1", "--until", "10s"],
+        vec!["restrict", "--user", "1", "--until", "400d"],
+        vec!["restrict", "--user", "1", "--allow", "stickers"],
+        vec!["promote", "--user", "1", "--right", "everything"]
+    )]
+    args: Vec<&str>,
+) {
+    let assertion = binary
+        .args(["bot", "member"])
+        .args(args)
+        .args(["--chat", "1"])
+        .assert();
+
+    assertion.failure().code(1);
+}
@@ -60,3 +60,65 @@ fn send_audio_with_metadata(mut binary: Command) {
 
     assertion.success();
 }
+
+#[rstest]
+fn send_audio_with_duration(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "audio",
+            "resources/test/audio.wav",
+            "--duration", "5",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn send_audio_invalid_duration(mut binary: Command, #[values("0", "-1", "a")] duration: &str) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "audio",
+            "resources/test/audio.wav",
+            "--duration", duration,
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn send_audio_with_thumbnail(mut binary: Command, #[values(false, true)] auto: bool) {
+    let mut args = vec![
+        "bot",
+        "send",
+        "audio",
+        "resources/test/audio.wav",
+        "--thumbnail",
+        "resources/test/thumbnail512.png",
+    ];
+    if auto {
+        args.push("--auto-thumbnail");
+    }
+
+    let assertion = binary
+        .args(args)
+        .args([
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
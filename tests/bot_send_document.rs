@@ -1,6 +1,7 @@
 use std::env;
 
 use assert_cmd::Command;
+use tgcli::operations::CommonExitCodes;
 
 // Copyright 2021 Eray Erdin
 //
@@ -100,3 +101,106 @@ fn send_document_with_thumbnail(mut binary: Command) {
 
     assertion.success();
 }
+
+#[rstest]
+fn send_document_with_auto_thumbnail(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--thumbnail",
+            "resources/test/thumbnail512.png",
+            "--auto-thumbnail",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn send_document_auto_thumbnail_without_thumbnail(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--auto-thumbnail",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn send_document_too_large(mut binary: Command) {
+    let path = env::temp_dir().join(format!("tgcli_too_large_{}.bin", std::process::id()));
+    let file = std::fs::File::create(&path).expect("Could not create the large file.");
+    file.set_len(51 * 1024 * 1024)
+        .expect("Could not resize the large file.");
+
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "document",
+            path.to_str().unwrap(),
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+    // removed before asserting so that a failure does not leave it behind
+    std::fs::remove_file(&path).expect("Could not remove the large file.");
+
+    assertion
+        .failure()
+        .code(CommonExitCodes::TelegramAPIFileTooLarge as i32);
+}
+
+#[rstest]
+fn send_document_with_json_progress(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--progress",
+            "json",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    let stderr = String::from_utf8_lossy(&assertion.success().get_output().stderr).to_string();
+    assert!(stderr.contains(r#""event":"done""#));
+}
+
+#[rstest]
+fn send_document_invalid_api_url(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--api-url",
+            "not a url",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
@@ -56,3 +56,69 @@ fn send_location_invalid_float(mut binary: Command, #[values("a", "0..1", "1..0"
 
     assertion.failure().code(1);
 }
+
+#[rstest]
+fn send_live_location(mut binary: Command) {
+    let assertion = binary.args([
+        "bot",
+        "send",
+        "location",
+        "-x", "38.42",
+        "-y", "27.14",
+        "--live-period", "60",
+        "--heading", "90",
+        "--horizontal-accuracy", "10.5",
+        "--proximity-alert-radius", "100",
+        "--receiver",
+        &env::var("TELEGRAM_RECEIVER")
+            .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+    ]).assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn send_location_heading_without_live_period(mut binary: Command) {
+    let assertion = binary.args([
+        "bot",
+        "send",
+        "location",
+        "-x", "38.42",
+        "-y", "27.14",
+        "--heading", "90",
+        "--receiver",
+        &env::var("TELEGRAM_RECEIVER")
+            .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+    ]).assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn send_live_location_invalid_values(
+    mut binary: Command,
+    #[values(
+        ["--live-period", "59"],
+        ["--live-period", "86401"],
+        ["--heading", "0"],
+        ["--heading", "361"],
+        ["--horizontal-accuracy", "1500.1"],
+        ["--proximity-alert-radius", "0"]
+    )]
+    arg: [&str; 2],
+) {
+    let assertion = binary.args([
+        "bot",
+        "send",
+        "location",
+        "-x", "38.42",
+        "-y", "27.14",
+        "--live-period", "60",
+        arg[0], arg[1],
+        "--receiver",
+        &env::var("TELEGRAM_RECEIVER")
+            .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+    ]).assert();
+
+    assertion.failure().code(1);
+}
@@ -59,7 +59,7 @@ fn send_message_to_absent_receiver(mut binary: Command) {
 
     assertion
         .failure()
-        .code(CommonExitCodes::TelegramAPIBadRequest as i32);
+        .code(CommonExitCodes::TelegramAPIChatNotFound as i32);
 }
 
 #[rstest]
@@ -41,3 +41,72 @@ fn send_video(mut binary: Command) {
 
     assertion.success();
 }
+
+#[rstest]
+fn send_video_with_metadata(mut binary: Command) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "video",
+            "resources/test/video.mkv",
+            "--width", "1920",
+            "--height", "1080",
+            "--duration", "10",
+            "--supports-streaming",
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
+
+#[rstest]
+fn send_video_invalid_metadata(
+    mut binary: Command,
+    #[values("--width", "--height", "--duration")] flag: &str,
+    #[values("0", "a")] value: &str,
+) {
+    let assertion = binary
+        .args([
+            "bot",
+            "send",
+            "video",
+            "resources/test/video.mkv",
+            flag, value,
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.failure().code(1);
+}
+
+#[rstest]
+fn send_video_with_thumbnail(mut binary: Command, #[values(false, true)] auto: bool) {
+    let mut args = vec![
+        "bot",
+        "send",
+        "video",
+        "resources/test/video.mkv",
+        "--thumbnail",
+        "resources/test/thumbnail512.png",
+    ];
+    if auto {
+        args.push("--auto-thumbnail");
+    }
+
+    let assertion = binary
+        .args(args)
+        .args([
+            "--receiver",
+            &env::var("TELEGRAM_RECEIVER")
+                .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+        ])
+        .assert();
+
+    assertion.success();
+}
@@ -0,0 +1,197 @@
+use std::{fs, path::Path};
+
+use assert_cmd::Command;
+use tgcli::operations::bot::send::code::{language_of, tail, to_html_parts};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// A command of `bot send code` against `server` with `args`.
+fn send_code(server: &MockServer, args: &[&str]) -> Command {
+    let mut command = binary(server.url());
+    command
+        .args(["bot", "send", "code", "-r", "1234"])
+        .args(args);
+    command
+}
+
+/// Writes `content` into a file named `name` of a test.
+fn code_file(name: &str, content: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("tgcli-code-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[rstest]
+#[case("main.rs", Some("rust"))]
+#[case("deploy.SH", Some("bash"))]
+#[case("config.yml", Some("yaml"))]
+#[case("build.log", None)]
+#[case("Makefile", None)]
+fn languages_are_guessed(#[case] file: &str, #[case] language: Option<&str>) {
+    assert_eq!(language_of(Path::new(file)), language);
+}
+
+#[rstest]
+#[case("a\nb\nc\n", 2, "b\nc")]
+#[case("a\nb\nc", 5, "a\nb\nc")]
+#[case("a\nb\nc", 1, "c")]
+fn tails_lines(#[case] text: &str, #[case] lines: usize, #[case] tailed: &str) {
+    assert_eq!(tail(text, lines), tailed);
+}
+
+#[rstest]
+fn code_is_escaped() {
+    assert_eq!(
+        to_html_parts("if a < b && c > d {}\n", Some("rust"), 4096),
+        ["<pre><code class=\"language-rust\">if a &lt; b &amp;&amp; c &gt; d {}</code></pre>"]
+    );
+    assert_eq!(to_html_parts("plain", None, 4096), ["<pre>plain</pre>"]);
+    assert!(to_html_parts("\n\n", None, 4096).is_empty());
+}
+
+#[rstest]
+fn code_splits_between_lines() {
+    let code = "line one\nline two\nline three\n";
+
+    assert_eq!(
+        to_html_parts(code, None, 20),
+        ["<pre>line one\nline two</pre>", "<pre>line three</pre>"]
+    );
+    assert_eq!(
+        to_html_parts(&"x".repeat(25), None, 10),
+        [
+            "<pre>xxxxxxxxxx</pre>",
+            "<pre>xxxxxxxxxx</pre>",
+            "<pre>xxxxx</pre>"
+        ]
+    );
+}
+
+#[rstest]
+fn sends_file_with_language(server: MockServer) {
+    let file = code_file("hello.rs", "fn main() {\n    println!(\"<hi>\");\n}\n");
+
+    send_code(&server, &[&file]).assert().success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].field("text").unwrap(),
+        "<pre><code class=\"language-rust\">fn main() {\n    println!(&quot;&lt;hi&gt;&quot;);\n}</code></pre>"
+    );
+    assert_eq!(requests[0].field("parse_mode").unwrap(), "HTML");
+}
+
+#[rstest]
+fn sends_tail_of_stdin(server: MockServer) {
+    let log = (1..=500)
+        .map(|i| format!("INFO step {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    send_code(&server, &["--stdin", "--tail", "2", "--lang", "log"])
+        .write_stdin(log)
+        .assert()
+        .success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].field("text").unwrap(),
+        "<pre><code class=\"language-log\">INFO step 499\nINFO step 500</code></pre>"
+    );
+}
+
+#[rstest]
+fn splits_long_code(server: MockServer) {
+    let log = "0123456789\n".repeat(1000);
+
+    send_code(&server, &["--stdin"])
+        .write_stdin(log)
+        .assert()
+        .success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 3);
+    let mut lines = 0;
+    for request in &requests {
+        let text = request.field("text").unwrap();
+        assert!(text.starts_with("<pre>") && text.ends_with("</pre>"));
+        assert!(text.len() - "<pre></pre>".len() <= 4096);
+        lines += text.lines().count();
+    }
+    assert_eq!(lines, 1000);
+}
+
+#[rstest]
+fn sends_long_code_as_document(server: MockServer) {
+    let file = code_file("big.py", &"print('hello')\n".repeat(500));
+
+    send_code(&server, &[&file, "--overflow", "document"])
+        .assert()
+        .success();
+
+    assert!(server.requests_to("sendMessage").is_empty());
+    let requests = server.requests_to("sendDocument");
+    assert_eq!(requests.len(), 1);
+    let document = requests[0].part("document").unwrap();
+    assert_eq!(document.file_name.as_deref(), Some("big.py"));
+    assert_eq!(document.data.len(), 15 * 500);
+}
+
+#[rstest]
+fn short_code_is_not_a_document(server: MockServer) {
+    send_code(&server, &["--stdin", "--overflow", "document"])
+        .write_stdin("echo hi")
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    assert!(server.requests_to("sendDocument").is_empty());
+}
+
+#[rstest]
+fn empty_code_fails(server: MockServer) {
+    send_code(&server, &["--stdin"])
+        .write_stdin("\n\n")
+        .assert()
+        .failure()
+        .code(2);
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn stdin_cannot_be_queued(server: MockServer) {
+    send_code(&server, &["--stdin", "--queue"])
+        .write_stdin("echo hi")
+        .assert()
+        .failure()
+        .code(1);
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,453 @@
+//! An in-process HTTP server impersonating the Bot API so that tests can run
+//! without network. It records the requests it receives and responds with
+//! canned results, unless a response is queued for a method.
+//!
+//! ```ignore
+//! mod common;
+//!
+//! let server = common::MockServer::start();
+//! server.respond("sendMessage", common::MockResponse::error(403, "Forbidden: bot was blocked by the user"));
+//! common::binary(server.url()).args([...]).assert().code(65);
+//! assert_eq!(server.requests()[0].field("text").as_deref(), Some("foo"));
+//! ```
+
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use assert_cmd::Command;
+use serde_json::{json, Map, Value};
+use tiny_http::{Header, Method, Response, Server};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The token tests pass to `tgcli` while using the mock server.
+pub const TOKEN: &str = "123456:mock";
+
+/// The binary talking to the Bot API at `api_url` instead of Telegram.
+pub fn binary(api_url: &str) -> Command {
+    let mut binary = Command::cargo_bin("tgcli").expect("Could not find tgcli binary.");
+    binary
+        .env("TELEGRAM_BOT_TOKEN", TOKEN)
+        .env("TELEGRAM_API_URL", api_url);
+    binary
+}
+
+/// A response of the mock server.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl MockResponse {
+    /// A successful response with `result`.
+    pub fn ok(result: Value) -> Self {
+        Self {
+            status: 200,
+            body: json!({ "ok": true, "result": result }),
+        }
+    }
+
+    /// An unsuccessful response like Telegram's, e.g. 400 with "Bad Request: chat not found".
+    pub fn error(error_code: u16, description: &str) -> Self {
+        Self {
+            status: error_code,
+            body: json!({ "ok": false, "error_code": error_code, "description": description }),
+        }
+    }
+
+    /// Flood control, which asks to retry after `retry_after` seconds.
+    pub fn too_many_requests(retry_after: u64) -> Self {
+        Self {
+            status: 429,
+            body: json!({
+                "ok": false,
+                "error_code": 429,
+                "description": format!("Too Many Requests: retry after {}", retry_after),
+                "parameters": { "retry_after": retry_after },
+            }),
+        }
+    }
+}
+
+/// A part of a multipart request.
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub name: String,
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl Part {
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.data).into_owned()
+    }
+}
+
+/// A request the mock server has received.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// The Bot API method, e.g. `sendMessage`.
+    pub method: String,
+    pub token: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+    /// The parts of a multipart body, empty for other bodies.
+    pub parts: Vec<Part>,
+}
+
+impl RecordedRequest {
+    pub fn part(&self, name: &str) -> Option<&Part> {
+        self.parts.iter().find(|p| p.name == name)
+    }
+
+    /// The value of a field either in a multipart or a JSON body.
+    pub fn field(&self, name: &str) -> Option<String> {
+        if let Some(p) = self.part(name) {
+            return Some(p.text());
+        }
+
+        match serde_json::from_slice::<Value>(&self.body)
+            .ok()?
+            .get(name)?
+        {
+            Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    requests: Vec<RecordedRequest>,
+    responses: HashMap<String, VecDeque<MockResponse>>,
+    files: HashMap<String, Vec<u8>>,
+}
+
+/// The mock Bot API server, which stops when it is dropped.
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    state: Arc<Mutex<State>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Starts the server on a free local port.
+    pub fn start() -> Self {
+        let server =
+            Arc::new(Server::http("127.0.0.1:0").expect("Could not start the mock server."));
+        let url = format!(
+            "http://{}",
+            server
+                .server_addr()
+                .to_ip()
+                .expect("The mock server is not listening on an IP address.")
+        );
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle_request(request, &state);
+                }
+            })
+        };
+
+        Self {
+            server,
+            url,
+            state,
+            handle: Some(handle),
+        }
+    }
+
+    /// The URL to pass with `--api-url` or `TELEGRAM_API_URL`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Queues a response for the next request to `method`. Queued responses
+    /// are used in order, then the canned one is used again.
+    pub fn respond(&self, method: &str, response: MockResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .entry(String::from(method))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Serves `data` for the file at `file_path`, as returned by `getFile`.
+    pub fn file(&self, file_path: &str, data: &[u8]) {
+        self.state
+            .lock()
+            .unwrap()
+            .files
+            .insert(String::from(file_path), data.to_vec());
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The requests received so far to `method`.
+    pub fn requests_to(&self, method: &str) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|r| r.method == method)
+            .collect()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(h) = self.handle.take() {
+            let _ = h.join();
+        }
+    }
+}
+
+fn handle_request(mut request: tiny_http::Request, state: &Mutex<State>) {
+    let path = request.url().to_string();
+
+    if let (Method::Get, Some(file_path)) = (request.method(), path.strip_prefix("/file/")) {
+        let file_path = file_path
+            .split_once('/')
+            .map(|(_, p)| p)
+            .unwrap_or_default();
+        let response = match state.lock().unwrap().files.get(file_path) {
+            Some(data) => Response::from_data(data.clone()),
+            // like Telegram, which describes a missing file like a malformed token
+            None => Response::from_string(
+                json!({ "ok": false, "error_code": 404, "description": "Not Found" }).to_string(),
+            )
+            .with_status_code(404),
+        };
+        let _ = request.respond(response);
+        return;
+    }
+
+    let mut segments = path.trim_start_matches('/').splitn(2, '/');
+    let token = segments
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("bot")
+        .to_string();
+    let method = segments
+        .next()
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    let content_type = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Content-Type"))
+        .map(|h| h.value.to_string());
+    let mut body = Vec::new();
+    let _ = request.as_reader().read_to_end(&mut body);
+    let parts = match content_type.as_deref().and_then(boundary) {
+        Some(b) => parse_multipart(&body, &b),
+        None => Vec::new(),
+    };
+
+    let recorded = RecordedRequest {
+        method,
+        token,
+        content_type,
+        body,
+        parts,
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        let queued = state
+            .responses
+            .get_mut(&recorded.method)
+            .and_then(|q| q.pop_front());
+        let response = queued.unwrap_or_else(|| canned_response(&recorded));
+        state.requests.push(recorded);
+        response
+    };
+
+    let _ = request.respond(
+        Response::from_string(response.body.to_string())
+            .with_status_code(response.status)
+            .with_header(
+                Header::from_bytes("Content-Type", "application/json")
+                    .expect("The header should be valid."),
+            ),
+    );
+}
+
+/// A successful response for `request` like Telegram would respond with.
+fn canned_response(request: &RecordedRequest) -> MockResponse {
+    let method = request.method.as_str();
+    let result = match method {
+        "getMe" => json!({
+            "id": 123456,
+            "is_bot": true,
+            "first_name": "Mock",
+            "username": "mock_bot",
+            "can_join_groups": true,
+            "can_read_all_group_messages": false,
+            "supports_inline_queries": false,
+        }),
+        "getFile" => json!({
+            "file_id": request.field("file_id").unwrap_or_default(),
+            "file_unique_id": "mock",
+            "file_size": 0,
+            "file_path": "documents/mock",
+        }),
+        "getMyCommands" | "getChatAdministrators" => json!([]),
+        m if m.starts_with("send") || m.starts_with("edit") || m == "stopMessageLiveLocation" => {
+            canned_message(request)
+        }
+        _ => json!(true),
+    };
+    MockResponse::ok(result)
+}
+
+fn canned_message(request: &RecordedRequest) -> Value {
+    let chat_id = request.field("chat_id").unwrap_or_default();
+    let mut message = Map::new();
+    message.insert(String::from("message_id"), json!(1));
+    message.insert(String::from("date"), json!(0));
+    message.insert(
+        String::from("chat"),
+        match chat_id.parse::<i64>() {
+            Ok(id) => json!({ "id": id, "type": "private" }),
+            Err(_) => {
+                json!({ "id": -1, "type": "channel", "username": chat_id.trim_start_matches('@') })
+            }
+        },
+    );
+
+    for field in ["text", "caption"] {
+        if let Some(v) = request.field(field) {
+            message.insert(String::from(field), json!(v));
+        }
+    }
+
+    for part in request.parts.iter().filter(|p| p.file_name.is_some()) {
+        if part.name == "thumb" || part.name == "thumbnail" {
+            continue;
+        }
+        let file = json!({
+            "file_id": "mock",
+            "file_unique_id": "mock",
+            "file_name": part.file_name,
+            "mime_type": part.content_type,
+            "file_size": part.data.len(),
+        });
+        let value = match part.name.as_str() {
+            "photo" => json!([file]),
+            _ => file,
+        };
+        message.insert(part.name.clone(), value);
+    }
+
+    Value::Object(message)
+}
+
+fn boundary(content_type: &str) -> Option<String> {
+    if !content_type.starts_with("multipart/form-data") {
+        return None;
+    }
+    content_type
+        .split(';')
+        .map(str::trim)
+        .find_map(|p| p.strip_prefix("boundary="))
+        .map(|b| b.trim_matches('"').to_string())
+}
+
+fn parse_multipart(body: &[u8], boundary: &str) -> Vec<Part> {
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let mut parts = Vec::new();
+
+    for chunk in split(body, &delimiter).into_iter().skip(1) {
+        // the closing delimiter is followed by "--"
+        if chunk.starts_with(b"--") {
+            break;
+        }
+        let chunk = chunk.strip_prefix(b"\r\n").unwrap_or(chunk);
+        let chunk = chunk.strip_suffix(b"\r\n").unwrap_or(chunk);
+        let (head, data) = match find(chunk, b"\r\n\r\n") {
+            Some(i) => (&chunk[..i], &chunk[i + 4..]),
+            None => continue,
+        };
+
+        let head = String::from_utf8_lossy(head);
+        let mut part = Part {
+            name: String::new(),
+            file_name: None,
+            content_type: None,
+            data: data.to_vec(),
+        };
+        for line in head.lines() {
+            let (key, value) = match line.split_once(':') {
+                Some((k, v)) => (k.trim().to_lowercase(), v.trim()),
+                None => continue,
+            };
+            match key.as_str() {
+                "content-disposition" => {
+                    part.name = disposition_param(value, "name").unwrap_or_default();
+                    part.file_name = disposition_param(value, "filename");
+                }
+                "content-type" => part.content_type = Some(String::from(value)),
+                _ => (),
+            }
+        }
+        parts.push(part);
+    }
+
+    parts
+}
+
+fn disposition_param(value: &str, name: &str) -> Option<String> {
+    value
+        .split(';')
+        .map(str::trim)
+        .find_map(|p| p.strip_prefix(&format!("{}=", name)))
+        .map(|v| v.trim_matches('"').to_string())
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn split<'a>(mut haystack: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    let mut chunks = Vec::new();
+    while let Some(i) = find(haystack, delimiter) {
+        chunks.push(&haystack[..i]);
+        haystack = &haystack[i + delimiter.len()..];
+    }
+    chunks.push(haystack);
+    chunks
+}
@@ -0,0 +1,213 @@
+use tgcli::operations::bot::send::commonmark::{to_html, to_html_parts, MESSAGE_LIMIT};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// Runs `bot send` against `server` with `args`.
+fn send(server: &MockServer, args: &[&str]) -> assert_cmd::assert::Assert {
+    binary(server.url())
+        .args(["bot", "send"])
+        .args(args)
+        .args(["-r", "1234", "--format", "commonmark"])
+        .assert()
+}
+
+#[rstest]
+#[case("# Release", "<b>Release</b>")]
+#[case("**bold** and __strong__", "<b>bold</b> and <b>strong</b>")]
+#[case("*it* ~~gone~~ `x < y`", "<i>it</i> <s>gone</s> <code>x &lt; y</code>")]
+#[case(
+    "[logs](https://ci.example.com/1)",
+    "<a href=\"https://ci.example.com/1\">logs</a>"
+)]
+#[case("[readme](docs/README.md)", "readme")]
+#[case(
+    "![chart](https://example.com/c.png)",
+    "<a href=\"https://example.com/c.png\">chart</a>"
+)]
+#[case("5 < 6 & 7 > 1", "5 &lt; 6 &amp; 7 &gt; 1")]
+#[case("a <br> b <span>c</span>", "a  b c")]
+#[case("> quoted", "<blockquote>quoted</blockquote>")]
+#[case("---", "——————————")]
+fn inline_and_simple_blocks(#[case] markdown: &str, #[case] html: &str) {
+    assert_eq!(to_html(markdown), html);
+}
+
+#[rstest]
+fn lists_become_bullets() {
+    let markdown = "- one\n- two\n  - nested\n  - [x] done\n\n1. first\n2. second";
+
+    assert_eq!(
+        to_html(markdown),
+        "• one\n• two\n  • nested\n  • ☑ done\n\n1. first\n2. second"
+    );
+}
+
+#[rstest]
+fn tables_become_pre() {
+    let markdown = "| Suite | Passed |\n|:--|--:|\n| unit | 142 |\n| e2e | 9 |";
+
+    assert_eq!(
+        to_html(markdown),
+        "<pre>Suite | Passed\n------+-------\nunit  |    142\ne2e   |      9</pre>"
+    );
+}
+
+#[rstest]
+fn code_blocks_keep_language() {
+    assert_eq!(
+        to_html("```rust\nfn main() {}\n```"),
+        "<pre><code class=\"language-rust\">fn main() {}</code></pre>"
+    );
+    assert_eq!(to_html("    indented\n"), "<pre>indented</pre>");
+}
+
+#[rstest]
+fn paragraphs_are_separated() {
+    assert_eq!(
+        to_html("# Title\nfirst\nline\n\nsecond"),
+        "<b>Title</b>\n\nfirst line\n\nsecond"
+    );
+}
+
+#[rstest]
+fn parts_split_between_blocks() {
+    let markdown = (1..=6)
+        .map(|i| format!("Paragraph number {}.", i))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let parts = to_html_parts(&markdown, 45);
+    assert_eq!(
+        parts,
+        [
+            "Paragraph number 1.\n\nParagraph number 2.",
+            "Paragraph number 3.\n\nParagraph number 4.",
+            "Paragraph number 5.\n\nParagraph number 6.",
+        ]
+    );
+}
+
+#[rstest]
+fn long_blocks_reopen_tags() {
+    let markdown = format!("**{}**", "word ".repeat(20).trim_end());
+
+    let parts = to_html_parts(&markdown, 30);
+    assert!(parts.len() > 1);
+    for part in &parts {
+        assert!(part.starts_with("<b>"), "{}", part);
+        assert!(part.ends_with("</b>"), "{}", part);
+        assert!(part.len() - "<b></b>".len() <= 30, "{}", part);
+    }
+    let words: usize = parts.iter().map(|p| p.matches("word").count()).sum();
+    assert_eq!(words, 20);
+}
+
+#[rstest]
+fn long_code_blocks_stay_code() {
+    let code = (0..40)
+        .map(|i| format!("let x{} = {};", i, i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let markdown = format!("```rust\n{}\n```", code);
+
+    let parts = to_html_parts(&markdown, 200);
+    assert!(parts.len() > 1);
+    for part in &parts {
+        assert!(
+            part.starts_with("<pre><code class=\"language-rust\">"),
+            "{}",
+            part
+        );
+        assert!(part.ends_with("</code></pre>"), "{}", part);
+    }
+}
+
+#[rstest]
+fn entities_count_once() {
+    let parts = to_html_parts("&&&&", 4);
+    assert_eq!(parts, ["&amp;&amp;&amp;&amp;"]);
+}
+
+#[rstest]
+fn sends_html(server: MockServer) {
+    send(&server, &["message", "**Build** passed"]).success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("text").unwrap(), "<b>Build</b> passed");
+    assert_eq!(requests[0].field("parse_mode").unwrap(), "HTML");
+}
+
+#[rstest]
+fn splits_long_messages(server: MockServer) {
+    let paragraph = "lorem ipsum ".repeat(200);
+    let markdown = format!("{}\n\n{}", paragraph, paragraph);
+
+    send(&server, &["message", &markdown]).success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 2);
+    for request in &requests {
+        assert!(request.field("text").unwrap().len() <= MESSAGE_LIMIT);
+        assert_eq!(request.field("parse_mode").unwrap(), "HTML");
+    }
+}
+
+#[rstest]
+fn split_messages_cannot_be_sent_once(server: MockServer) {
+    let markdown = format!("{}\n\n{}", "a".repeat(4000), "b".repeat(4000));
+
+    send(
+        &server,
+        &["message", &markdown, "--idempotency-key", "long"],
+    )
+    .failure()
+    .code(2);
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn converts_captions(server: MockServer) {
+    send(
+        &server,
+        &[
+            "document",
+            "resources/test/doc.txt",
+            "-m",
+            "*Nightly* build",
+        ],
+    )
+    .success();
+
+    let requests = server.requests_to("sendDocument");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].field("caption").unwrap(),
+        "<i>Nightly</i> build"
+    );
+    assert_eq!(requests[0].field("parse_mode").unwrap(), "HTML");
+}
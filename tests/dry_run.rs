@@ -0,0 +1,234 @@
+use serde_json::Value;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer, TOKEN};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// The JSON object printed last, which is the request.
+fn printed_request(stdout: &[u8]) -> Value {
+    let stdout = String::from_utf8_lossy(stdout);
+    let start = stdout.find("{\n").expect("No request was printed.");
+    serde_json::from_str(&stdout[start..]).expect("The request is not valid JSON.")
+}
+
+#[rstest]
+fn dry_run_message(server: MockServer) {
+    let output = binary(server.url())
+        .args([
+            "--dry-run",
+            "bot",
+            "send",
+            "message",
+            "foo",
+            "--receiver",
+            "1234",
+        ])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    assert!(server.requests().is_empty());
+
+    let request = printed_request(&output.stdout);
+    let url = request["url"].as_str().unwrap();
+    assert!(url.ends_with("/bot<redacted>/sendMessage"));
+    assert!(!url.contains(TOKEN));
+    assert_eq!(request["parts"][0]["name"], "chat_id");
+    assert_eq!(request["parts"][0]["value"], "1234");
+    assert_eq!(request["parts"][1]["value"], "foo");
+}
+
+#[rstest]
+fn dry_run_document(server: MockServer) {
+    let output = binary(server.url())
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--receiver",
+            "1234",
+            "--dry-run",
+        ])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    assert!(server.requests().is_empty());
+
+    let request = printed_request(&output.stdout);
+    let document = request["parts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == "document")
+        .expect("No document part was printed.");
+    assert_eq!(document["file_name"], "doc.txt");
+    assert_eq!(document["mime_type"], "text/plain");
+    assert_eq!(
+        document["size"],
+        std::fs::metadata("resources/test/doc.txt").unwrap().len()
+    );
+}
+
+#[rstest]
+fn dry_run_unsupported(server: MockServer) {
+    binary(server.url())
+        .args(["bot", "me", "--dry-run"])
+        .assert()
+        .failure()
+        .code(2);
+
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+#[case(&["animation", "resources/test/animation.gif"], "sendAnimation", "animation")]
+#[case(&["audio", "resources/test/audio.wav", "--duration", "1"], "sendAudio", "audio")]
+#[case(&["location", "-x", "38.42", "-y", "27.14"], "sendLocation", "latitude")]
+#[case(&["photo", "resources/test/thumbnail512.png"], "sendPhoto", "photo")]
+#[case(&["poll", "foo", "-o", "bar", "-o", "baz"], "sendPoll", "options")]
+#[case(&["video", "resources/test/video.mkv", "--width", "1", "--height", "1", "--duration", "1"], "sendVideo", "video")]
+fn dry_run_send(
+    server: MockServer,
+    #[case] args: &[&str],
+    #[case] method: &str,
+    #[case] part: &str,
+) {
+    let output = binary(server.url())
+        .args(["--dry-run", "bot", "send"])
+        .args(args)
+        .args(["--receiver", "1234"])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    assert!(server.requests().is_empty());
+
+    let request = printed_request(&output.stdout);
+    assert!(request["url"].as_str().unwrap().ends_with(method));
+    assert!(request["parts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|p| p["name"] == part));
+}
+
+#[rstest]
+fn emit_curl(server: MockServer) {
+    let output = binary(server.url())
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--receiver",
+            "1234",
+            "--message",
+            "it's",
+            "--emit",
+            "curl",
+        ])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    assert!(server.requests().is_empty());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(&format!(
+        "curl -X POST \"{}/bot${{TELEGRAM_BOT_TOKEN}}/sendDocument\"",
+        server.url()
+    )));
+    assert!(stdout.contains("--form-string 'chat_id=1234'"));
+    assert!(stdout.contains(r#"--form-string 'caption=it'\''s'"#));
+    assert!(stdout.contains("-F 'document=@resources/test/doc.txt;type=text/plain'"));
+    assert!(!stdout.contains(TOKEN));
+}
+
+#[rstest]
+fn emit_curl_show_token(server: MockServer) {
+    let output = binary(server.url())
+        .args([
+            "bot",
+            "send",
+            "message",
+            "foo",
+            "--receiver",
+            "1234",
+            "--emit",
+            "curl",
+            "--show-token",
+        ])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(&format!("'{}/bot{}/sendMessage'", server.url(), TOKEN)));
+}
+
+#[rstest]
+fn emit_http(server: MockServer) {
+    let output = binary(server.url())
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--receiver",
+            "1234",
+            "--emit",
+            "http",
+        ])
+        .output()
+        .expect("Could not run tgcli.");
+
+    assert!(output.status.success());
+    assert!(server.requests().is_empty());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let host = server.url().trim_start_matches("http://");
+    assert!(stdout.contains(&format!(
+        "POST /bot<redacted>/sendDocument HTTP/1.1\r\nHost: {}\r\n",
+        host
+    )));
+    assert!(stdout
+        .contains("Content-Disposition: form-data; name=\"document\"; filename=\"doc.txt\"\r\n"));
+    assert!(stdout.contains(&format!(
+        "<{} bytes of resources/test/doc.txt>",
+        std::fs::metadata("resources/test/doc.txt").unwrap().len()
+    )));
+    assert!(stdout.contains("--tgcli-dry-run--"));
+}
+
+#[rstest]
+fn emit_unsupported(server: MockServer) {
+    binary(server.url())
+        .args(["bot", "me", "--emit", "curl"])
+        .assert()
+        .failure()
+        .code(2);
+}
@@ -0,0 +1,167 @@
+use std::fs;
+
+use serde_json::{json, Value};
+use tgcli::{
+    bot::{MessageEntity, RichText},
+    Bot,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer, TOKEN};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// Runs `bot send message` against `server` with `args` after the text.
+fn send_message(server: &MockServer, text: &str, args: &[&str]) -> assert_cmd::assert::Assert {
+    binary(server.url())
+        .args(["bot", "send", "message", text, "-r", "1234"])
+        .args(args)
+        .assert()
+}
+
+#[rstest]
+fn entities_json_replaces_parse_mode(server: MockServer) {
+    send_message(
+        &server,
+        "Hello, see docs.",
+        &["--entities-json", "resources/test/entities.json"],
+    )
+    .success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("text").unwrap(), "Hello, see docs.");
+    assert!(requests[0].field("parse_mode").is_none());
+    let entities: Value = serde_json::from_str(&requests[0].field("entities").unwrap()).unwrap();
+    assert_eq!(
+        entities,
+        json!([
+            { "type": "bold", "offset": 0, "length": 5 },
+            { "type": "text_link", "offset": 9, "length": 4, "url": "https://example.com" },
+        ])
+    );
+}
+
+#[rstest]
+#[case("end", r#"[{ "type": "bold", "offset": 3, "length": 10 }]"#)]
+#[case("overflow", &format!(r#"[{{ "type": "bold", "offset": {}, "length": 1 }}]"#, usize::MAX))]
+fn entity_out_of_text_fails(server: MockServer, #[case] name: &str, #[case] entities: &str) {
+    let path = std::env::temp_dir().join(format!(
+        "tgcli-entities-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    fs::write(&path, entities).unwrap();
+
+    send_message(
+        &server,
+        "Hello",
+        &["--entities-json", path.to_str().unwrap()],
+    )
+    .failure()
+    .code(2);
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn invalid_entities_json_fails(server: MockServer) {
+    send_message(
+        &server,
+        "Hello",
+        &["--entities-json", "resources/test/vars.json"],
+    )
+    .failure()
+    .code(2);
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn missing_entities_json_fails(server: MockServer) {
+    send_message(
+        &server,
+        "Hello",
+        &["--entities-json", "resources/test/nonexistent.json"],
+    )
+    .failure()
+    .code(1);
+}
+
+#[rstest]
+fn rich_text_counts_utf16() {
+    let text = RichText::new().text("👍 ").bold("çok").text(" ").code("x");
+
+    assert_eq!(text.as_str(), "👍 çok x");
+    assert_eq!(
+        text.entities(),
+        [
+            MessageEntity::new("bold", 3, 3),
+            MessageEntity::new("code", 7, 1)
+        ]
+    );
+}
+
+#[rstest]
+fn rich_text_links_and_mentions() {
+    let text = RichText::new()
+        .text_link("docs", "https://example.com")
+        .text(" by ")
+        .mention("erayerdin")
+        .pre("fn main() {}", Some("rust"));
+
+    assert_eq!(text.as_str(), "docs by @erayerdinfn main() {}");
+    let entities = text.entities();
+    assert_eq!(entities[0].url.as_deref(), Some("https://example.com"));
+    assert_eq!(entities[1], MessageEntity::new("mention", 8, 10));
+    assert_eq!(entities[2].offset, 18);
+    assert_eq!(entities[2].language.as_deref(), Some("rust"));
+}
+
+#[rstest]
+fn empty_styles_add_no_entity() {
+    let text = RichText::new().bold("").text("plain");
+
+    assert_eq!(text.as_str(), "plain");
+    assert!(text.entities().is_empty());
+}
+
+#[rstest]
+fn bot_sends_rich_text(server: MockServer) {
+    let bot = Bot::new(TOKEN)
+        .expect("Could not create the bot.")
+        .with_api_url(server.url());
+
+    bot.send_rich_text(1234, RichText::new().text("Build ").bold("passed"))
+        .send()
+        .expect("Could not send the rich text.");
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("text").unwrap(), "Build passed");
+    assert!(requests[0].field("parse_mode").is_none());
+    let entities: Value = serde_json::from_str(&requests[0].field("entities").unwrap()).unwrap();
+    assert_eq!(
+        entities,
+        json!([{ "type": "bold", "offset": 6, "length": 6 }])
+    );
+}
@@ -0,0 +1,171 @@
+use tgcli::operations::bot::send::html::{sanitize, validate};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// Runs `bot send` against `server` with `args` in HTML.
+fn send(server: &MockServer, args: &[&str]) -> assert_cmd::assert::Assert {
+    binary(server.url())
+        .args(["bot", "send"])
+        .args(args)
+        .args(["-r", "1234", "--format", "html"])
+        .assert()
+}
+
+#[rstest]
+#[case("plain text > 1")]
+#[case("<b>bold</b> <strong>strong</strong> <i>i</i> <em>em</em> <u>u</u> <s>s</s> <del>del</del>")]
+#[case("<a href=\"https://example.com/?a=1&amp;b=2\">link</a>")]
+#[case("<span class=\"tg-spoiler\">secret</span> <tg-spoiler>secret</tg-spoiler>")]
+#[case("<pre><code class=\"language-rust\">fn main() {}</code></pre>")]
+#[case("<blockquote expandable>quote</blockquote>")]
+#[case("&lt; &gt; &amp; &quot; &#128077; &#x1F44D;")]
+#[case("<B>upper</B>")]
+fn valid_html(#[case] html: &str) {
+    assert!(validate(html).is_ok(), "{}", html);
+}
+
+#[rstest]
+#[case("<p>text</p>", "Unsupported tag <p> at byte offset 0.")]
+#[case("line<br>break", "Unsupported tag <br> at byte offset 4.")]
+#[case("<b>a <i>b</b></i>", "Unexpected end tag </b> at byte offset 9.")]
+#[case("text</i>", "Unexpected end tag </i> at byte offset 4.")]
+#[case("ok <b>open", "Unclosed tag <b> at byte offset 3.")]
+#[case(
+    "5 < 6",
+    "Unescaped < at byte offset 2, which must be written as &lt;."
+)]
+#[case(
+    "A & B",
+    "Unescaped & at byte offset 2, which must be written as &amp;."
+)]
+#[case("&nbsp;", "Unsupported entity &nbsp; at byte offset 0.")]
+#[case(
+    "<span>x</span>",
+    "Tag <span> at byte offset 0 must have class \"tg-spoiler\"."
+)]
+#[case(
+    "é<!-- note -->",
+    "Unsupported comment or declaration at byte offset 2."
+)]
+fn invalid_html(#[case] html: &str, #[case] message: &str) {
+    let error = validate(html).unwrap_err();
+
+    assert_eq!(error.exit_code(), 2);
+    assert_eq!(error.to_string(), message);
+}
+
+#[rstest]
+#[case("<p>one</p>\n<p>two</p>", "one\n\ntwo")]
+#[case("<h2>Title</h2>text", "<b>Title</b>\n\ntext")]
+#[case("line<br/>break", "line\nbreak")]
+#[case("<ul><li>one</li><li>two</li></ul>", "• one\n• two")]
+#[case("<div class=\"x\">a</div><div>b</div>", "a\nb")]
+#[case("<b class=\"x\">bold</b>", "<b>bold</b>")]
+#[case(
+    "<a href=\"https://x.y\" target=\"_blank\">l</a>",
+    "<a href=\"https://x.y\">l</a>"
+)]
+#[case(
+    "<code class=\"language-rs\" id=1>x</code>",
+    "<code class=\"language-rs\">x</code>"
+)]
+#[case(
+    "<span>plain</span> <span class=\"tg-spoiler\">secret</span>",
+    "plain <span class=\"tg-spoiler\">secret</span>"
+)]
+#[case("<b>a <i>b</b> c</i>", "<b>a <i>b</i></b><i> c</i>")]
+#[case("text</i> <u>open", "text <u>open</u>")]
+#[case("5 < 6 & 7", "5 &lt; 6 &amp; 7")]
+#[case("&nbsp;&copy;&foo;", "&#160;&#169;&amp;foo;")]
+#[case("<!-- note --><kbd>K</kbd>", "<code>K</code>")]
+fn sanitized_html(#[case] html: &str, #[case] sanitized: &str) {
+    assert_eq!(sanitize(html), sanitized);
+    assert!(validate(sanitized).is_ok(), "{}", sanitized);
+}
+
+#[rstest]
+fn unsupported_tags_fail_before_sending(server: MockServer) {
+    let assertion = send(&server, &["message", "<p>Deployed</p>"])
+        .failure()
+        .code(2);
+
+    let stderr = String::from_utf8_lossy(&assertion.get_output().stderr).to_string();
+    assert!(stderr.contains("Unsupported tag <p> at byte offset 0."));
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn sanitize_rewrites_tags(server: MockServer) {
+    send(
+        &server,
+        &[
+            "message",
+            "<h1>Deployed</h1><p>to <em>prod</em></p>",
+            "--sanitize",
+        ],
+    )
+    .success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].field("text").unwrap(),
+        "<b>Deployed</b>\n\nto <em>prod</em>"
+    );
+}
+
+#[rstest]
+fn captions_are_validated(server: MockServer) {
+    send(
+        &server,
+        &["document", "resources/test/doc.txt", "-m", "<b>unclosed"],
+    )
+    .failure()
+    .code(2);
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn markdown_is_not_validated(server: MockServer) {
+    binary(server.url())
+        .args(["bot", "send", "message", "<p>as is</p>", "-r", "1234"])
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+}
+
+#[rstest]
+fn sanitize_requires_html(server: MockServer, #[values("markdown", "commonmark")] format: &str) {
+    binary(server.url())
+        .args(["bot", "send", "message", "<p>foo</p>", "-r", "1234"])
+        .args(["--format", format, "--sanitize"])
+        .assert()
+        .failure()
+        .code(2);
+    assert!(server.requests().is_empty());
+}
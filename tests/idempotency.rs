@@ -0,0 +1,215 @@
+use std::{fs, path::PathBuf, thread, time::Duration};
+
+use serde_json::Value;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockResponse, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// An empty state directory of a test.
+fn state(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tgcli-state-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+/// Sends `message` with `key` and returns the message printed on stdout.
+fn send(server: &MockServer, state: &PathBuf, message: &str, args: &[&str]) -> Value {
+    let assert = binary(server.url())
+        .env("TGCLI_STATE_DIR", state)
+        .args(["bot", "send", "message", message, "-r", "1234"])
+        .args(args)
+        .assert()
+        .success();
+    String::from_utf8_lossy(&assert.get_output().stdout)
+        .lines()
+        .filter_map(|l| serde_json::from_str::<Value>(l).ok())
+        .find(|v| v.get("message_id").is_some())
+        .unwrap()
+}
+
+#[rstest]
+fn repeat_is_sent_once(server: MockServer) {
+    let state = state("repeat");
+    let first = send(&server, &state, "foo", &["--idempotency-key", "alert-1"]);
+    let second = send(&server, &state, "foo", &["--idempotency-key", "alert-1"]);
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    assert_eq!(first, second);
+    assert_eq!(second["text"], "foo");
+}
+
+#[rstest]
+fn other_keys_are_sent(server: MockServer) {
+    let state = state("other-keys");
+    send(&server, &state, "foo", &["--idempotency-key", "alert-1"]);
+    send(&server, &state, "foo", &["--idempotency-key", "alert-2"]);
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+}
+
+#[rstest]
+fn other_bots_are_sent(server: MockServer) {
+    let state = state("other-bots");
+    send(&server, &state, "foo", &["--idempotency-key", "alert-1"]);
+    binary(server.url())
+        .env("TGCLI_STATE_DIR", &state)
+        .args(["bot", "send", "message", "foo", "-r", "1234"])
+        .args(["--idempotency-key", "alert-1", "--token", "654321:mock"])
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+}
+
+#[rstest]
+fn expired_key_is_sent(server: MockServer) {
+    let state = state("expired");
+    let args = ["--idempotency-key", "alert-1", "--idempotency-ttl", "1s"];
+    send(&server, &state, "foo", &args);
+    thread::sleep(Duration::from_secs(2));
+    send(&server, &state, "foo", &args);
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+}
+
+#[rstest]
+fn failed_send_is_not_kept(server: MockServer) {
+    let state = state("failed");
+    server.respond(
+        "sendMessage",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+    binary(server.url())
+        .env("TGCLI_STATE_DIR", &state)
+        .args(["bot", "send", "message", "foo", "-r", "1234"])
+        .args(["--idempotency-key", "alert-1"])
+        .assert()
+        .failure()
+        .code(66);
+    send(&server, &state, "foo", &["--idempotency-key", "alert-1"]);
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+}
+
+#[rstest]
+fn upsert_edits_text(server: MockServer) {
+    let state = state("upsert-text");
+    send(&server, &state, "foo", &["--idempotency-key", "status"]);
+    let edited = send(
+        &server,
+        &state,
+        "bar",
+        &["--idempotency-key", "status", "--upsert"],
+    );
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    let requests = server.requests_to("editMessageText");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("chat_id").unwrap(), "1234");
+    assert_eq!(requests[0].field("message_id").unwrap(), "1");
+    assert_eq!(requests[0].field("text").unwrap(), "bar");
+    assert_eq!(edited["text"], "bar");
+
+    // the edited message is printed for the next repeat
+    let repeated = send(&server, &state, "bar", &["--idempotency-key", "status"]);
+    assert_eq!(repeated["text"], "bar");
+}
+
+#[rstest]
+fn upsert_without_earlier_message(server: MockServer) {
+    let state = state("upsert-new");
+    send(
+        &server,
+        &state,
+        "foo",
+        &["--idempotency-key", "status", "--upsert"],
+    );
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    assert!(server.requests_to("editMessageText").is_empty());
+}
+
+#[rstest]
+fn upsert_not_modified(server: MockServer) {
+    let state = state("upsert-same");
+    send(&server, &state, "foo", &["--idempotency-key", "status"]);
+    server.respond(
+        "editMessageText",
+        MockResponse::error(
+            400,
+            "Bad Request: message is not modified: specified new message content and reply markup are exactly the same",
+        ),
+    );
+    let edited = send(
+        &server,
+        &state,
+        "foo",
+        &["--idempotency-key", "status", "--upsert"],
+    );
+
+    assert_eq!(edited["text"], "foo");
+}
+
+#[rstest]
+fn upsert_edits_caption(server: MockServer) {
+    let state = state("upsert-caption");
+    for caption in ["foo", "bar"] {
+        binary(server.url())
+            .env("TGCLI_STATE_DIR", &state)
+            .args([
+                "bot",
+                "send",
+                "document",
+                "resources/test/doc.txt",
+                "-r",
+                "1234",
+            ])
+            .args(["-m", caption, "--idempotency-key", "report", "--upsert"])
+            .assert()
+            .success();
+    }
+
+    assert_eq!(server.requests_to("sendDocument").len(), 1);
+    let requests = server.requests_to("editMessageCaption");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("caption").unwrap(), "bar");
+}
+
+#[rstest]
+fn upsert_poll(server: MockServer) {
+    let state = state("upsert-poll");
+    binary(server.url())
+        .env("TGCLI_STATE_DIR", &state)
+        .args([
+            "bot", "send", "poll", "foo", "-o", "bar", "-o", "baz", "-r", "1234",
+        ])
+        .args(["--idempotency-key", "vote", "--upsert"])
+        .assert()
+        .failure()
+        .code(2);
+
+    assert!(server.requests().is_empty());
+}
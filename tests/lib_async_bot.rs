@@ -0,0 +1,90 @@
+#![cfg(feature = "async")]
+
+use std::env;
+
+use tgcli::{bot::MessageFormat, AsyncBot};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn bot() -> AsyncBot {
+    let _ = dotenv::dotenv();
+    AsyncBot::new(
+        &env::var("TELEGRAM_BOT_TOKEN")
+            .expect("TELEGRAM_BOT_TOKEN environment variable could not be found. Please create .env file and define it."),
+    )
+    .expect("Could not create the bot.")
+}
+
+fn receiver() -> String {
+    let _ = dotenv::dotenv();
+    env::var("TELEGRAM_RECEIVER")
+        .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+}
+
+#[tokio::test]
+async fn get_me() {
+    let me = bot().get_me().await.expect("Could not get the bot.");
+
+    assert!(me.is_bot);
+}
+
+#[tokio::test]
+async fn send_message() {
+    let bot = bot();
+    let message = bot
+        .send_message(receiver(), "<b>tgcli</b> async library")
+        .format(MessageFormat::HTML)
+        .silent()
+        .send()
+        .await
+        .expect("Could not send the message.");
+
+    assert_eq!(message.text.as_deref(), Some("tgcli async library"));
+}
+
+#[tokio::test]
+async fn send_document() {
+    let message = bot()
+        .send_document(receiver(), "resources/test/doc.txt")
+        .caption("an async document")
+        .send()
+        .await
+        .expect("Could not send the document.");
+
+    assert!(message.other.contains_key("document"));
+}
+
+#[tokio::test]
+async fn connection_error() {
+    let e = bot()
+        .with_api_url("http://127.0.0.1:9")
+        .send_message(receiver(), "unreachable")
+        .send()
+        .await
+        .unwrap_err();
+
+    assert_eq!(e.exit_code(), 40);
+}
+
+#[tokio::test]
+async fn invalid_token() {
+    let e = AsyncBot::new("123:invalid")
+        .expect("Could not create the bot.")
+        .get_me()
+        .await
+        .unwrap_err();
+
+    assert_eq!(e.exit_code(), 64);
+}
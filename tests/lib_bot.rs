@@ -0,0 +1,112 @@
+use std::env;
+
+use tgcli::{
+    bot::MessageFormat,
+    operations::{OperationError, TelegramErrorKind},
+    Bot,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[fixture]
+fn bot() -> Bot {
+    let _ = dotenv::dotenv();
+    Bot::new(
+        &env::var("TELEGRAM_BOT_TOKEN")
+            .expect("TELEGRAM_BOT_TOKEN environment variable could not be found. Please create .env file and define it."),
+    )
+    .expect("Could not create the bot.")
+}
+
+#[fixture]
+fn receiver() -> String {
+    let _ = dotenv::dotenv();
+    env::var("TELEGRAM_RECEIVER")
+        .expect("TELEGRAM_RECEIVER environment variable could not be found. Please create .env file and define it.")
+}
+
+#[rstest]
+fn get_me(bot: Bot) {
+    let me = bot.get_me().expect("Could not get the bot.");
+
+    assert!(me.is_bot);
+}
+
+#[rstest]
+fn send_message(bot: Bot, receiver: String) {
+    let message = bot
+        .send_message(&receiver, "<b>tgcli</b> library")
+        .format(MessageFormat::HTML)
+        .silent()
+        .send()
+        .expect("Could not send the message.");
+    let reply = bot
+        .send_message(&receiver, "a reply")
+        .reply_to(message.message_id)
+        .send()
+        .expect("Could not send the reply.");
+
+    assert_eq!(message.text.as_deref(), Some("tgcli library"));
+    assert_eq!(reply.chat.id, message.chat.id);
+}
+
+#[rstest]
+fn send_document(bot: Bot, receiver: String) {
+    let message = bot
+        .send_document(&receiver, "resources/test/doc.txt")
+        .caption("a document")
+        .send()
+        .expect("Could not send the document.");
+
+    assert!(message.other.contains_key("document"));
+}
+
+#[rstest]
+fn connection_error(bot: Bot, receiver: String) {
+    let e = bot
+        .with_api_url("http://127.0.0.1:9")
+        .send_message(&receiver, "unreachable")
+        .send()
+        .unwrap_err();
+
+    assert_eq!(e.exit_code(), 40);
+}
+
+#[rstest]
+fn invalid_token() {
+    let e = Bot::new("123:invalid")
+        .expect("Could not create the bot.")
+        .get_me()
+        .unwrap_err();
+
+    assert_eq!(e.exit_code(), 64);
+}
+
+#[rstest]
+fn chat_not_found(bot: Bot) {
+    let e = bot.send_message("1", "nobody").send().unwrap_err();
+
+    match e {
+        OperationError::Telegram(t) => {
+            assert_eq!(t.error_code, 400);
+            assert_eq!(t.kind(), TelegramErrorKind::ChatNotFound);
+            assert_eq!(t.kind().exit_code(), 66);
+        }
+        e => panic!("Expected a Telegram error, got {:?}.", e),
+    }
+}
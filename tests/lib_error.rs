@@ -0,0 +1,113 @@
+use std::error::Error;
+
+use tgcli::operations::{
+    CommonExitCodes, OperationError, ResponseParameters, TelegramError, TelegramErrorKind,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+#[rstest]
+#[case(401, "Unauthorized", TelegramErrorKind::Unauthorized)]
+#[case(404, "Not Found", TelegramErrorKind::Unauthorized)]
+#[case(
+    403,
+    "Forbidden: bot was blocked by the user",
+    TelegramErrorKind::BotBlocked
+)]
+#[case(
+    403,
+    "Forbidden: bot was kicked from the group chat",
+    TelegramErrorKind::Forbidden
+)]
+#[case(400, "Bad Request: chat not found", TelegramErrorKind::ChatNotFound)]
+#[case(
+    400,
+    "Bad Request: message to edit not found",
+    TelegramErrorKind::MessageNotFound
+)]
+#[case(
+    400,
+    "Bad Request: can't parse entities: Unsupported start tag \"x\" at byte offset 0",
+    TelegramErrorKind::EntityParseError
+)]
+#[case(413, "Request Entity Too Large", TelegramErrorKind::FileTooLarge)]
+#[case(
+    429,
+    "Too Many Requests: retry after 5",
+    TelegramErrorKind::TooManyRequests
+)]
+#[case(502, "Bad Gateway", TelegramErrorKind::ServerError)]
+#[case(400, "Bad Request: message is too long", TelegramErrorKind::Other)]
+fn telegram_error_kind(
+    #[case] error_code: u16,
+    #[case] description: &str,
+    #[case] kind: TelegramErrorKind,
+) {
+    let e = TelegramError::new(error_code, description, None);
+    assert_eq!(e.kind(), kind);
+}
+
+#[rstest]
+#[case(TelegramErrorKind::Unauthorized, 64)]
+#[case(TelegramErrorKind::BotBlocked, 65)]
+#[case(TelegramErrorKind::Forbidden, 65)]
+#[case(TelegramErrorKind::ChatNotFound, 66)]
+#[case(TelegramErrorKind::MessageNotFound, 67)]
+#[case(TelegramErrorKind::TooManyRequests, 68)]
+#[case(TelegramErrorKind::EntityParseError, 69)]
+#[case(TelegramErrorKind::FileTooLarge, 63)]
+#[case(TelegramErrorKind::ServerError, 70)]
+#[case(TelegramErrorKind::Other, 61)]
+fn telegram_error_exit_code(#[case] kind: TelegramErrorKind, #[case] exit_code: i32) {
+    assert_eq!(kind.exit_code(), exit_code);
+}
+
+#[rstest]
+fn telegram_error_parameters() {
+    let e = OperationError::from(TelegramError::new(
+        429,
+        "Too Many Requests: retry after 5",
+        Some(ResponseParameters {
+            retry_after: Some(5),
+            migrate_to_chat_id: None,
+        }),
+    ));
+
+    assert_eq!(e.telegram_kind(), Some(TelegramErrorKind::TooManyRequests));
+    assert_eq!(e.telegram().and_then(|t| t.retry_after()), Some(5));
+    assert_eq!(e.exit_code(), 68);
+    assert_eq!(e.to_string(), "Too Many Requests: retry after 5");
+}
+
+#[rstest]
+fn other_error_source() {
+    let io = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+    let e = OperationError::new(
+        CommonExitCodes::StdFsReadError as i32,
+        "Could not read the file.",
+        Some(io),
+    );
+
+    assert_eq!(e.exit_code(), 4);
+    assert_eq!(e.telegram_kind(), None);
+    assert_eq!(e.to_string(), "Could not read the file.");
+    assert_eq!(
+        e.source().map(|s| s.to_string()),
+        Some(String::from("no such file"))
+    );
+}
@@ -0,0 +1,289 @@
+use serde_json::json;
+use tgcli::{
+    operations::{CommonExitCodes, OperationError, TelegramErrorKind},
+    Bot,
+};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockResponse, MockServer, TOKEN};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+#[rstest]
+fn send_message(server: MockServer) {
+    binary(server.url())
+        .args(["bot", "send", "message", "foo", "--receiver", "1234"])
+        .assert()
+        .success();
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].token, TOKEN);
+    assert_eq!(requests[0].field("chat_id").as_deref(), Some("1234"));
+    assert_eq!(requests[0].field("text").as_deref(), Some("foo"));
+}
+
+#[rstest]
+fn send_document(server: MockServer) {
+    binary(server.url())
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--receiver",
+            "1234",
+        ])
+        .assert()
+        .success();
+
+    let request = &server.requests_to("sendDocument")[0];
+    let document = request.part("document").expect("No document was sent.");
+    assert_eq!(document.file_name.as_deref(), Some("doc.txt"));
+    assert_eq!(
+        document.data,
+        std::fs::read("resources/test/doc.txt").expect("Could not read the document.")
+    );
+}
+
+#[rstest]
+fn send_video_malformed_mp4(server: MockServer) {
+    // a moov box with a 64-bit size of u64::MAX after a minimal ftyp box
+    let mut mp4 = vec![0, 0, 0, 16];
+    mp4.extend(b"ftypisom");
+    mp4.extend([0; 4]);
+    mp4.extend([0, 0, 0, 1]);
+    mp4.extend(b"moov");
+    mp4.extend(u64::MAX.to_be_bytes());
+    let path = std::env::temp_dir().join(format!("tgcli-malformed-{}.mp4", std::process::id()));
+    std::fs::write(&path, mp4).expect("Could not write the video.");
+
+    binary(server.url())
+        .args(["bot", "send", "video"])
+        .arg(&path)
+        .args(["--receiver", "1234"])
+        .assert()
+        .success();
+    std::fs::remove_file(&path).ok();
+
+    let request = &server.requests_to("sendVideo")[0];
+    assert!(request.part("video").is_some());
+    assert_eq!(request.field("duration"), None);
+    assert_eq!(request.field("width"), None);
+    assert_eq!(request.field("height"), None);
+}
+
+#[rstest]
+fn file_get_stdin_same_names(server: MockServer) {
+    server.file("documents/mock", b"report");
+    let output = std::env::temp_dir().join(format!("tgcli-same-names-{}", std::process::id()));
+    let update = json!([
+        { "message_id": 1, "document": { "file_id": "a", "file_unique_id": "a", "file_name": "report.pdf" } },
+        { "message_id": 2, "document": { "file_id": "b", "file_unique_id": "b", "file_name": "report.pdf" } },
+    ]);
+
+    binary(server.url())
+        .args(["bot", "file", "get", "--stdin", "-o"])
+        .arg(&output)
+        .write_stdin(update.to_string())
+        .assert()
+        .success();
+
+    assert_eq!(std::fs::read(output.join("report.pdf")).unwrap(), b"report");
+    assert_eq!(
+        std::fs::read(output.join("report (1).pdf")).unwrap(),
+        b"report"
+    );
+    std::fs::remove_dir_all(&output).ok();
+}
+
+#[rstest]
+fn file_get_local_server(server: MockServer) {
+    let source = std::env::temp_dir().join(format!("tgcli-local-{}.txt", std::process::id()));
+    let output = std::env::temp_dir().join(format!("tgcli-local-{}.out", std::process::id()));
+    std::fs::write(&source, b"local").unwrap();
+    server.respond(
+        "getFile",
+        MockResponse::ok(json!({
+            "file_id": "a",
+            "file_unique_id": "a",
+            "file_path": source.to_str().unwrap(),
+        })),
+    );
+
+    binary(server.url())
+        .args(["bot", "file", "get", "a", "-o"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    assert_eq!(std::fs::read(&output).unwrap(), b"local");
+    std::fs::remove_file(&source).ok();
+    std::fs::remove_file(&output).ok();
+}
+
+#[rstest]
+fn member_ban_prints_each_user(server: MockServer) {
+    server.respond("banChatMember", MockResponse::ok(json!(true)));
+    server.respond(
+        "banChatMember",
+        MockResponse::error(400, "Bad Request: user not found"),
+    );
+
+    let assert = binary(server.url())
+        .args(["bot", "member", "ban", "-c", "1234", "-u", "1", "-u", "2"])
+        .assert()
+        .failure()
+        .code(61);
+
+    let lines: Vec<serde_json::Value> = String::from_utf8_lossy(&assert.get_output().stdout)
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], json!({ "id": 1, "ok": true }));
+    assert_eq!(lines[1]["id"], 2);
+    assert_eq!(lines[1]["ok"], false);
+    assert_eq!(lines[1]["exit_code"], 61);
+    assert_eq!(server.requests_to("banChatMember").len(), 2);
+}
+
+#[rstest]
+fn follow_coalesces_close_points(server: MockServer) {
+    binary(server.url())
+        .args([
+            "bot",
+            "location",
+            "follow",
+            "--from-gpx",
+            "resources/test/track.gpx",
+            "--receiver",
+            "1234",
+        ])
+        .assert()
+        .success();
+
+    // the middle point is two seconds after the first one, so only the last is sent
+    let edits = server.requests_to("editMessageLiveLocation");
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].field("latitude").as_deref(), Some("38.4253"));
+    assert_eq!(server.requests_to("stopMessageLiveLocation").len(), 1);
+}
+
+#[rstest]
+fn follow_stops_on_failure(server: MockServer) {
+    server.respond(
+        "editMessageLiveLocation",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+
+    binary(server.url())
+        .args([
+            "bot",
+            "location",
+            "follow",
+            "--from-gpx",
+            "resources/test/track.gpx",
+            "--receiver",
+            "1234",
+        ])
+        .assert()
+        .failure()
+        .code(66);
+
+    assert_eq!(server.requests_to("stopMessageLiveLocation").len(), 1);
+}
+
+#[rstest]
+#[case(&[], false)]
+#[case(&["--local-server"], true)]
+fn upload_limit_of_server(server: MockServer, #[case] args: &[&str], #[case] accepted: bool) {
+    let path = std::env::temp_dir().join(format!(
+        "tgcli-limit-{}-{}.bin",
+        accepted,
+        std::process::id()
+    ));
+    let file = std::fs::File::create(&path).expect("Could not create the large file.");
+    file.set_len(51 * 1024 * 1024)
+        .expect("Could not resize the large file.");
+
+    // any server other than the official one is not assumed to be a local one
+    let assert = binary(server.url())
+        .args(["bot", "send", "document"])
+        .arg(&path)
+        .args(["--receiver", "1234"])
+        .args(args)
+        .assert();
+    std::fs::remove_file(&path).ok();
+
+    match accepted {
+        true => {
+            assert.success();
+            assert_eq!(server.requests_to("sendDocument").len(), 1);
+        }
+        false => {
+            assert
+                .failure()
+                .code(CommonExitCodes::TelegramAPIFileTooLarge as i32);
+            assert!(server.requests().is_empty());
+        }
+    }
+}
+
+#[rstest]
+#[case(MockResponse::error(400, "Bad Request: chat not found"), 66)]
+#[case(MockResponse::error(403, "Forbidden: bot was blocked by the user"), 65)]
+#[case(MockResponse::too_many_requests(5), 68)]
+#[case(MockResponse::error(502, "Bad Gateway"), 70)]
+fn send_message_failure(server: MockServer, #[case] response: MockResponse, #[case] code: i32) {
+    server.respond("sendMessage", response);
+
+    binary(server.url())
+        .args(["bot", "send", "message", "foo", "--receiver", "1234"])
+        .assert()
+        .failure()
+        .code(code);
+}
+
+#[rstest]
+fn library_error(server: MockServer) {
+    server.respond("sendMessage", MockResponse::too_many_requests(5));
+    let bot = Bot::new(TOKEN)
+        .expect("Could not create the bot.")
+        .with_api_url(server.url());
+
+    match bot.send_message(1234, "foo").send().unwrap_err() {
+        OperationError::Telegram(e) => {
+            assert_eq!(e.kind(), TelegramErrorKind::TooManyRequests);
+            assert_eq!(e.retry_after(), Some(5));
+        }
+        e => panic!("Expected a Telegram error, got {:?}.", e),
+    }
+
+    let message = bot
+        .send_message(1234, "foo")
+        .send()
+        .expect("The queued response should have been used once.");
+    assert_eq!(message.text.as_deref(), Some("foo"));
+}
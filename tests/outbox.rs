@@ -0,0 +1,280 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{json, Value};
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockResponse, MockServer, TOKEN};
+
+/// Nothing listens on the discard port, so that every request fails to
+/// connect.
+const UNREACHABLE_URL: &str = "http://127.0.0.1:9";
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// An empty outbox of a test.
+fn outbox(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tgcli-outbox-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+/// The JSON lines a command printed on stdout.
+fn json_lines(output: &[u8]) -> Vec<Value> {
+    String::from_utf8_lossy(output)
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect()
+}
+
+/// The entries `outbox list` prints.
+fn list(outbox: &PathBuf) -> Vec<Value> {
+    let assert = binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", outbox)
+        .args(["outbox", "list"])
+        .assert()
+        .success();
+    json_lines(&assert.get_output().stdout)
+}
+
+/// Keeps a message that fails to connect in `outbox`.
+fn keep_message(outbox: &PathBuf, message: &str) {
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", outbox)
+        .args(["bot", "send", "message", message, "-r", "1234", "--outbox"])
+        .assert()
+        .success();
+}
+
+#[rstest]
+fn keep_on_connection_failure() {
+    let outbox = outbox("keep");
+    let assert = binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["bot", "send", "message", "foo", "-r", "1234", "--outbox"])
+        .assert()
+        .success();
+
+    let printed = json_lines(&assert.get_output().stdout);
+    let kept = printed.iter().find(|v| v.get("outbox").is_some()).unwrap();
+
+    let entries = list(&outbox);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["id"], kept["outbox"]);
+    assert_eq!(entries[0]["method"], "sendMessage");
+    assert_eq!(entries[0]["chat_id"], "1234");
+    assert_eq!(entries[0]["attempts"], 1);
+}
+
+#[rstest]
+fn fail_without_outbox() {
+    let outbox = outbox("without");
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["bot", "send", "message", "foo", "-r", "1234"])
+        .assert()
+        .failure()
+        .code(40);
+
+    assert!(!outbox.exists());
+}
+
+#[rstest]
+fn fail_on_permanent_error(server: MockServer) {
+    let outbox = outbox("permanent");
+    server.respond(
+        "sendMessage",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+    binary(server.url())
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["bot", "send", "message", "foo", "-r", "1234", "--outbox"])
+        .assert()
+        .failure()
+        .code(66);
+
+    assert!(list(&outbox).is_empty());
+}
+
+#[rstest]
+fn flush_with_copied_file(server: MockServer) {
+    let outbox = outbox("flush-file");
+    // the original is gone by the time the outbox is flushed
+    let document = std::env::temp_dir().join(format!("tgcli-outbox-{}.txt", std::process::id()));
+    fs::copy("resources/test/doc.txt", &document).unwrap();
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["bot", "send", "document", "-r", "1234", "--outbox"])
+        .arg(&document)
+        .assert()
+        .success();
+    fs::remove_file(&document).unwrap();
+
+    let assert = binary(server.url())
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "flush"])
+        .assert()
+        .success();
+
+    let requests = server.requests_to("sendDocument");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].token, TOKEN);
+    assert_eq!(requests[0].field("chat_id").unwrap(), "1234");
+    let part = requests[0].part("document").unwrap();
+    assert_eq!(
+        part.file_name.as_deref(),
+        document.file_name().unwrap().to_str()
+    );
+    assert_eq!(part.data, fs::read("resources/test/doc.txt").unwrap());
+
+    let printed = json_lines(&assert.get_output().stdout);
+    assert!(printed.iter().any(|v| v["ok"] == json!(true)));
+    assert!(list(&outbox).is_empty());
+}
+
+#[rstest]
+fn flush_in_order(server: MockServer) {
+    let outbox = outbox("flush-order");
+    keep_message(&outbox, "first");
+    keep_message(&outbox, "second");
+
+    binary(server.url())
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "flush"])
+        .assert()
+        .success();
+
+    let texts: Vec<String> = server
+        .requests_to("sendMessage")
+        .iter()
+        .map(|r| r.field("text").unwrap())
+        .collect();
+    assert_eq!(texts, ["first", "second"]);
+}
+
+#[rstest]
+fn flush_retries_flood_control(server: MockServer) {
+    let outbox = outbox("flush-retry");
+    keep_message(&outbox, "foo");
+    server.respond("sendMessage", MockResponse::too_many_requests(0));
+
+    binary(server.url())
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "flush", "--retries", "1"])
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+    assert!(list(&outbox).is_empty());
+}
+
+#[rstest]
+fn flush_stops_on_connection_failure() {
+    let outbox = outbox("flush-stop");
+    keep_message(&outbox, "foo");
+
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "flush", "--retries", "0"])
+        .assert()
+        .failure()
+        .code(40);
+
+    let entries = list(&outbox);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["attempts"], 2);
+}
+
+#[rstest]
+fn flush_continues_after_permanent_error(server: MockServer) {
+    let outbox = outbox("flush-continue");
+    keep_message(&outbox, "first");
+    keep_message(&outbox, "second");
+    server.respond(
+        "sendMessage",
+        MockResponse::error(400, "Bad Request: chat not found"),
+    );
+
+    binary(server.url())
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "flush"])
+        .assert()
+        .failure()
+        .code(66);
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+    let entries = list(&outbox);
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0]["last_error"]
+        .as_str()
+        .unwrap()
+        .contains("chat not found"));
+}
+
+#[rstest]
+fn drop_by_id() {
+    let outbox = outbox("drop");
+    keep_message(&outbox, "first");
+    keep_message(&outbox, "second");
+    let id = list(&outbox)[0]["id"].as_str().unwrap().to_string();
+
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "drop", &id])
+        .assert()
+        .success();
+
+    let entries = list(&outbox);
+    assert_eq!(entries.len(), 1);
+    assert_ne!(entries[0]["id"], json!(id));
+}
+
+#[rstest]
+fn drop_all() {
+    let outbox = outbox("drop-all");
+    keep_message(&outbox, "first");
+    keep_message(&outbox, "second");
+
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "drop", "--all"])
+        .assert()
+        .success();
+
+    assert!(list(&outbox).is_empty());
+}
+
+#[rstest]
+fn drop_unknown_id() {
+    let outbox = outbox("drop-unknown");
+    keep_message(&outbox, "foo");
+
+    binary(UNREACHABLE_URL)
+        .env("TGCLI_OUTBOX_DIR", &outbox)
+        .args(["outbox", "drop", "foo"])
+        .assert()
+        .failure()
+        .code(2);
+
+    assert_eq!(list(&outbox).len(), 1);
+}
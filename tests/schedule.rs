@@ -0,0 +1,240 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer, TOKEN};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// An empty spool directory of a test.
+fn spool(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tgcli-spool-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+/// The entries in `spool`.
+fn entries(spool: &PathBuf) -> Vec<Value> {
+    match fs::read_dir(spool) {
+        Ok(d) => d
+            .map(|e| fs::read_to_string(e.unwrap().path()).unwrap())
+            .map(|c| serde_json::from_str(&c).unwrap())
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+#[rstest]
+fn wait_in(server: MockServer) {
+    let spool = spool("wait-in");
+    let start = Instant::now();
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["bot", "send", "message", "foo", "-r", "1234", "--in", "2s"])
+        .assert()
+        .success();
+
+    assert!(start.elapsed() >= Duration::from_secs(2));
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+}
+
+#[rstest]
+fn at_past(server: MockServer) {
+    let spool = spool("at-past");
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args([
+            "bot",
+            "send",
+            "message",
+            "foo",
+            "-r",
+            "1234",
+            "--at",
+            "2021-01-01T09:00:00+00:00",
+        ])
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+}
+
+#[rstest]
+fn queue(server: MockServer) {
+    let spool = spool("queue");
+    let output = binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args([
+            "bot",
+            "send",
+            "message",
+            "foo",
+            "-r",
+            "1234",
+            "--in",
+            "1h",
+            "--queue",
+            "-t",
+            "123:other",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    assert!(String::from_utf8_lossy(&output).contains("\"id\""));
+    assert!(server.requests().is_empty());
+    let queued = entries(&spool);
+    assert_eq!(queued.len(), 1);
+    assert_eq!(
+        queued[0]["args"],
+        serde_json::json!(["bot", "send", "message", "foo", "-r", "1234"])
+    );
+
+    // not due yet
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["daemon", "--once"])
+        .assert()
+        .success();
+    assert!(server.requests().is_empty());
+    assert_eq!(entries(&spool).len(), 1);
+}
+
+#[rstest]
+fn queue_keeps_arguments_like_token(server: MockServer) {
+    let spool = spool("like-token");
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["bot", "send", "message", "-r", "1234", "--in", "1h"])
+        .args(["--queue", "-t123:other", "--", "-tada"])
+        .assert()
+        .success();
+
+    // only the attached token is left out, not the message starting with -t
+    let queued = entries(&spool);
+    assert_eq!(
+        queued[0]["args"],
+        serde_json::json!(["bot", "send", "message", "-r", "1234", "--", "-tada"])
+    );
+}
+
+#[rstest]
+fn daemon(server: MockServer) {
+    let spool = spool("daemon");
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "-r",
+            "1234",
+            "--queue",
+        ])
+        .assert()
+        .success();
+
+    // relative paths are resolved against the directory it was queued in
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .current_dir(std::env::temp_dir())
+        .args(["daemon", "--once"])
+        .assert()
+        .success();
+
+    let requests = server.requests_to("sendDocument");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].token, TOKEN);
+    assert!(requests[0].part("document").is_some());
+    assert!(entries(&spool).is_empty());
+}
+
+#[rstest]
+fn daemon_cron(server: MockServer) {
+    let spool = spool("daemon-cron");
+    fs::create_dir_all(&spool).unwrap();
+    fs::write(
+        spool.join("recurring.json"),
+        serde_json::json!({
+            "args": ["bot", "send", "message", "foo", "-r", "1234"],
+            "dir": std::env::current_dir().unwrap(),
+            "at": "2021-01-01T09:00:00+00:00",
+            "cron": "0 9 * * *",
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["daemon", "--once"])
+        .assert()
+        .success();
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    let entries = entries(&spool);
+    assert_eq!(entries.len(), 1);
+    let at = chrono::DateTime::parse_from_rfc3339(entries[0]["at"].as_str().unwrap()).unwrap();
+    assert!(at > chrono::Local::now());
+}
+
+#[rstest]
+#[case(&["--cron", "0 9 * * *"])]
+#[case(&["--cron", "every day", "--queue"])]
+#[case(&["--at", "tomorrow"])]
+#[case(&["--at", "2021-01-01T09:00", "--in", "1h"])]
+fn invalid(server: MockServer, #[case] args: &[&str]) {
+    let spool = spool("invalid");
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["bot", "send", "message", "foo", "-r", "1234"])
+        .args(args)
+        .assert()
+        .failure()
+        .code(1);
+
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+fn batch(server: MockServer) {
+    let spool = spool("batch");
+    binary(server.url())
+        .env("TGCLI_SPOOL_DIR", &spool)
+        .args(["batch", "-"])
+        .write_stdin(r#"{"op": "send.message", "receiver": 1234, "message": "foo", "in": "1h"}"#)
+        .assert()
+        .failure()
+        .code(2);
+
+    assert!(server.requests_to("sendMessage").is_empty());
+}
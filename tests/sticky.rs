@@ -0,0 +1,145 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::json;
+
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockResponse, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+/// An empty state directory of a test.
+fn state(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tgcli-sticky-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+/// Sends `message` to `receiver` as the sticky message `name`.
+fn send_sticky(server: &MockServer, state: &PathBuf, receiver: &str, name: &str, message: &str) {
+    binary(server.url())
+        .env("TGCLI_STATE_DIR", state)
+        .args([
+            "bot", "send", "message", message, "-r", receiver, "--sticky", name,
+        ])
+        .assert()
+        .success();
+}
+
+#[rstest]
+fn first_run_sends(server: MockServer) {
+    let state = state("first");
+    send_sticky(&server, &state, "1234", "build-status", "Build #1 passed.");
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    assert!(server.requests_to("editMessageText").is_empty());
+    assert!(state.join("sticky.json").is_file());
+}
+
+#[rstest]
+fn later_runs_edit(server: MockServer) {
+    let state = state("later");
+    send_sticky(&server, &state, "1234", "build-status", "Build #1 passed.");
+    send_sticky(&server, &state, "1234", "build-status", "Build #2 failed.");
+    send_sticky(&server, &state, "1234", "build-status", "Build #3 passed.");
+
+    assert_eq!(server.requests_to("sendMessage").len(), 1);
+    let requests = server.requests_to("editMessageText");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].field("chat_id").unwrap(), "1234");
+    assert_eq!(requests[1].field("message_id").unwrap(), "1");
+    assert_eq!(requests[1].field("text").unwrap(), "Build #3 passed.");
+}
+
+#[rstest]
+fn names_are_separate(server: MockServer) {
+    let state = state("names");
+    send_sticky(&server, &state, "1234", "build-status", "Build #1 passed.");
+    send_sticky(
+        &server,
+        &state,
+        "1234",
+        "deploy-status",
+        "Deploy #1 started.",
+    );
+
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+    assert!(server.requests_to("editMessageText").is_empty());
+}
+
+#[rstest]
+fn chats_are_separate(server: MockServer) {
+    let state = state("chats");
+    send_sticky(&server, &state, "1234", "build-status", "Build #1 passed.");
+    send_sticky(&server, &state, "5678", "build-status", "Build #1 passed.");
+    send_sticky(&server, &state, "5678", "build-status", "Build #2 failed.");
+
+    let requests = server.requests_to("sendMessage");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].field("chat_id").unwrap(), "5678");
+    let requests = server.requests_to("editMessageText");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].field("chat_id").unwrap(), "5678");
+}
+
+#[rstest]
+fn deleted_message_is_sent_again(server: MockServer) {
+    let state = state("deleted");
+    send_sticky(&server, &state, "1234", "build-status", "Build #1 passed.");
+
+    server.respond(
+        "editMessageText",
+        MockResponse::error(400, "Bad Request: message to edit not found"),
+    );
+    server.respond(
+        "sendMessage",
+        MockResponse::ok(json!({
+            "message_id": 7,
+            "date": 0,
+            "chat": { "id": 1234, "type": "private" },
+            "text": "Build #2 failed.",
+        })),
+    );
+    send_sticky(&server, &state, "1234", "build-status", "Build #2 failed.");
+    assert_eq!(server.requests_to("sendMessage").len(), 2);
+
+    // the new message is edited from then on
+    send_sticky(&server, &state, "1234", "build-status", "Build #3 passed.");
+    let requests = server.requests_to("editMessageText");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].field("message_id").unwrap(), "7");
+}
+
+#[rstest]
+fn sticky_with_idempotency_key(server: MockServer) {
+    let state = state("conflict");
+    binary(server.url())
+        .env("TGCLI_STATE_DIR", &state)
+        .args(["bot", "send", "message", "foo", "-r", "1234"])
+        .args(["--sticky", "build-status", "--idempotency-key", "foo"])
+        .assert()
+        .failure()
+        .code(1);
+
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Eray Erdin
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate rstest;
+
+mod common;
+
+use common::{binary, MockServer};
+
+#[fixture]
+fn server() -> MockServer {
+    MockServer::start()
+}
+
+#[rstest]
+#[case(
+    "markdown",
+    "Released *tgcli* 1\\.2\\.0\\-rc by Eray \\(erayerdin\\)\n_safe_"
+)]
+#[case("html", "Released *tgcli* 1.2.0-rc by Eray (erayerdin)\n_safe_")]
+fn message(server: MockServer, #[case] format: &str, #[case] text: &str) {
+    binary(server.url())
+        .env("TGCLI_TEST_AUTHOR", "Eray (erayerdin)")
+        .args([
+            "bot",
+            "send",
+            "message",
+            "--receiver",
+            "1234",
+            "--format",
+            format,
+            "--template",
+            "resources/test/template.md",
+            "--vars-json",
+            "resources/test/vars.json",
+            "--var",
+            "version=1.2.0-rc",
+            "--var",
+            "notes=_safe_",
+        ])
+        .assert()
+        .success();
+
+    let request = &server.requests_to("sendMessage")[0];
+    assert_eq!(request.field("text").as_deref(), Some(text));
+}
+
+#[rstest]
+fn html_escape(server: MockServer) {
+    binary(server.url())
+        .env("TGCLI_TEST_AUTHOR", "Eray (erayerdin)")
+        .args([
+            "bot",
+            "send",
+            "message",
+            "--receiver",
+            "1234",
+            "--format",
+            "html",
+            "--template",
+            "resources/test/template.md",
+            "--var",
+            "project=<b>&</b>",
+            "--var",
+            "version=1",
+            "--var",
+            "notes=<i>ok</i>",
+        ])
+        .assert()
+        .success();
+
+    let request = &server.requests_to("sendMessage")[0];
+    assert_eq!(
+        request.field("text").as_deref(),
+        Some("Released *&lt;b&gt;&amp;&lt;/b&gt;* 1 by Eray (erayerdin)\n<i>ok</i>")
+    );
+}
+
+#[rstest]
+fn caption(server: MockServer) {
+    binary(server.url())
+        .env("TGCLI_TEST_AUTHOR", "Eray (erayerdin)")
+        .args([
+            "bot",
+            "send",
+            "document",
+            "resources/test/doc.txt",
+            "--receiver",
+            "1234",
+            "--template",
+            "resources/test/template.md",
+            "--vars-json",
+            "resources/test/vars.json",
+            "--var",
+            "notes=",
+        ])
+        .assert()
+        .success();
+
+    let request = &server.requests_to("sendDocument")[0];
+    assert_eq!(
+        request.field("caption").as_deref(),
+        Some("Released *tgcli* 0\\.0\\.0 by Eray \\(erayerdin\\)\n")
+    );
+}
+
+#[rstest]
+fn undefined_variable(server: MockServer) {
+    binary(server.url())
+        .env("TGCLI_TEST_AUTHOR", "Eray (erayerdin)")
+        .args([
+            "bot",
+            "send",
+            "message",
+            "--receiver",
+            "1234",
+            "--template",
+            "resources/test/template.md",
+        ])
+        .assert()
+        .failure()
+        .code(2);
+
+    assert!(server.requests().is_empty());
+}
+
+#[rstest]
+#[case(&["foo", "--template", "resources/test/template.md"])]
+#[case(&["foo", "--var", "version=1"])]
+#[case(&["--template", "resources/test/template.md", "--var", "version"])]
+fn invalid_args(server: MockServer, #[case] args: &[&str]) {
+    binary(server.url())
+        .env("TGCLI_TEST_AUTHOR", "Eray (erayerdin)")
+        .args(["bot", "send", "message", "--receiver", "1234"])
+        .args(args)
+        .assert()
+        .failure()
+        .code(1);
+}